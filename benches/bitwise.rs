@@ -0,0 +1,49 @@
+/*! Benchmarks for the in-place bitwise operations.
+
+`.and_assign()` combines two bit-slices a memory element at a time when they
+share type parameters and alignment, and a `usize` at a time when they do not.
+These compare both paths against the `BitAndAssign` implementation, which
+accepts an iterator of `bool` and proceeds one bit at a time.
+!*/
+
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::{
+	mem::BitMemory,
+	prelude::*,
+};
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// Builds a 64-kibibit slice of irregular data.
+fn data() -> BitVec<Lsb0, usize> {
+	(0 .. (1 << 16) / <usize as BitMemory>::BITS as usize)
+		.map(|idx| idx.wrapping_mul(0x9E37_79B9).rotate_left(idx as u32))
+		.collect()
+}
+
+#[bench]
+fn and_assign_aligned(b: &mut Bencher) {
+	let src = data();
+	let mut dst = data();
+	dst.rotate_left(<usize as BitMemory>::BITS as usize);
+	b.iter(|| dst.and_assign(black_box(src.as_bitslice())));
+}
+
+#[bench]
+fn and_assign_unaligned(b: &mut Bencher) {
+	let src = data();
+	let mut dst = data();
+	b.iter(|| dst[3 ..].and_assign(black_box(&src[.. src.len() - 3])));
+}
+
+#[bench]
+fn and_assign_iter(b: &mut Bencher) {
+	let src = data();
+	let mut dst = data();
+	b.iter(|| *dst.as_mut_bitslice() &= black_box(src.iter().copied()));
+}
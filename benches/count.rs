@@ -0,0 +1,53 @@
+/*! Benchmarks for `BitSlice::count_ones`.
+
+The body of a large slice may be counted with vector instructions, when the
+target processor supports them. These benchmarks compare `.count_ones()`
+against counting each element of the same memory in turn.
+!*/
+
+use bitvec::prelude::*;
+
+use criterion::{
+	criterion_group,
+	criterion_main,
+	Criterion,
+	Throughput,
+};
+
+//  Sixteen mebibytes
+const BYTES: usize = 16 << 20;
+
+pub fn benchmarks(crit: &mut Criterion) {
+	let mut state = 0x9E37_79B9_7F4A_7C15u64;
+	let data = (0 .. BYTES / 8)
+		.map(|_| {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		})
+		.collect::<Vec<u64>>();
+	let bits = data.view_bits::<Lsb0>();
+
+	let mut group = crit.benchmark_group("count_ones");
+	group.throughput(Throughput::Bytes(BYTES as u64));
+	group.bench_function("elements", |b| {
+		b.iter(|| {
+			criterion::black_box(&data)
+				.iter()
+				.map(|elem| elem.count_ones() as usize)
+				.sum::<usize>()
+		})
+	});
+	group.bench_function("bitslice", |b| {
+		b.iter(|| criterion::black_box(bits).count_ones())
+	});
+	group.bench_function("bitslice_unaligned", |b| {
+		let bits = &bits[3 .. bits.len() - 5];
+		b.iter(|| criterion::black_box(bits).count_ones())
+	});
+	group.finish();
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
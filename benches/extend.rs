@@ -0,0 +1,107 @@
+/*! Benchmarks for `BitVec::extend_from_bitslice`.
+
+When the source slice has the same type parameters as the vector,
+`extend_from_bitslice` copies it with `copy_from_bitslice`, which moves whole
+elements where the two layouts agree and batches `BitField` transfers where
+they do not. These compare it against the `Extend<bool>` implementation, which
+pushes one bit at a time, for an element-aligned and an unaligned vector.
+
+`BitVec::append` uses the same copy, and is compared against moving the bits of
+the other vector across one at a time.
+!*/
+
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::{
+	mem::BitMemory,
+	prelude::*,
+};
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// Builds a 64-kibibit slice of irregular data.
+fn data() -> BitVec<Lsb0, usize> {
+	(0 .. (1 << 16) / <usize as BitMemory>::BITS as usize)
+		.map(|idx| idx.wrapping_mul(0x9E37_79B9).rotate_left(idx as u32))
+		.collect()
+}
+
+#[bench]
+fn bitslice_aligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = BitVec::<Lsb0, usize>::new();
+		bv.extend_from_bitslice(black_box(src.as_bitslice()));
+		bv
+	});
+}
+
+#[bench]
+fn bitslice_unaligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = bitvec![Lsb0, usize; 0; 3];
+		bv.extend_from_bitslice(black_box(src.as_bitslice()));
+		bv
+	});
+}
+
+#[bench]
+fn extend_aligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = BitVec::<Lsb0, usize>::new();
+		bv.extend(black_box(src.as_bitslice()).iter().copied());
+		bv
+	});
+}
+
+#[bench]
+fn extend_unaligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = bitvec![Lsb0, usize; 0; 3];
+		bv.extend(black_box(src.as_bitslice()).iter().copied());
+		bv
+	});
+}
+
+#[bench]
+fn append_aligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = BitVec::<Lsb0, usize>::new();
+		let mut other = black_box(src.clone());
+		bv.append(&mut other);
+		bv
+	});
+}
+
+#[bench]
+fn append_unaligned(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = bitvec![Lsb0, usize; 0; 3];
+		let mut other = black_box(src.clone());
+		bv.append(&mut other);
+		bv
+	});
+}
+
+#[bench]
+fn append_per_bit(b: &mut Bencher) {
+	let src = data();
+	b.iter(|| {
+		let mut bv = BitVec::<Lsb0, usize>::new();
+		let mut other = black_box(src.clone());
+		for bit in other.iter().copied() {
+			bv.push(bit);
+		}
+		other.clear();
+		bv
+	});
+}
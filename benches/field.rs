@@ -0,0 +1,45 @@
+/*! Benchmarks for `BitField` transfers.
+
+Regions that begin at the start of a memory element and fill whole elements are
+loaded and stored element by element, without computing their domain. These
+compare such regions against the same number of bits at an offset, which take
+the masked path through partial edge elements.
+!*/
+
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::prelude::*;
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+#[bench]
+fn store_le_aligned(b: &mut Bencher) {
+	let mut data = [0u8; 8];
+	let bits = data.view_bits_mut::<Lsb0>();
+	b.iter(|| bits.store_le(black_box(0x0123_4567_89AB_CDEFu64)));
+}
+
+#[bench]
+fn store_le_offset(b: &mut Bencher) {
+	let mut data = [0u8; 9];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 68];
+	b.iter(|| bits.store_le(black_box(0x0123_4567_89AB_CDEFu64)));
+}
+
+#[bench]
+fn load_le_aligned(b: &mut Bencher) {
+	let data = [0xA5u8; 8];
+	let bits = data.view_bits::<Lsb0>();
+	b.iter(|| black_box(bits).load_le::<u64>());
+}
+
+#[bench]
+fn load_le_offset(b: &mut Bencher) {
+	let data = [0xA5u8; 9];
+	let bits = &data.view_bits::<Lsb0>()[4 .. 68];
+	b.iter(|| black_box(bits).load_le::<u64>());
+}
@@ -0,0 +1,48 @@
+/*! Benchmarks for `BitSlice::par_count_ones`.
+
+These compare the serial and parallel population counts of a 64 MiB slice. The
+parallel count should scale with the number of threads in the `rayon` pool until
+it is limited by memory bandwidth.
+!*/
+
+use bitvec::prelude::*;
+
+use criterion::{
+	criterion_group,
+	criterion_main,
+	Criterion,
+	Throughput,
+};
+
+//  Sixty-four mebibytes
+const BYTES: usize = 64 << 20;
+
+pub fn benchmarks(crit: &mut Criterion) {
+	let mut state = 0x9E37_79B9_7F4A_7C15u64;
+	let data = (0 .. BYTES / 8)
+		.map(|_| {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		})
+		.collect::<Vec<u64>>();
+	let bits = data.view_bits::<Lsb0>();
+
+	let mut group = crit.benchmark_group("par_count_ones");
+	group.throughput(Throughput::Bytes(BYTES as u64));
+	group.bench_function("serial", |b| {
+		b.iter(|| criterion::black_box(bits).count_ones())
+	});
+	group.bench_function("parallel", |b| {
+		b.iter(|| criterion::black_box(bits).par_count_ones())
+	});
+	group.bench_function("parallel_unaligned", |b| {
+		let bits = &bits[3 .. bits.len() - 5];
+		b.iter(|| criterion::black_box(bits).par_count_ones())
+	});
+	group.finish();
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+/*! Benchmarks for rank queries.
+
+These compare the scanning [`BitSlice::rank_ones`] against the block-indexed
+[`RankIndex::rank_ones`], for queries near the start and near the end of a large
+slice, and for several index block sizes. The scanning cost grows with the
+index, while the indexed cost should depend only on the block size.
+
+[`BitSlice::rank_ones`]: bitvec::slice::BitSlice::rank_ones
+[`RankIndex::rank_ones`]: bitvec::slice::RankIndex::rank_ones
+!*/
+
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::{
+	mem::BitMemory,
+	prelude::*,
+	slice::RankIndex,
+};
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// Builds a one-mebibit slice of irregular data.
+fn data() -> BitVec<Lsb0, usize> {
+	(0 .. (1 << 20) / <usize as BitMemory>::BITS as usize)
+		.map(|idx| idx.wrapping_mul(0x9E37_79B9).rotate_left(idx as u32))
+		.collect()
+}
+
+#[bench]
+fn scan_near(b: &mut Bencher) {
+	let bv = data();
+	b.iter(|| bv.rank_ones(black_box(1_000)));
+}
+
+#[bench]
+fn scan_far(b: &mut Bencher) {
+	let bv = data();
+	let idx = bv.len() - 1_000;
+	b.iter(|| bv.rank_ones(black_box(idx)));
+}
+
+#[bench]
+fn indexed_near(b: &mut Bencher) {
+	let bv = data();
+	let index = RankIndex::new(bv.as_bitslice(), 8);
+	b.iter(|| index.rank_ones(black_box(1_000)));
+}
+
+#[bench]
+fn indexed_far(b: &mut Bencher) {
+	let bv = data();
+	let index = RankIndex::new(bv.as_bitslice(), 8);
+	let idx = bv.len() - 1_000;
+	b.iter(|| index.rank_ones(black_box(idx)));
+}
+
+/// Measures a far query against an index with `block`-element blocks.
+fn indexed_block(b: &mut Bencher, block: usize) {
+	let bv = data();
+	let index = RankIndex::new(bv.as_bitslice(), block);
+	let idx = bv.len() - 1_000;
+	b.iter(|| index.rank_ones(black_box(idx)));
+}
+
+#[bench]
+fn indexed_block_1(b: &mut Bencher) {
+	indexed_block(b, 1);
+}
+
+#[bench]
+fn indexed_block_4(b: &mut Bencher) {
+	indexed_block(b, 4);
+}
+
+#[bench]
+fn indexed_block_16(b: &mut Bencher) {
+	indexed_block(b, 16);
+}
+
+#[bench]
+fn indexed_block_64(b: &mut Bencher) {
+	indexed_block(b, 64);
+}
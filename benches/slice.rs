@@ -137,3 +137,22 @@ fn get_mut(b: &mut Bencher) {
 		b.iter(|| *bsl64.get_mut(69).unwrap() = true);
 	}
 }
+
+#[bench]
+fn fill(b: &mut Bencher) {
+	let mut data = [0usize; 1024];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 ..];
+	b.iter(|| black_box(&mut *bits).fill(true));
+}
+
+#[bench]
+fn fill_per_bit(b: &mut Bencher) {
+	let mut data = [0usize; 1024];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 ..];
+	b.iter(|| {
+		let bits = black_box(&mut *bits);
+		for idx in 0 .. bits.len() {
+			bits.set(idx, true);
+		}
+	});
+}
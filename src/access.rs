@@ -159,6 +159,40 @@ where <Self as Radium>::Item: BitRegister
 		}
 	}
 
+	/// Writes a value to one bit in a memory register, and returns the value
+	/// that the bit held before the write.
+	///
+	/// This is [`write_bit`], reporting the previous state of the bit from the
+	/// same read-modify-write instruction, so that no other write can occur
+	/// between the read and the write.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A bit ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit in `*self` to write.
+	/// - `value`: The bit value to write into `*self` at `index`.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index` immediately before the write.
+	///
+	/// [`write_bit`]: Self::write_bit
+	fn fetch_write_bit<O>(&self, index: BitIdx<Self::Item>, value: bool) -> bool
+	where O: BitOrder {
+		let sel = index.select::<O>();
+		let prev = if value {
+			self.fetch_or(sel.value(), atomic::Ordering::Relaxed)
+		}
+		else {
+			self.fetch_and(!sel.value(), atomic::Ordering::Relaxed)
+		};
+		BitMask::new(prev).test(sel)
+	}
+
 	/// Gets the function that writes `value` into all bits under a mask.
 	///
 	/// # Parameters
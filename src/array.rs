@@ -248,6 +248,7 @@ where
 	}
 }
 
+mod consts;
 mod iter;
 mod ops;
 mod traits;
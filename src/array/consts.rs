@@ -0,0 +1,109 @@
+/*! Constant values.
+
+A `const` initializer cannot call trait methods, so the all-zero array cannot be
+produced from the generic `V` parameter. Instead, [`BitArray::ZERO`] is defined
+separately for each integer, and for each array of integers that implements
+[`BitView`].
+
+`BitArray` requires its type parameters to implement [`BitOrder`] and
+[`BitView`], and a `const fn` cannot yet carry trait bounds on its parameters,
+so [`BitArray::from_bits`] is likewise defined separately for each of these
+storage shapes, under each of the orderings this crate provides: [`Lsb0`],
+[`Msb0`], and [`HiLo`]. Arrays with any other ordering have [`BitArray::ZERO`],
+but cannot be built from a value in a `const` context. As many such functions
+exist, the array type must be named in full at the call site:
+
+```rust
+use bitvec::prelude::*;
+
+const MASK: BitArray<Msb0, [u8; 2]> =
+  BitArray::<Msb0, [u8; 2]>::from_bits([0xFF, 0x0F]);
+
+assert_eq!(MASK.count_ones(), 12);
+assert!(MASK[.. 8].all());
+assert!(MASK[8 .. 12].not_any());
+```
+
+[`BitArray::ZERO`]: crate::array::BitArray::ZERO
+[`BitArray::from_bits`]: crate::array::BitArray::from_bits
+[`BitOrder`]: crate::order::BitOrder
+[`BitView`]: crate::view::BitView
+[`HiLo`]: crate::order::HiLo
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+!*/
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		HiLo,
+		Lsb0,
+		Msb0,
+	},
+};
+
+use core::marker::PhantomData;
+
+//  Replace with a const-generic once that becomes available.
+macro_rules! consts {
+	(@arrays $store:ty; $($n:expr),+ $(,)?) => { $(
+		consts!(@impl [$store; $n], [0; $n]);
+	)+ };
+
+	(@impl $shape:ty, $zero:expr) => {
+		impl<O> BitArray<O, $shape>
+		where O: BitOrder
+		{
+			/// An array with every bit cleared to `0`.
+			///
+			/// This is usable in `const` and `static` initializers. When the
+			/// array type is not otherwise named, write it out in full, as
+			/// in `BitArray::<Msb0, [u8; 2]>::ZERO`, or with the type form of
+			/// [`bitarr!`].
+			///
+			/// [`bitarr!`]: macro@crate::bitarr
+			pub const ZERO: Self = Self {
+				_ord: PhantomData,
+				data: $zero,
+			};
+		}
+
+		consts!(@from_bits $shape; Lsb0, Msb0, HiLo);
+	};
+
+	(@from_bits $shape:ty; $($ord:ty),+) => { $(
+		impl BitArray<$ord, $shape> {
+			/// Wraps a buffer in a `BitArray`, in a `const` context.
+			///
+			/// This is [`::new()`], usable in `const` and `static`
+			/// initializers. The buffer is the exact memory value of the
+			/// array: each element is viewed under the array’s ordering, so
+			/// `from_bits([0xF0u8])` sets bits `0 .. 4` under `Msb0` and bits
+			/// `4 .. 8` under `Lsb0`.
+			///
+			/// [`::new()`]: Self::new
+			pub const fn from_bits(data: $shape) -> Self {
+				Self {
+					_ord: PhantomData,
+					data,
+				}
+			}
+		}
+	)+ };
+
+	($($store:ty),+ $(,)?) => { $(
+		consts!(@impl $store, 0);
+		consts!(@arrays $store;
+			0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+			19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+			36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+			53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+		);
+	)+ };
+}
+
+consts!(u8, u16, u32, usize);
+
+#[cfg(target_pointer_width = "64")]
+consts!(u64);
@@ -19,6 +19,11 @@ use core::{
 
 /** A by-value [array] iterator.
 
+This is produced by `BitArray`’s [`IntoIterator`] implementation. It owns the
+array, and yields each of its bits as a `bool`, so a `for` loop can consume an
+array without borrowing it. It can be walked from either end, and knows its
+remaining length.
+
 # Original
 
 [`array::IntoIter`](core::array::IntoIter)
@@ -30,8 +35,28 @@ const-generics. The [`BitView`] trait provides a rough simulacrum of
 const-generic arrays until this feature stabilizes for use outside the standard
 libraries.
 
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let arr = bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 0, 0];
+let mut ones = 0;
+for bit in arr {
+  ones += bit as usize;
+}
+assert_eq!(ones, 3);
+
+let mut iter = arr.into_iter();
+assert_eq!(iter.len(), 8);
+assert_eq!(iter.next(), Some(true));
+assert_eq!(iter.next_back(), Some(false));
+assert_eq!(iter.len(), 6);
+```
+
 [array]: crate::array::BitArray
 [`BitView`]: crate::view::BitView
+[`IntoIterator`]: core::iter::IntoIterator
 **/
 #[derive(Clone)]
 pub struct IntoIter<O, V>
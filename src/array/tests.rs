@@ -2,7 +2,10 @@
 
 #![cfg(test)]
 
-use crate::prelude::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
 
 use core::convert::TryInto;
 
@@ -36,6 +39,33 @@ fn wrap_unwrap() {
 	assert_eq!(bits.value(), data);
 }
 
+#[test]
+fn const_construction() {
+	const MASK: BitArray<Msb0, [u8; 2]> =
+		BitArray::<Msb0, [u8; 2]>::from_bits([0xFF, 0x0F]);
+	static LOW: BitArray<Lsb0, u16> = BitArray::<Lsb0, u16>::from_bits(0x00FF);
+	const NIBBLE: BitArray<HiLo, u8> = BitArray::<HiLo, u8>::from_bits(0x0F);
+	const EMPTY: bitarr!(for 40, in Msb0, u32) =
+		<bitarr!(for 40, in Msb0, u32)>::ZERO;
+
+	assert_eq!(MASK.value(), [0xFF, 0x0F]);
+	assert!(MASK[.. 8].all());
+	assert!(MASK[8 .. 12].not_any());
+	assert!(MASK[12 ..].all());
+
+	assert!(LOW[.. 8].all());
+	assert!(LOW[8 ..].not_any());
+
+	assert!(NIBBLE[.. 4].not_any());
+	assert!(NIBBLE[4 ..].all());
+
+	assert_eq!(EMPTY.len(), 64);
+	assert!(EMPTY.not_any());
+	assert_eq!(BitArray::<Lsb0, usize>::ZERO.value(), 0);
+	assert_eq!(BitArray::<Msb0, [u16; 0]>::ZERO.len(), 0);
+	assert_eq!(BitArray::<Msb0, [u8; 64]>::ZERO.count_ones(), 0);
+}
+
 #[test]
 fn views() {
 	let mut arr = bitarr![Msb0, u8; 0; 20];
@@ -72,6 +102,34 @@ fn convert() {
 	assert!((&*bits).try_conv::<&BitArray<LocalBits, usize>>().is_err());
 	assert!(bits.try_conv::<&mut BitArray<LocalBits, usize>>().is_err());
 }
+
+#[test]
+fn into_iter_by_value() {
+	let arr = bitarr![Lsb0, u8; 1, 1, 0, 1, 0, 0, 1, 0];
+
+	let mut seen = 0u8;
+	for (idx, bit) in arr.into_iter().enumerate() {
+		seen |= (bit as u8) << idx;
+	}
+	assert_eq!(seen, arr.value()[0]);
+
+	let mut rev = BitArray::<Lsb0, u8>::zeroed();
+	for (idx, bit) in arr.into_iter().rev().enumerate() {
+		rev.set(idx, bit);
+	}
+	assert_eq!(rev.value(), arr.value()[0].reverse_bits());
+
+	let mut iter = arr.into_iter();
+	assert_eq!(iter.len(), 8);
+	assert_eq!(iter.next(), Some(true));
+	assert_eq!(iter.next_back(), Some(false));
+	assert_eq!(iter.len(), 6);
+	assert_eq!(iter.by_ref().filter(|b| *b).count(), 3);
+	assert_eq!(iter.len(), 0);
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+}
+
 #[test]
 #[allow(deprecated)]
 fn iter() {
@@ -169,6 +227,22 @@ fn traits() {
 	assert!(a.not_any());
 }
 
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_casts() {
+	let mut arr = BitArray::<Lsb0, [u8; 4]>::new([0x12, 0x34, 0x56, 0x78]);
+	assert_eq!(bytemuck::bytes_of(&arr), &[0x12, 0x34, 0x56, 0x78]);
+
+	bytemuck::bytes_of_mut(&mut arr)[1] = 0xFF;
+	assert!(arr[8 .. 16].all());
+
+	let zero: BitArray<Msb0, [u16; 3]> = bytemuck::Zeroable::zeroed();
+	assert!(zero.not_any());
+
+	let cast: BitArray<Lsb0, [u8; 4]> = bytemuck::cast(0xA5A5_A5A5u32);
+	assert_eq!(cast.count_ones(), 16);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn format() {
@@ -404,3 +404,30 @@ where
 	T: BitStore,
 {
 }
+
+/** `BitArray` is a transparent wrapper over its buffer, and the ordering marker
+is zero-sized, so an array of zeroed bytes is a valid all-zero `BitArray`.
+
+This is never implemented for [`BitSlice`] or the heap collections, which hold
+pointers rather than the memory they describe.
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+#[cfg(feature = "bytemuck")]
+unsafe impl<O, V> bytemuck::Zeroable for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView + bytemuck::Zeroable,
+{
+}
+
+/** `BitArray` has exactly the layout and validity of its buffer, so it can be
+cast to and from bytes whenever the buffer can.
+**/
+#[cfg(feature = "bytemuck")]
+unsafe impl<O, V> bytemuck::Pod for BitArray<O, V>
+where
+	O: BitOrder + Copy,
+	V: BitView + bytemuck::Pod,
+{
+}
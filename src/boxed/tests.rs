@@ -44,6 +44,25 @@ fn api() {
 	assert_eq!(bb, bits![0, 1, 0, 1]);
 }
 
+#[test]
+fn vec_round_trip() {
+	let data = [0x5Au8, 0xC3, 0x81];
+	let mut bv = data.view_bits::<Msb0>()[3 .. 21].to_bitvec();
+	bv.reserve(1000);
+	assert!(bv.alloc_capacity() > bv.elements());
+	let head = bv.bitptr().head();
+
+	//  Excess capacity is dropped, and the region is not moved.
+	let bb = bv.into_boxed_bitslice();
+	assert_eq!(bb.bitptr().head(), head);
+	assert_eq!(bb, data.view_bits::<Msb0>()[3 .. 21]);
+
+	let bv = bb.into_bitvec();
+	assert_eq!(bv.alloc_capacity(), bv.elements());
+	assert_eq!(bv.bitptr().head(), head);
+	assert_eq!(bv, data.view_bits::<Msb0>()[3 .. 21]);
+}
+
 #[test]
 fn ops() {
 	let a = bitbox![0, 0, 1, 1];
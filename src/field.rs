@@ -59,9 +59,14 @@ use crate::{
 		DomainMut,
 	},
 	index::BitMask,
-	mem::BitMemory,
+	mem::{
+		BitMemory,
+		BitRegister,
+	},
 	order::{
+		self,
 		BitOrder,
+		HiLo,
 		Lsb0,
 		Msb0,
 	},
@@ -71,8 +76,17 @@ use crate::{
 };
 
 use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	iter,
 	mem,
+	ops::Range,
 	ptr,
+	slice,
 };
 
 use tap::pipe::Pipe;
@@ -241,10 +255,12 @@ pub trait BitField {
 	/// # Panics
 	///
 	/// This method is encouraged to panic if `self` is empty, or wider than a
-	/// single element `M`.
+	/// single element `M`. Use [`.try_load_le()`] to receive these conditions as
+	/// an error instead.
 	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.try_load_le()`]: crate::slice::BitSlice::try_load_le
 	fn load_le<M>(&self) -> M
 	where M: BitMemory;
 
@@ -273,10 +289,12 @@ pub trait BitField {
 	/// # Panics
 	///
 	/// This method is encouraged to panic if `self` is empty, or wider than a
-	/// single element `M`.
+	/// single element `M`. Use [`.try_load_be()`] to receive these conditions as
+	/// an error instead.
 	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.try_load_be()`]: crate::slice::BitSlice::try_load_be
 	fn load_be<M>(&self) -> M
 	where M: BitMemory;
 
@@ -307,10 +325,12 @@ pub trait BitField {
 	/// # Panics
 	///
 	/// This method is encouraged to panic if `self` is empty, or wider than a
-	/// single element `M`.
+	/// single element `M`. Use [`.try_store_le()`] to receive these conditions
+	/// as an error instead.
 	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.try_store_le()`]: crate::slice::BitSlice::try_store_le
 	fn store_le<M>(&mut self, value: M)
 	where M: BitMemory;
 
@@ -341,10 +361,12 @@ pub trait BitField {
 	/// # Panics
 	///
 	/// This method is encouraged to panic if `self` is empty, or wider than a
-	/// single element `M`.
+	/// single element `M`. Use [`.try_store_be()`] to receive these conditions
+	/// as an error instead.
 	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.try_store_be()`]: crate::slice::BitSlice::try_store_be
 	fn store_be<M>(&mut self, value: M)
 	where M: BitMemory;
 }
@@ -356,7 +378,16 @@ where T: BitStore
 	where M: BitMemory {
 		check::<M>("load", self.len());
 
-		match self.domain() {
+		if is_aligned(self) {
+			let value = load_le_aligned::<T, M>(self.as_slice());
+
+			#[cfg(feature = "trace")]
+			trace::emit(trace::FieldAccess::Load, self, value);
+
+			return value;
+		}
+
+		let value = match self.domain() {
 			//  In Lsb0, a `head` index counts distance from LSedge, and a
 			//  `tail` index counts element width minus distance from MSedge.
 			Domain::Enclave { head, elem, tail } => {
@@ -402,7 +433,12 @@ where T: BitStore
 
 				accum
 			},
-		}
+		};
+
+		#[cfg(feature = "trace")]
+		trace::emit(trace::FieldAccess::Load, self, value);
+
+		value
 	}
 
 	fn load_be<M>(&self) -> M
@@ -444,6 +480,14 @@ where T: BitStore
 	where M: BitMemory {
 		check::<M>("store", self.len());
 
+		#[cfg(feature = "trace")]
+		trace::emit(trace::FieldAccess::Store, self, value);
+
+		if is_aligned(self) {
+			store_le_aligned(self, value);
+			return;
+		}
+
 		match self.domain_mut() {
 			DomainMut::Enclave { head, elem, tail } => {
 				set::<T, M>(elem, value, Lsb0::mask(head, tail), head.value());
@@ -512,7 +556,16 @@ where T: BitStore
 	where M: BitMemory {
 		check::<M>("load", self.len());
 
-		match self.domain() {
+		if is_aligned(self) {
+			let value = load_le_aligned::<T, M>(self.as_slice());
+
+			#[cfg(feature = "trace")]
+			trace::emit(trace::FieldAccess::Load, self, value);
+
+			return value;
+		}
+
+		let value = match self.domain() {
 			Domain::Enclave { head, elem, tail } => get::<T, M>(
 				elem,
 				Msb0::mask(head, tail),
@@ -543,7 +596,12 @@ where T: BitStore
 
 				accum
 			},
-		}
+		};
+
+		#[cfg(feature = "trace")]
+		trace::emit(trace::FieldAccess::Load, self, value);
+
+		value
 	}
 
 	fn load_be<M>(&self) -> M
@@ -589,6 +647,14 @@ where T: BitStore
 	where M: BitMemory {
 		check::<M>("store", self.len());
 
+		#[cfg(feature = "trace")]
+		trace::emit(trace::FieldAccess::Store, self, value);
+
+		if is_aligned(self) {
+			store_le_aligned(self, value);
+			return;
+		}
+
 		match self.domain_mut() {
 			DomainMut::Enclave { head, elem, tail } => set::<T, M>(
 				elem,
@@ -658,6 +724,62 @@ where T: BitStore
 	}
 }
 
+/** Nibble-swapped field access.
+
+Each memory element contributes the bits of the slice that it holds, in index
+order, with the lowest index as the least significant bit of its chunk. The
+chunks are then combined by element, as in the `Lsb0` and `Msb0`
+implementations. This is equivalent to `BitSlice<Lsb0, T>` field access over a
+copy of the memory with the nibbles of each byte exchanged.
+**/
+impl<T> BitField for BitSlice<HiLo, T>
+where T: BitStore
+{
+	fn load_le<M>(&self) -> M
+	where M: BitMemory {
+		check::<M>("load", self.len());
+
+		//  Each element holds the chunk of next-higher significance.
+		element_runs(self).fold(M::ZERO, |accum, run| {
+			let shamt = run.start as u8;
+			accum | hilo_get::<T, M>(unsafe { self.get_unchecked(run) }) << shamt
+		})
+	}
+
+	fn load_be<M>(&self) -> M
+	where M: BitMemory {
+		check::<M>("load", self.len());
+
+		//  Each element holds the chunk of next-lower significance.
+		let len = self.len();
+		element_runs(self).fold(M::ZERO, |accum, run| {
+			let shamt = (len - run.end) as u8;
+			accum | hilo_get::<T, M>(unsafe { self.get_unchecked(run) }) << shamt
+		})
+	}
+
+	fn store_le<M>(&mut self, value: M)
+	where M: BitMemory {
+		check::<M>("store", self.len());
+
+		for run in element_runs(self) {
+			let shamt = run.start as u8;
+			hilo_set(unsafe { self.get_unchecked_mut(run) }, value >> shamt);
+		}
+	}
+
+	fn store_be<M>(&mut self, value: M)
+	where M: BitMemory {
+		check::<M>("store", self.len());
+
+		let len = self.len();
+		for run in element_runs(self) {
+			let shamt = (len - run.end) as u8;
+			hilo_set(unsafe { self.get_unchecked_mut(run) }, value >> shamt);
+		}
+	}
+}
+
 impl<O, V> BitField for BitArray<O, V>
 where
 	O: BitOrder,
@@ -741,6 +863,57 @@ where
 	}
 }
 
+/** An error produced by an attempt to transfer a value through a region that
+is not a valid width for that value.
+
+[`BitField`] regions must hold at least one bit, and no more bits than the
+[`M::BITS`] width of the integer being loaded or stored. This error records the
+width of the rejected region and the limit that it exceeded.
+
+[`BitField`]: crate::field::BitField
+[`M::BITS`]: crate::mem::BitMemory::BITS
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BitFieldError {
+	/// The width of the rejected region.
+	len: usize,
+	/// The width of the integer that the region was transferring.
+	max_bits: u8,
+}
+
+impl BitFieldError {
+	/// Gets the width of the rejected region.
+	///
+	/// This is `0` when the region is empty, and otherwise greater than
+	/// [`.max_bits()`].
+	///
+	/// [`.max_bits()`]: Self::max_bits
+	pub fn width(&self) -> usize {
+		self.len
+	}
+
+	/// Gets the width of the integer that the region was transferring, which
+	/// is the widest region that it can use.
+	pub fn max_bits(&self) -> u8 {
+		self.max_bits
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for BitFieldError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"Cannot transfer a {}-bit integer through a {}-bit region",
+			self.max_bits, self.len
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitFieldError {
+}
+
 /// Asserts that a slice length is within a memory element width.
 ///
 /// # Panics
@@ -760,6 +933,117 @@ where M: BitMemory {
 	}
 }
 
+/// Tests that a slice length is within a memory element width.
+///
+/// # Errors
+///
+/// This fails if `len` is 0, or wider than [`M::BITS`].
+///
+/// [`M::BITS`]: crate::mem::BitMemory::BITS
+fn try_check<M>(len: usize) -> Result<(), BitFieldError>
+where M: BitMemory {
+	if (1 ..= M::BITS as usize).contains(&len) {
+		Ok(())
+	}
+	else {
+		Err(BitFieldError {
+			len,
+			max_bits: M::BITS,
+		})
+	}
+}
+
+/// Tests whether a slice exactly covers the memory elements it touches.
+///
+/// Such a slice starts at the zero index of its first element and fills its
+/// last element, so `BitField` transfers through it need no masks or shifts.
+fn is_aligned<O, T>(bits: &BitSlice<O, T>) -> bool
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits.bitptr().head().value() == 0
+		&& bits.len() % T::Mem::BITS as usize == 0
+}
+
+/// Loads a little-endian value from whole memory elements.
+///
+/// This is the body loop of the `Domain::Region` arm of `.load_le()`, for
+/// slices with neither a partial head nor a partial tail element.
+fn load_le_aligned<T, M>(elts: &[T]) -> M
+where
+	T: BitStore,
+	M: BitMemory,
+{
+	let mut accum = M::ZERO;
+	for elem in elts.iter().rev().map(BitStore::load_value) {
+		if M::BITS > T::Mem::BITS {
+			accum <<= T::Mem::BITS;
+		}
+		accum |= resize::<T::Mem, M>(elem);
+	}
+	accum
+}
+
+/// Stores a little-endian value into whole memory elements.
+///
+/// This is the body loop of the `DomainMut::Region` arm of `.store_le()`, for
+/// slices with neither a partial head nor a partial tail element. The caller
+/// must ensure that [`is_aligned`] holds for `bits`.
+///
+/// [`is_aligned`]: self::is_aligned
+fn store_le_aligned<O, T, M>(bits: &mut BitSlice<O, T>, mut value: M)
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	debug_assert!(is_aligned(bits), "Slice does not span whole elements");
+	let bitptr = bits.bitptr();
+	/* The slice fully spans its elements, so no other handle can observe
+	them, and they may be written without alias protection. This is the same
+	reasoning that `.domain_mut()` uses to produce its `body` slice.
+	*/
+	let elts = unsafe {
+		slice::from_raw_parts_mut(
+			bitptr.pointer().to_mut() as *mut T::Unalias,
+			bitptr.elements(),
+		)
+	};
+	for elem in elts.iter_mut() {
+		elem.store_value(resize(value));
+		if M::BITS > T::Mem::BITS {
+			value >>= T::Mem::BITS;
+		}
+	}
+}
+
+/// Divides a slice into the ranges of its indices that share a memory element.
+///
+/// Each range can be transferred by a single `BitField` access, which resolves
+/// to one `get` or `set` of the element. The ranges are produced in index
+/// order, and the first and last may be shorter than an element.
+fn element_runs<O, T>(
+	bits: &BitSlice<O, T>,
+) -> impl Iterator<Item = Range<usize>>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let width = T::Mem::BITS as usize;
+	let len = bits.len();
+	let first = cmp::min(width - bits.bitptr().head().value() as usize, len);
+	iter::successors(Some(0 .. first), move |prev| {
+		let start = prev.end;
+		if start < len {
+			Some(start .. cmp::min(start + width, len))
+		}
+		else {
+			None
+		}
+	})
+}
+
 /** Reads a value out of a section of a memory element.
 
 This function is used to extract a portion of an `M` value from a portion of a
@@ -877,6 +1161,55 @@ where
 	elem.set_bits(value);
 }
 
+/// Reads the bits of a `HiLo` slice that lie in a single memory element.
+///
+/// # Returns
+///
+/// The bits of `bits`, in index order, with the bit at index `0` as the least
+/// significant bit of the returned value.
+fn hilo_get<T, M>(bits: &BitSlice<HiLo, T>) -> M
+where
+	T: BitStore,
+	M: BitMemory,
+{
+	let bitptr = bits.bitptr();
+	let elem = unsafe { &*bitptr.pointer().to_const() }.load_value();
+	let mask = Lsb0::mask(bitptr.head(), bitptr.tail());
+	resize::<T::Mem, M>(hilo_take(elem, mask, bitptr.head().value()))
+}
+
+/// Writes the low `bits.len()` bits of `value` into a `HiLo` slice that lies
+/// in a single memory element.
+///
+/// This is the inverse of `hilo_get`.
+fn hilo_set<T, M>(bits: &mut BitSlice<HiLo, T>, value: M)
+where
+	T: BitStore,
+	M: BitMemory,
+{
+	let bitptr = bits.bitptr();
+	//  Convert the `mask` type to fit into the accessor.
+	let mask = BitMask::new(HiLo::mask(bitptr.head(), bitptr.tail()).value());
+	let value = hilo_place(resize::<M, T::Mem>(value), bitptr.head().value());
+	let elem = unsafe { &*bitptr.pointer().to_access() };
+	elem.clear_bits(mask);
+	elem.set_bits(mask & value);
+}
+
+/// Moves the `Lsb0`-masked bits of a nibble-swapped element down to the least
+/// significant edge.
+fn hilo_take<R>(elem: R, mask: BitMask<R>, shamt: u8) -> R
+where R: BitRegister {
+	//  Exchanging the nibbles moves each index `n` to bit `n`, as in `Lsb0`.
+	(mask & order::swap_nibbles(elem)).value() >> shamt
+}
+
+/// Moves the low bits of `value` up to `shamt`, and then into `HiLo` positions.
+fn hilo_place<R>(value: R, shamt: u8) -> R
+where R: BitRegister {
+	order::swap_nibbles(value << shamt)
+}
+
 /** Resizes a value from one register width to another.
 
 This zero-extends or truncates its source value in order to fit in the target
@@ -958,9 +1291,51 @@ compile_fail!(concat!(
 	env!(CARGO_PKG_REPOSITORY)
 ));
 
+mod blit;
+mod bytes;
+mod checked;
+mod color;
+mod cursor;
+
+#[cfg(feature = "alloc")]
+mod encoder;
+
+mod export;
+mod fallible;
+mod gray;
+
 #[cfg(feature = "std")]
 mod io;
 
+mod morton;
+mod prefix;
+mod radix;
+mod saturate;
+mod signed;
+mod split;
+mod stream;
+mod time;
+
+#[cfg(feature = "trace")]
+pub mod trace;
+
+pub use self::blit::BlitOp;
+
+pub use self::checked::OverflowError;
+
+pub use self::cursor::{
+	BitFieldCursor,
+	BitFieldCursorMut,
+};
+
+#[cfg(feature = "alloc")]
+pub use self::encoder::FieldEncoder;
+
+pub use self::stream::BitReader;
+
+#[cfg(feature = "alloc")]
+pub use self::stream::BitWriter;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,147 @@
+/*! Bitmap block transfers.
+
+[`BitSlice::blit`] treats two slices as row-major bitmaps, and combines a
+rectangular block of one into the other with a [`BlitOp`] raster operation.
+
+[`BitSlice::blit`]: crate::slice::BitSlice::blit
+[`BlitOp`]: self::BlitOp
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::cmp;
+
+/// Bitmap block transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Transfers a rectangular block of bits from one two-dimensional bitmap
+	/// into another.
+	///
+	/// Both `self` and `src` are read as bitmaps stored in row-major order,
+	/// where each row begins `stride` bits after the previous one. The block
+	/// is `width` bits wide and `height` rows tall, and each of its bits in
+	/// `self` is combined with the corresponding bit in `src` according to
+	/// `op`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: The destination bitmap.
+	/// - `dst_stride`: The distance, in bits, between rows of `self`.
+	/// - `dst_x`, `dst_y`: The column and row in `self` of the top left corner
+	///   of the block.
+	/// - `src`: The source bitmap. The block is read from its top left corner;
+	///   subslice `src` to move that corner.
+	/// - `src_stride`: The distance, in bits, between rows of `src`.
+	/// - `width`, `height`: The dimensions of the block.
+	/// - `op`: The raster operation used to combine the source block into the
+	///   destination.
+	///
+	/// # Behavior
+	///
+	/// Neither bitmap needs its rows, or the block, to begin at an element
+	/// edge. Each row of the block is transferred in `usize`-wide segments
+	/// with [`.load_le()`] and [`.store_le()`], which shift the bits between
+	/// their positions in `src` and in `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if the block does not fit within either bitmap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BlitOp;
+	///
+	/// //  An 8x2 source pattern, and a 16x4 screen.
+	/// let sprite = bits![Msb0, u8;
+	///   1, 1, 1, 1, 0, 0, 0, 0,
+	///   1, 0, 1, 0, 1, 0, 1, 0,
+	/// ];
+	/// let mut screen = [0u16; 4];
+	/// let fb = screen.view_bits_mut::<Msb0>();
+	///
+	/// fb.blit(16, 3, 1, sprite, 8, 8, 2, BlitOp::Copy);
+	/// assert_eq!(screen, [
+	///   0,
+	///   0b0001_1110_0000_0000,
+	///   0b0001_0101_0100_0000,
+	///   0,
+	/// ]);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[allow(clippy::too_many_arguments)]
+	pub fn blit(
+		&mut self,
+		dst_stride: usize,
+		dst_x: usize,
+		dst_y: usize,
+		src: &Self,
+		src_stride: usize,
+		width: usize,
+		height: usize,
+		op: BlitOp,
+	)
+	{
+		let seg = <usize as BitMemory>::BITS as usize;
+		for row in 0 .. height {
+			let dst = &mut self[(dst_y + row) * dst_stride + dst_x ..][.. width];
+			let src = &src[row * src_stride ..][.. width];
+			if let BlitOp::Copy = op {
+				dst.copy_from_bitslice(src);
+				continue;
+			}
+			let mut start = 0;
+			while start < width {
+				let end = cmp::min(start + seg, width);
+				let (d, s) = (&mut dst[start .. end], &src[start .. end]);
+				let (a, b) = (d.load_le::<usize>(), s.load_le::<usize>());
+				d.store_le::<usize>(op.apply(a, b));
+				start = end;
+			}
+		}
+	}
+}
+
+/** Raster operations for combining bitmaps with [`BitSlice::blit`].
+
+Each operation describes how a bit of the source block is combined with the
+destination bit it lands on.
+
+[`BitSlice::blit`]: crate::slice::BitSlice::blit
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BlitOp {
+	/// The destination bit is replaced by the source bit.
+	Copy,
+	/// The destination bit is set if either bit is set.
+	Or,
+	/// The destination bit is set if both bits are set.
+	And,
+	/// The destination bit is set if exactly one bit is set.
+	Xor,
+}
+
+impl BlitOp {
+	/// Combines a segment of destination bits with a segment of source bits.
+	fn apply(self, dst: usize, src: usize) -> usize {
+		match self {
+			Self::Copy => src,
+			Self::Or => dst | src,
+			Self::And => dst & src,
+			Self::Xor => dst ^ src,
+		}
+	}
+}
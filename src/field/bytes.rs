@@ -0,0 +1,181 @@
+/*! Byte-buffer transfers.
+
+These move a region that is wider than any register through a byte buffer, in
+either big-endian or little-endian byte order.
+!*/
+
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+/// Byte-buffer transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a big-endian field of any width into a byte buffer.
+	///
+	/// [`.load_be()`] cannot produce a value wider than `u128`. This produces
+	/// the integer that `.load_be()` would if it had an arbitrarily wide
+	/// destination type, and writes it into `bytes` most significant byte
+	/// first.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bytes`: The destination buffer. The field is written into its last
+	///   `self.len()` bits, and all bits before the field are cleared.
+	///
+	/// # Behavior
+	///
+	/// The slice is divided at the edges of its memory elements, and each part
+	/// is moved with [`.load_be()`] and [`.store_be()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `bytes.len() * 8`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let mut bytes = [0xFFu8; 4];
+	/// data.view_bits::<Msb0>().load_be_bytes(&mut bytes);
+	/// assert_eq!(bytes, [0x00, 0x12, 0x34, 0x56]);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn load_be_bytes(&self, bytes: &mut [u8]) {
+		check_bytes("load", self.len(), bytes.len());
+		let dst = bytes.view_bits_mut::<Msb0>();
+		dst.set_all(false);
+		let base = dst.len() - self.len();
+		for run in super::element_runs(self) {
+			let value = self[run.clone()].load_be::<u64>();
+			dst[base + run.start .. base + run.end].store_be(value);
+		}
+	}
+
+	/// Loads a little-endian field of any width into a byte buffer.
+	///
+	/// This is [`.load_be_bytes()`], using [`.load_le()`] to read the field
+	/// and writing it into `bytes` least significant byte first. The field is
+	/// written into the first `self.len()` bits of `bytes`, and all bits after
+	/// the field are cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `bytes.len() * 8`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let mut bytes = [0xFFu8; 4];
+	/// data.view_bits::<Lsb0>()[4 ..].load_le_bytes(&mut bytes);
+	/// assert_eq!(bytes, [0x41, 0x63, 0x05, 0x00]);
+	/// ```
+	///
+	/// [`.load_be_bytes()`]: Self::load_be_bytes
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	pub fn load_le_bytes(&self, bytes: &mut [u8]) {
+		check_bytes("load", self.len(), bytes.len());
+		let dst = bytes.view_bits_mut::<Lsb0>();
+		dst.set_all(false);
+		for run in super::element_runs(self) {
+			let value = self[run.clone()].load_le::<u64>();
+			dst[run].store_le(value);
+		}
+	}
+
+	/// Stores a big-endian field of any width from a byte buffer.
+	///
+	/// This is the inverse of [`.load_be_bytes()`]: `bytes` holds an integer,
+	/// most significant byte first, and its low `self.len()` bits are written
+	/// as though by [`.store_be()`] with an arbitrarily wide source type. Any
+	/// higher bits in `bytes` are discarded.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `bytes.len() * 8`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// data.view_bits_mut::<Msb0>()[4 ..].store_be_bytes(&[0xAB, 0xCD, 0xEF]);
+	/// assert_eq!(data, [0x0B, 0xCD, 0xEF]);
+	/// ```
+	///
+	/// [`.load_be_bytes()`]: Self::load_be_bytes
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn store_be_bytes(&mut self, bytes: &[u8]) {
+		check_bytes("store", self.len(), bytes.len());
+		let src = bytes.view_bits::<Msb0>();
+		let base = src.len() - self.len();
+		for run in super::element_runs(self) {
+			let value = src[base + run.start .. base + run.end].load_be::<u64>();
+			self[run].store_be(value);
+		}
+	}
+
+	/// Stores a little-endian field of any width from a byte buffer.
+	///
+	/// This is the inverse of [`.load_le_bytes()`]: `bytes` holds an integer,
+	/// least significant byte first, and its low `self.len()` bits are written
+	/// as though by [`.store_le()`] with an arbitrarily wide source type. Any
+	/// higher bits in `bytes` are discarded.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `bytes.len() * 8`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// data.view_bits_mut::<Lsb0>()[4 ..].store_le_bytes(&[0x41, 0x63, 0x05]);
+	/// assert_eq!(data, [0x10, 0x34, 0x56]);
+	/// ```
+	///
+	/// [`.load_le_bytes()`]: Self::load_le_bytes
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn store_le_bytes(&mut self, bytes: &[u8]) {
+		check_bytes("store", self.len(), bytes.len());
+		let src = bytes.view_bits::<Lsb0>();
+		for run in super::element_runs(self) {
+			let value = src[run.clone()].load_le::<u64>();
+			self[run].store_le(value);
+		}
+	}
+}
+
+/// Asserts that a slice length fits in a byte buffer.
+///
+/// # Panics
+///
+/// This panics if `len` is 0, or wider than `bytes * 8`.
+fn check_bytes(action: &'static str, len: usize, bytes: usize) {
+	if !(1 ..= bytes * 8).contains(&len) {
+		panic!("Cannot {} {} bytes from a {}-bit region", action, bytes, len);
+	}
+}
@@ -0,0 +1,221 @@
+/*! Stores that refuse to truncate.
+
+The [`BitField`] stores keep only the low bits of a value that is wider than
+its destination. The checked stores here report such a value as an
+[`OverflowError`] instead, and leave the destination unchanged.
+
+[`BitField`]: crate::field::BitField
+[`OverflowError`]: self::OverflowError
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/// Checked stores.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores a value into the slice, failing instead of truncating it when it
+	/// does not fit.
+	///
+	/// This calls [`.checked_store_le()`] on little-endian byte-ordered CPUs,
+	/// and [`.checked_store_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// # Errors
+	///
+	/// This returns an [`OverflowError`], and writes nothing, if `value` has
+	/// any bits set at or above `self.len()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// [`OverflowError`]: crate::field::OverflowError
+	/// [`.checked_store_be()`]: Self::checked_store_be
+	/// [`.checked_store_le()`]: Self::checked_store_le
+	pub fn checked_store<M>(&mut self, value: M) -> Result<(), OverflowError<M>>
+	where M: BitMemory {
+		#[cfg(target_endian = "little")]
+		return self.checked_store_le(value);
+
+		#[cfg(target_endian = "big")]
+		return self.checked_store_be(value);
+	}
+
+	/// Stores a value into the slice as a little-endian field, failing instead
+	/// of truncating it when it does not fit.
+	///
+	/// [`.store_le()`] writes only the low `self.len()` bits of `value`, and
+	/// silently discards the rest. This first checks that the discarded bits
+	/// are all zero, so that a caller driving a field with a value too large
+	/// for it finds out.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer being stored.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to store.
+	///
+	/// # Errors
+	///
+	/// This returns an [`OverflowError`], and writes nothing, if `value` has
+	/// any bits set at or above `self.len()`. The error carries `value` and
+	/// the width of the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`, as
+	/// [`.store_le()`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 10];
+	///
+	/// assert!(bits.checked_store_le(63u8).is_ok());
+	/// assert_eq!(data, 0x03F0);
+	///
+	/// let err = bits.checked_store_le(64u8).unwrap_err();
+	/// assert_eq!(err.value(), 64);
+	/// assert_eq!(err.width(), 6);
+	/// assert_eq!(data, 0x03F0);
+	/// ```
+	///
+	/// [`OverflowError`]: crate::field::OverflowError
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn checked_store_le<M>(
+		&mut self,
+		value: M,
+	) -> Result<(), OverflowError<M>>
+	where
+		M: BitMemory,
+	{
+		check_overflow(value, self.len())?;
+		self.store_le(value);
+		Ok(())
+	}
+
+	/// Stores a value into the slice as a big-endian field, failing instead
+	/// of truncating it when it does not fit.
+	///
+	/// This is [`.checked_store_le()`], using [`.store_be()`] to write the
+	/// field.
+	///
+	/// # Errors
+	///
+	/// This returns an [`OverflowError`], and writes nothing, if `value` has
+	/// any bits set at or above `self.len()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// [`OverflowError`]: crate::field::OverflowError
+	/// [`.checked_store_le()`]: Self::checked_store_le
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn checked_store_be<M>(
+		&mut self,
+		value: M,
+	) -> Result<(), OverflowError<M>>
+	where
+		M: BitMemory,
+	{
+		check_overflow(value, self.len())?;
+		self.store_be(value);
+		Ok(())
+	}
+}
+
+/** An error produced by an attempt to store a value into a region too narrow
+to hold it.
+
+The checked stores, such as [`BitSlice::checked_store_le`], refuse to truncate
+their value. This error records the value that was refused and the width of the
+region, which is the number of low bits of the value that it could hold.
+
+# Type Parameters
+
+- `M`: The integer that was being stored.
+
+[`BitSlice::checked_store_le`]: crate::slice::BitSlice::checked_store_le
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OverflowError<M>
+where M: BitMemory
+{
+	/// The value that did not fit.
+	value: M,
+	/// The width of the region.
+	width: usize,
+}
+
+impl<M> OverflowError<M>
+where M: BitMemory
+{
+	/// Gets the value that did not fit in the region.
+	pub fn value(&self) -> M {
+		self.value
+	}
+
+	/// Gets the width of the region. `value` has at least one bit set at or
+	/// above this index.
+	pub fn width(&self) -> usize {
+		self.width
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<M> Display for OverflowError<M>
+where M: BitMemory
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"Cannot store {:#x} in a {}-bit region without truncation",
+			self.value, self.width
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<M> std::error::Error for OverflowError<M> where M: BitMemory
+{
+}
+
+/// Tests that a value fits in a `width`-bit region.
+///
+/// # Panics
+///
+/// This panics if `width` is zero or greater than `M::BITS`.
+fn check_overflow<M>(value: M, width: usize) -> Result<(), OverflowError<M>>
+where M: BitMemory {
+	super::check::<M>("store", width);
+	if width < M::BITS as usize && value >> width as u8 != M::ZERO {
+		return Err(OverflowError { value, width });
+	}
+	Ok(())
+}
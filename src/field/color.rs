@@ -0,0 +1,197 @@
+/*! Packed pixel formats.
+
+These store eight-bit color channels into the 16-bit RGB565 and RGB555 formats,
+quantizing each channel to the width of its field, and widen them back when
+loading.
+!*/
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Packed-pixel transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores an 8-bit-per-channel color into the slice as an RGB565 pixel.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `r`, `g`, `b`: The red, green, and blue channels of the color.
+	///
+	/// # Behavior
+	///
+	/// Each channel is quantized to the nearest value of its packed width:
+	/// five bits for red and blue, and six bits for green. The pixel holds
+	/// `r << 11 | g << 5 | b`, written with [`.store_le::<u16>()`], so red
+	/// occupies the high bits of the field and blue the low bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut pixel = 0u16;
+	/// let bits = pixel.view_bits_mut::<Lsb0>();
+	///
+	/// bits.store_rgb565(255, 128, 0);
+	/// assert_eq!(pixel, 0b11111_100000_00000);
+	/// ```
+	///
+	/// [`.store_le::<u16>()`]: crate::field::BitField::store_le
+	pub fn store_rgb565(&mut self, r: u8, g: u8, b: u8) {
+		check_pixel("RGB565", 16, self.len());
+		self.store_le::<u16>(
+			quantize(r, 5) << 11 | quantize(g, 6) << 5 | quantize(b, 5),
+		);
+	}
+
+	/// Loads an RGB565 pixel from the slice as an 8-bit-per-channel color.
+	///
+	/// This is the inverse of [`.store_rgb565()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The red, green, and blue channels of the pixel. Each packed channel is
+	/// widened to eight bits by replicating its high bits into the new low
+	/// bits, so that a zero channel loads as `0` and a full channel loads as
+	/// `255`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let pixel = 0b11111_100000_00000u16;
+	/// assert_eq!(pixel.view_bits::<Lsb0>().load_rgb565(), (255, 130, 0));
+	/// ```
+	///
+	/// [`.store_rgb565()`]: Self::store_rgb565
+	pub fn load_rgb565(&self) -> (u8, u8, u8) {
+		check_pixel("RGB565", 16, self.len());
+		let pixel = self.load_le::<u16>();
+		(
+			expand(pixel >> 11, 5),
+			expand(pixel >> 5 & 0x3F, 6),
+			expand(pixel & 0x1F, 5),
+		)
+	}
+
+	/// Stores an 8-bit-per-channel color into the slice as an RGB555 pixel.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `r`, `g`, `b`: The red, green, and blue channels of the color.
+	///
+	/// # Behavior
+	///
+	/// Each channel is quantized to the nearest five-bit value. The pixel
+	/// holds `r << 10 | g << 5 | b`, written with [`.store_le::<u16>()`].
+	///
+	/// Formats that pad RGB555 pixels to sixteen bits leave the padding bit
+	/// out of the slice passed to this method.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is not 15.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut pixel = 0x8000u16;
+	/// let bits = &mut pixel.view_bits_mut::<Lsb0>()[.. 15];
+	///
+	/// bits.store_rgb555(255, 128, 0);
+	/// assert_eq!(bits.load_rgb555(), (255, 132, 0));
+	/// assert_eq!(pixel, 0x8000 | 0b11111_10000_00000);
+	/// ```
+	///
+	/// [`.store_le::<u16>()`]: crate::field::BitField::store_le
+	pub fn store_rgb555(&mut self, r: u8, g: u8, b: u8) {
+		check_pixel("RGB555", 15, self.len());
+		self.store_le::<u16>(
+			quantize(r, 5) << 10 | quantize(g, 5) << 5 | quantize(b, 5),
+		);
+	}
+
+	/// Loads an RGB555 pixel from the slice as an 8-bit-per-channel color.
+	///
+	/// This is the inverse of [`.store_rgb555()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The red, green, and blue channels of the pixel, each widened from five
+	/// to eight bits by replicating its high bits into the new low bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is not 15.
+	///
+	/// [`.store_rgb555()`]: Self::store_rgb555
+	pub fn load_rgb555(&self) -> (u8, u8, u8) {
+		check_pixel("RGB555", 15, self.len());
+		let pixel = self.load_le::<u16>();
+		(
+			expand(pixel >> 10, 5),
+			expand(pixel >> 5 & 0x1F, 5),
+			expand(pixel & 0x1F, 5),
+		)
+	}
+}
+
+/// Asserts that a slice is exactly as wide as a packed pixel format.
+///
+/// # Panics
+///
+/// This panics if `len` is not `width`.
+fn check_pixel(format: &'static str, width: usize, len: usize) {
+	assert!(
+		len == width,
+		"Cannot use a {}-bit slice as a {}-bit {} pixel",
+		len,
+		width,
+		format
+	);
+}
+
+/// Quantizes an eight-bit color channel to the nearest `bits`-bit value.
+fn quantize(channel: u8, bits: u32) -> u16 {
+	let max = (1u16 << bits) - 1;
+	(channel as u16 * max + 127) / 255
+}
+
+/// Widens a `bits`-bit color channel to eight bits.
+///
+/// The channel is shifted to the top of the byte, and its high bits are
+/// repeated in the vacated low bits. This maps the full range of the narrow
+/// channel onto the full range of the byte, and `quantize` maps each result
+/// back to the value it came from.
+fn expand(value: u16, bits: u32) -> u8 {
+	(value << (8 - bits) | value >> (2 * bits - 8)) as u8
+}
@@ -0,0 +1,357 @@
+/*! Sequential field access.
+
+Packed structures are usually decoded by computing the range of each field by
+hand, and loading each subslice in turn. [`BitFieldCursor`] does that
+bookkeeping: it holds a position in a [`BitSlice`], and each read loads the
+field that begins at the position and then advances past it.
+[`BitFieldCursorMut`] does the same for writes.
+
+[`BitFieldCursor`]: self::BitFieldCursor
+[`BitFieldCursorMut`]: self::BitFieldCursorMut
+[`BitSlice`]: crate::slice::BitSlice
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Cursor construction.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Creates a cursor that reads consecutive fields from the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A cursor positioned at the front of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x5Au8, 0x3C];
+	/// let mut cursor = data.view_bits::<Lsb0>().field_cursor();
+	///
+	/// assert_eq!(cursor.read_le::<u8>(4), 0xA);
+	/// assert_eq!(cursor.read_le::<u8>(4), 0x5);
+	/// assert_eq!(cursor.read_le::<u8>(8), 0x3C);
+	/// assert_eq!(cursor.remaining(), 0);
+	/// ```
+	pub fn field_cursor(&self) -> BitFieldCursor<O, T> {
+		BitFieldCursor { bits: self, pos: 0 }
+	}
+
+	/// Creates a cursor that writes consecutive fields into the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A cursor positioned at the front of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let mut cursor = data.view_bits_mut::<Lsb0>().field_cursor_mut();
+	///
+	/// cursor.write_le(0x5u8, 3);
+	/// cursor.write_le(0x1FFu16, 9);
+	/// assert_eq!(cursor.position(), 12);
+	/// assert_eq!(data, 0x1FF << 3 | 0x5);
+	/// ```
+	pub fn field_cursor_mut(&mut self) -> BitFieldCursorMut<O, T> {
+		BitFieldCursorMut { bits: self, pos: 0 }
+	}
+}
+
+/** Reads consecutive fields from a [`BitSlice`].
+
+This is created by [`BitSlice::field_cursor`].
+
+# Lifetimes
+
+- `'a`: The lifetime of the slice being read.
+
+# Type Parameters
+
+- `O`: The ordering of the slice. It must be one of the orderings for which
+  `BitSlice` implements [`BitField`].
+- `T`: The storage type of the slice.
+
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::field_cursor`]: crate::slice::BitSlice::field_cursor
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct BitFieldCursor<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// The slice being read.
+	bits: &'a BitSlice<O, T>,
+	/// The index in `bits` at which the next field begins.
+	pos: usize,
+}
+
+impl<'a, O, T> BitFieldCursor<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Gets the index at which the next field begins.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits after the cursor.
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Reads a field with [`.load_le()`], and advances past it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The width of the field.
+	///
+	/// # Returns
+	///
+	/// The value of the `width` bits after the cursor.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.remaining()`]: Self::remaining
+	pub fn read_le<M>(&mut self, width: usize) -> M
+	where M: BitMemory {
+		super::check::<M>("load", width);
+		self.take(width).load_le::<M>()
+	}
+
+	/// Reads a field with [`.load_be()`], and advances past it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The width of the field.
+	///
+	/// # Returns
+	///
+	/// The value of the `width` bits after the cursor.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.remaining()`]: Self::remaining
+	pub fn read_be<M>(&mut self, width: usize) -> M
+	where M: BitMemory {
+		super::check::<M>("load", width);
+		self.take(width).load_be::<M>()
+	}
+
+	/// Advances past bits without reading them.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than [`.remaining()`].
+	///
+	/// [`.remaining()`]: Self::remaining
+	pub fn skip(&mut self, width: usize) {
+		self.take(width);
+	}
+
+	/// Gets all of the bits after the cursor, without advancing past them.
+	pub(crate) fn rest(&self) -> &'a BitSlice<O, T> {
+		unsafe { self.bits.get_unchecked(self.pos ..) }
+	}
+
+	/// Gets the `width` bits after the cursor, and advances past them.
+	fn take(&mut self, width: usize) -> &'a BitSlice<O, T> {
+		check_remaining(width, self.remaining());
+		let start = self.pos;
+		self.pos += width;
+		unsafe { self.bits.get_unchecked(start .. self.pos) }
+	}
+}
+
+/** Writes consecutive fields into a [`BitSlice`].
+
+This is created by [`BitSlice::field_cursor_mut`].
+
+# Lifetimes
+
+- `'a`: The lifetime of the slice being written.
+
+# Type Parameters
+
+- `O`: The ordering of the slice. It must be one of the orderings for which
+  `BitSlice` implements [`BitField`].
+- `T`: The storage type of the slice.
+
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::field_cursor_mut`]: crate::slice::BitSlice::field_cursor_mut
+**/
+#[derive(Debug)]
+pub struct BitFieldCursorMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// The slice being written.
+	bits: &'a mut BitSlice<O, T>,
+	/// The index in `bits` at which the next field begins.
+	pos: usize,
+}
+
+impl<'a, O, T> BitFieldCursorMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Gets the index at which the next field begins.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits after the cursor.
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Writes a field with [`.store_le()`], and advances past it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to write. Any bits of it above `width` are
+	///   discarded.
+	/// - `width`: The width of the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.remaining()`]: Self::remaining
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn write_le<M>(&mut self, value: M, width: usize)
+	where M: BitMemory {
+		super::check::<M>("store", width);
+		self.take(width).store_le::<M>(value);
+	}
+
+	/// Writes a field with [`.store_be()`], and advances past it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to write. Any bits of it above `width` are
+	///   discarded.
+	/// - `width`: The width of the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.remaining()`]: Self::remaining
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn write_be<M>(&mut self, value: M, width: usize)
+	where M: BitMemory {
+		super::check::<M>("store", width);
+		self.take(width).store_be::<M>(value);
+	}
+
+	/// Reads a field with [`.load_le()`], and advances past it.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.remaining()`]: Self::remaining
+	pub fn read_le<M>(&mut self, width: usize) -> M
+	where M: BitMemory {
+		super::check::<M>("load", width);
+		self.take(width).load_le::<M>()
+	}
+
+	/// Reads a field with [`.load_be()`], and advances past it.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`, or if it is
+	/// greater than [`.remaining()`]. The cursor does not move if it panics.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.remaining()`]: Self::remaining
+	pub fn read_be<M>(&mut self, width: usize) -> M
+	where M: BitMemory {
+		super::check::<M>("load", width);
+		self.take(width).load_be::<M>()
+	}
+
+	/// Advances past bits without writing them.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than [`.remaining()`].
+	///
+	/// [`.remaining()`]: Self::remaining
+	pub fn skip(&mut self, width: usize) {
+		self.take(width);
+	}
+
+	/// Gets the `width` bits after the cursor, and advances past them.
+	fn take(&mut self, width: usize) -> &mut BitSlice<O, T> {
+		check_remaining(width, self.remaining());
+		let start = self.pos;
+		self.pos += width;
+		unsafe { self.bits.get_unchecked_mut(start .. self.pos) }
+	}
+}
+
+/// Asserts that a field fits in the bits after a cursor.
+///
+/// # Panics
+///
+/// This panics if `width` is greater than `remaining`.
+fn check_remaining(width: usize, remaining: usize) {
+	assert!(
+		width <= remaining,
+		"Cannot access a {}-bit field with {} bits remaining",
+		width,
+		remaining
+	);
+}
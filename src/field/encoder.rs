@@ -0,0 +1,189 @@
+/*! Incremental construction of bit-packed messages.
+
+Messages with heterogeneous fields are usually built by tracking a write cursor
+by hand, and storing each field into the subslice after it. [`FieldEncoder`]
+does that bookkeeping: each push appends one field to the end of a growing
+[`BitVec`], and [`.finish()`] produces the completed message.
+
+[`BitVec`]: crate::vec::BitVec
+[`FieldEncoder`]: self::FieldEncoder
+[`.finish()`]: self::FieldEncoder::finish
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/** Builds a bit-vector by appending fields of arbitrary widths.
+
+Each method takes the encoder by value and returns it, so that a whole message
+can be written as a single chain of calls.
+
+# Type Parameters
+
+- `O`: The ordering of the produced bit-vector. It must be one of the orderings
+  for which `BitSlice` implements [`BitField`].
+- `T`: The storage type of the produced bit-vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::FieldEncoder;
+
+let msg = FieldEncoder::<Lsb0, u8>::new()
+  .push_le(0xAu8, 4)
+  .push_le(0x123u16, 12)
+  .push_bool(true)
+  .finish();
+
+assert_eq!(msg.len(), 17);
+assert_eq!(msg[.. 4].load_le::<u8>(), 0xA);
+assert_eq!(msg[4 .. 16].load_le::<u16>(), 0x123);
+assert!(msg[16]);
+```
+
+[`BitField`]: crate::field::BitField
+**/
+#[derive(Clone, Debug)]
+pub struct FieldEncoder<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The fields written so far.
+	bits: BitVec<O, T>,
+}
+
+impl<O, T> FieldEncoder<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Creates an empty encoder.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates an empty encoder with room for at least `bits` bits before it
+	/// must reallocate.
+	///
+	/// Use this when the total width of the message is known in advance.
+	pub fn with_capacity(bits: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(bits),
+		}
+	}
+
+	/// Gets the number of bits written so far.
+	///
+	/// This is the index at which the next pushed field will begin.
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests whether no bits have been written.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Appends a field, written with [`.store_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `value`: The value of the field. Any bits of it above `width` are
+	///   discarded.
+	/// - `width`: The width of the field, in bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn push_le<M>(mut self, value: M, width: usize) -> Self
+	where M: BitMemory {
+		self.grow(width).store_le::<M>(value);
+		self
+	}
+
+	/// Appends a field, written with [`.store_be()`].
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `value`: The value of the field. Any bits of it above `width` are
+	///   discarded.
+	/// - `width`: The width of the field, in bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn push_be<M>(mut self, value: M, width: usize) -> Self
+	where M: BitMemory {
+		self.grow(width).store_be::<M>(value);
+		self
+	}
+
+	/// Appends a single bit.
+	pub fn push_bool(mut self, value: bool) -> Self {
+		self.bits.push(value);
+		self
+	}
+
+	/// Appends a copy of a bit-slice, bit for bit.
+	///
+	/// The segment may have any ordering or storage type; its bits are
+	/// appended in index order.
+	pub fn push_bits<O2, T2>(mut self, bits: &BitSlice<O2, T2>) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.extend_from_bitslice(bits);
+		self
+	}
+
+	/// Finishes the message.
+	///
+	/// # Returns
+	///
+	/// A bit-vector containing every pushed field, in the order they were
+	/// pushed.
+	pub fn finish(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Extends the message by a `width`-bit field, and returns that field.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero. Widths greater than the caller’s
+	/// register are rejected by the subsequent store.
+	fn grow(&mut self, width: usize) -> &mut BitSlice<O, T> {
+		assert!(width != 0, "Cannot push an empty field");
+		let len = self.bits.len();
+		self.bits.grow_filled(width, false);
+		unsafe { self.bits.get_unchecked_mut(len ..) }
+	}
+}
+
+impl<O, T> Default for FieldEncoder<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+}
@@ -0,0 +1,192 @@
+/*! Whole-slice transfers.
+
+These walk a slice in fixed-width chunks and hand each loaded chunk to the
+caller, either as a callback or into a buffer of integers, so that a slice can
+be exported without collecting it into an intermediate buffer first.
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Chunked exports.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Calls a function on each byte reconstructed from the slice contents.
+	///
+	/// This is the push-based equivalent of walking `.chunks(8)` and loading
+	/// each chunk as a byte. It allocates no buffer, and so can feed a hashing
+	/// or checksum routine directly.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `func`: A function which receives each byte of the slice, in order.
+	///
+	/// # Behavior
+	///
+	/// The slice is divided into eight-bit chunks, starting from index `0`.
+	/// Each chunk is loaded with [`.load_be::<u8>()`], so a chunk that exactly
+	/// covers a byte of memory reproduces that byte unchanged.
+	///
+	/// If `self.len()` is not a multiple of eight, the final chunk is shorter
+	/// than a byte. It is also loaded with [`.load_be::<u8>()`], which places
+	/// its live bits in the least significant positions of the produced byte
+	/// and fills the remaining high bits with zero. The empty slice produces no
+	/// bytes.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let bits = &data.view_bits::<Msb0>()[.. 20];
+	///
+	/// let mut sum = 0u32;
+	/// let mut last = 0u8;
+	/// bits.for_each_byte(|byte| {
+	///   sum += byte as u32;
+	///   last = byte;
+	/// });
+	/// assert_eq!(sum, 0x12 + 0x34 + 0x05);
+	/// assert_eq!(last, 0x05);
+	/// ```
+	///
+	/// [`.load_be::<u8>()`]: crate::field::BitField::load_be
+	pub fn for_each_byte<F>(&self, mut func: F)
+	where F: FnMut(u8) {
+		for chunk in self.chunks(8) {
+			func(chunk.load_be::<u8>());
+		}
+	}
+
+	/// Exports the slice into a buffer of integers.
+	///
+	/// This is the allocation-free inverse of viewing an integer slice as
+	/// bits: each integer in `out` receives the next `M::BITS` bits of `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `out`: The destination buffer. Its length must be exactly the number
+	///   of `M::BITS`-wide chunks in `self`, rounded up.
+	///
+	/// # Behavior
+	///
+	/// The slice is divided into `M::BITS`-wide chunks, starting from index
+	/// `0`, and each chunk is loaded with [`.load_le::<M>()`]. If `self.len()`
+	/// is not a multiple of `M::BITS`, the final chunk is shorter, and its
+	/// integer is zero-extended in the high bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `out.len()` is not `self.len()` divided by `M::BITS`,
+	/// rounded up.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x1234u16, 0x5678];
+	/// let bits = &data.view_bits::<Lsb0>()[.. 28];
+	///
+	/// let mut out = [0u8; 4];
+	/// bits.export_le(&mut out);
+	/// assert_eq!(out, [0x34, 0x12, 0x78, 0x06]);
+	/// ```
+	///
+	/// [`.load_le::<M>()`]: crate::field::BitField::load_le
+	pub fn export_le<M>(&self, out: &mut [M])
+	where M: BitMemory {
+		let width = M::BITS as usize;
+		let need = self.len() / width + (self.len() % width != 0) as usize;
+		assert_eq!(
+			out.len(),
+			need,
+			"Cannot export {} bits into {} {}-bit integers",
+			self.len(),
+			out.len(),
+			width,
+		);
+		for (slot, chunk) in out.iter_mut().zip(self.chunks(width)) {
+			*slot = chunk.load_le::<M>();
+		}
+	}
+
+	/// Loads each `width`-bit field of the slice and passes it to a fallible
+	/// function, stopping at the first error.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The type in which each field is loaded.
+	/// - `E`: The error type of `func`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The width of each field.
+	/// - `func`: A function which receives the index of each field, counting
+	///   from zero, and its value as loaded with [`.load_le::<M>()`].
+	///
+	/// # Behavior
+	///
+	/// The slice is divided into `width`-bit fields, starting from index `0`.
+	/// If `self.len()` is not a multiple of `width`, the final field is shorter,
+	/// and its value is zero-extended.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if `func` accepts every field; otherwise, the first error it
+	/// returns. No fields after the failing one are loaded.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than `M::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x21u8, 0x43, 0xF5];
+	/// let bits = data.view_bits::<Lsb0>();
+	///
+	/// let mut sum = 0;
+	/// let res = bits.try_fields_le::<u8, _, _>(4, |idx, nibble| {
+	///   if nibble > 9 {
+	///     return Err(idx);
+	///   }
+	///   sum += nibble;
+	///   Ok(())
+	/// });
+	/// assert_eq!(res, Err(5));
+	/// assert_eq!(sum, 1 + 2 + 3 + 4 + 5);
+	/// ```
+	///
+	/// [`.load_le::<M>()`]: crate::field::BitField::load_le
+	pub fn try_fields_le<M, E, F>(
+		&self,
+		width: usize,
+		mut func: F,
+	) -> Result<(), E>
+	where
+		M: BitMemory,
+		F: FnMut(usize, M) -> Result<(), E>,
+	{
+		super::check::<M>("load", width);
+		for (idx, chunk) in self.chunks(width).enumerate() {
+			func(idx, chunk.load_le::<M>())?;
+		}
+		Ok(())
+	}
+}
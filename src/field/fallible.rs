@@ -0,0 +1,204 @@
+/*! Fallible field transfers.
+
+The [`BitField`] transfers panic when a region is empty, or wider than the
+integer being transferred. The methods here check the region width first, and
+report an invalid width as a [`BitFieldError`] instead of calling the transfer.
+
+They are defined on [`BitSlice`], so that the width is always known. The other
+[`BitField`] implementors, [`BitArray`], [`BitBox`], and [`BitVec`], reach them
+through their dereference to [`BitSlice`].
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitField`]: crate::field::BitField
+[`BitFieldError`]: crate::field::BitFieldError
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+!*/
+
+use crate::{
+	field::{
+		BitField,
+		BitFieldError,
+	},
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Fallible transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads the bits in the `self` region into a local value, reporting an
+	/// invalid region width as an error.
+	///
+	/// This is the fallible form of [`.load()`], and likewise calls
+	/// [`.try_load_le()`] on little-endian byte-ordered CPUs and
+	/// [`.try_load_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`] if `self` is empty, or wider than a
+	/// single element `M`.
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`.load()`]: crate::field::BitField::load
+	/// [`.try_load_be()`]: Self::try_load_be
+	/// [`.try_load_le()`]: Self::try_load_le
+	pub fn try_load<M>(&self) -> Result<M, BitFieldError>
+	where M: BitMemory {
+		#[cfg(target_endian = "little")]
+		return self.try_load_le::<M>();
+
+		#[cfg(target_endian = "big")]
+		return self.try_load_be::<M>();
+	}
+
+	/// Stores a sequence of bits from the user into the domain of `self`,
+	/// reporting an invalid region width as an error.
+	///
+	/// This is the fallible form of [`.store()`], and likewise calls
+	/// [`.try_store_le()`] on little-endian byte-ordered CPUs and
+	/// [`.try_store_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`], and writes nothing, if `self` is
+	/// empty or wider than a single element `M`.
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`.store()`]: crate::field::BitField::store
+	/// [`.try_store_be()`]: Self::try_store_be
+	/// [`.try_store_le()`]: Self::try_store_le
+	pub fn try_store<M>(&mut self, value: M) -> Result<(), BitFieldError>
+	where M: BitMemory {
+		#[cfg(target_endian = "little")]
+		return self.try_store_le(value);
+
+		#[cfg(target_endian = "big")]
+		return self.try_store_be(value);
+	}
+
+	/// Loads from `self`, using little-endian element `T` ordering, and
+	/// reporting an invalid region width as an error.
+	///
+	/// This behaves exactly as [`.load_le()`] does for a `self` region that is
+	/// between one and [`M::BITS`] bits wide.
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`] if `self` is empty, or wider than a
+	/// single element `M`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0x1234u16;
+	/// let bits = data.view_bits::<Lsb0>();
+	///
+	/// assert_eq!(bits[.. 8].try_load_le::<u8>(), Ok(0x34));
+	///
+	/// let err = bits.try_load_le::<u8>().unwrap_err();
+	/// assert_eq!(err.width(), 16);
+	/// assert_eq!(err.max_bits(), 8);
+	/// ```
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	pub fn try_load_le<M>(&self) -> Result<M, BitFieldError>
+	where M: BitMemory {
+		super::try_check::<M>(self.len())?;
+		Ok(self.load_le::<M>())
+	}
+
+	/// Loads from `self`, using big-endian element `T` ordering, and reporting
+	/// an invalid region width as an error.
+	///
+	/// This behaves exactly as [`.load_be()`] does for a `self` region that is
+	/// between one and [`M::BITS`] bits wide.
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`] if `self` is empty, or wider than a
+	/// single element `M`.
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	pub fn try_load_be<M>(&self) -> Result<M, BitFieldError>
+	where M: BitMemory {
+		super::try_check::<M>(self.len())?;
+		Ok(self.load_be::<M>())
+	}
+
+	/// Stores into `self`, using little-endian element ordering, and reporting
+	/// an invalid region width as an error.
+	///
+	/// This behaves exactly as [`.store_le()`] does for a `self` region that is
+	/// between one and [`M::BITS`] bits wide.
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`], and writes nothing, if `self` is
+	/// empty or wider than a single element `M`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Msb0>();
+	///
+	/// assert!(bits[4 .. 12].try_store_le(0xA5u8).is_ok());
+	/// assert!(bits[4 .. 4].try_store_le(0u8).is_err());
+	/// assert_eq!(data, 0x0A50);
+	/// ```
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn try_store_le<M>(&mut self, value: M) -> Result<(), BitFieldError>
+	where M: BitMemory {
+		super::try_check::<M>(self.len())?;
+		self.store_le::<M>(value);
+		Ok(())
+	}
+
+	/// Stores into `self`, using big-endian element ordering, and reporting an
+	/// invalid region width as an error.
+	///
+	/// This behaves exactly as [`.store_be()`] does for a `self` region that is
+	/// between one and [`M::BITS`] bits wide.
+	///
+	/// # Errors
+	///
+	/// This returns a [`BitFieldError`], and writes nothing, if `self` is
+	/// empty or wider than a single element `M`.
+	///
+	/// [`BitFieldError`]: crate::field::BitFieldError
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	pub fn try_store_be<M>(&mut self, value: M) -> Result<(), BitFieldError>
+	where M: BitMemory {
+		super::try_check::<M>(self.len())?;
+		self.store_be::<M>(value);
+		Ok(())
+	}
+}
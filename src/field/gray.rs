@@ -0,0 +1,157 @@
+/*! Gray-coded fields.
+
+A reflected binary Gray code changes exactly one bit between consecutive
+values, which makes it the usual encoding for position sensors and counters that
+are sampled asynchronously. The methods here convert between the Gray code held
+in memory and the binary value held in a register.
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Gray-code transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a little-endian Gray-coded field from the slice, and converts it
+	/// to binary.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer into which the field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The binary value whose Gray code is the value of [`.load_le()`]. Bit
+	/// `n` of the result is the XOR of bits `n` and above of the field, which
+	/// is computed by folding `value ^= value >> shift` for `shift` of `1`,
+	/// `2`, `4`, and so on, while `shift` is less than `self.len()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 5];
+	///
+	/// bits.store_le(0b1_0000u8);
+	/// assert_eq!(bits.load_le_gray::<u8>(), 31);
+	/// bits.store_le(0b0_0110u8);
+	/// assert_eq!(bits.load_le_gray::<u8>(), 4);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	pub fn load_le_gray<M>(&self) -> M
+	where M: BitMemory {
+		gray_to_binary(self.load_le::<M>(), self.len())
+	}
+
+	/// Loads a big-endian Gray-coded field from the slice, and converts it to
+	/// binary.
+	///
+	/// This is [`.load_le_gray()`], using [`.load_be()`] to read the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.load_le_gray()`]: Self::load_le_gray
+	pub fn load_be_gray<M>(&self) -> M
+	where M: BitMemory {
+		gray_to_binary(self.load_be::<M>(), self.len())
+	}
+
+	/// Converts a binary value to Gray code, and stores it into the slice as a
+	/// little-endian field.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: A binary value. Only its low `self.len()` bits are used; the
+	///   Gray code `value ^ (value >> 1)` is computed after the bits above the
+	///   field are discarded, and is written with [`.store_le()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 5];
+	///
+	/// bits.store_le_gray(31u8);
+	/// assert_eq!(data, 0b1_0000);
+	/// assert_eq!(bits.load_le_gray::<u8>(), 31);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn store_le_gray<M>(&mut self, value: M)
+	where M: BitMemory {
+		let gray = binary_to_gray(value, self.len());
+		self.store_le::<M>(gray);
+	}
+
+	/// Converts a binary value to Gray code, and stores it into the slice as a
+	/// big-endian field.
+	///
+	/// This is [`.store_le_gray()`], using [`.store_be()`] to write the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `M::BITS`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	/// [`.store_le_gray()`]: Self::store_le_gray
+	pub fn store_be_gray<M>(&mut self, value: M)
+	where M: BitMemory {
+		let gray = binary_to_gray(value, self.len());
+		self.store_be::<M>(gray);
+	}
+}
+
+/// Converts a `width`-bit Gray code to binary.
+///
+/// `gray` must have no bits set at or above `width`.
+fn gray_to_binary<M>(mut gray: M, width: usize) -> M
+where M: BitMemory {
+	let mut shift = 1u8;
+	while (shift as usize) < width {
+		gray ^= gray >> shift;
+		shift <<= 1;
+	}
+	gray
+}
+
+/// Converts the low `width` bits of a binary value to Gray code.
+///
+/// # Panics
+///
+/// This panics if `width` is zero or greater than `M::BITS`.
+fn binary_to_gray<M>(value: M, width: usize) -> M
+where M: BitMemory {
+	super::check::<M>("store", width);
+	let value = value & (!M::ZERO >> (M::BITS - width as u8));
+	value ^ (value >> 1u8)
+}
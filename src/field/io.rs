@@ -32,7 +32,10 @@ use crate::{
 	vec::BitVec,
 };
 
-use core::mem;
+use core::{
+	cmp,
+	mem,
+};
 
 use std::io::{
 	self,
@@ -47,7 +50,23 @@ of either the source [`BitSlice`] or destination `[u8]`. When `.read()` returns,
 `self` will have been updated to no longer include the leading segment copied
 out as bytes of `buf`.
 
-The implementation uses [`BitField::load_be`].
+The implementation uses [`BitField::load_be`]. When fewer than eight bits remain
+in the slice, they are loaded as one final byte, with its high bits cleared, and
+`self` becomes empty. A slice of `n` bits therefore reads as `⌈n / 8⌉` bytes.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use std::io::Read;
+
+let mut bits = bits![Msb0, u8; 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0];
+let mut buf = [0u8; 4];
+assert_eq!(bits.read(&mut buf).unwrap(), 2);
+assert_eq!(buf[.. 2], [0xA5, 0b110]);
+assert!(bits.is_empty());
+assert_eq!(bits.read(&mut buf).unwrap(), 0);
+```
 
 [here]: https://doc.rust-lang.org/stable/std/primitive.slice.html#impl-Read
 [`BitField::load_be`]: crate::field::BitField::load_be
@@ -61,11 +80,12 @@ where
 {
 	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
 		let mut idx = 0;
-		for (byte, slot) in self.chunks_exact(8).zip(buf.iter_mut()) {
+		for (byte, slot) in self.chunks(8).zip(buf.iter_mut()) {
 			*slot = byte.load_be();
 			idx += 1;
 		}
-		*self = unsafe { self.get_unchecked(idx * 8 ..) };
+		let used = cmp::min(idx * 8, self.len());
+		*self = unsafe { self.get_unchecked(used ..) };
 		Ok(idx)
 	}
 }
@@ -114,8 +134,27 @@ The implementation copies bytes from `buf` into the tail end of `self`. The
 performance characteristics of this operation are dependent on the type
 parameters of the [`BitVec`], and the position of its tail.
 
+Each byte grows the vector by eight bits, written with [`BitField::store_be`]
+just as the `&mut BitSlice` implementation does. This is implemented for every
+storage type, not only `u8`, so any [`BitVec`] can be the destination of
+`write!` or of a serializer that writes to an [`io::Write`] sink.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use std::io::Write;
+
+let mut bv = bitvec![Msb0, u16; 1, 0, 1];
+bv.write_all(&[0xA5]).unwrap();
+assert_eq!(bv.len(), 11);
+assert_eq!(bv[3 ..], bits![1, 0, 1, 0, 0, 1, 0, 1]);
+```
+
 [here]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html#impl-Write
+[`BitField::store_be`]: crate::field::BitField::store_be
 [`BitVec`]: crate::vec::BitVec
+[`io::Write`]: std::io::Write
 **/
 impl<O, T> Write for BitVec<O, T>
 where
@@ -148,17 +187,22 @@ mod tests {
 		assert_eq!(bits.len(), 28);
 
 		let mut transfer = [0u8; 4];
-		let last_ptr = &mut transfer[3] as *mut _;
 		let mut transfer_handle = &mut transfer[..];
 
-		assert_eq!(io::copy(&mut bits, &mut transfer_handle).unwrap(), 3);
+		assert_eq!(io::copy(&mut bits, &mut transfer_handle).unwrap(), 4);
+
+		//  The last four bits are read as a final, zero-extended, byte.
+		assert!(bits.is_empty());
+		assert!(transfer_handle.is_empty());
 
-		//  Once a bitslice cannot produce a byte, it stops `Read`ing
-		assert_eq!(bits, data.view_bits::<Msb0>()[28 ..]);
-		//  So the destination slice does not fill up.
-		assert_eq!(transfer_handle.as_mut_ptr() as *mut _, last_ptr);
+		assert_eq!(transfer, [0x36, 0xC8, 0xC6, 0x03]);
 
-		assert_eq!(transfer[.. 3], [0x36, 0xC8, 0xC6][..]);
+		let mut bits = &data.view_bits::<Msb0>()[.. 16];
+		let mut transfer = [0u8; 3];
+		assert_eq!(bits.read(&mut transfer).unwrap(), 2);
+		assert!(bits.is_empty());
+		assert_eq!(bits.read(&mut transfer).unwrap(), 0);
+		assert_eq!(transfer, [0x13, 0x6C, 0]);
 	}
 
 	#[test]
@@ -176,4 +220,20 @@ mod tests {
 			0, 1, 1, 0, 1, 0, 0, 1,
 		]);
 	}
+
+	#[test]
+	fn write_grows() {
+		let mut bv = BitVec::<Msb0, u8>::new();
+		assert_eq!(bv.write(&[0xA5]).unwrap(), 1);
+		assert_eq!(bv, bits![1, 0, 1, 0, 0, 1, 0, 1]);
+		assert_eq!(bv.as_slice(), &[0xA5]);
+
+		let mut bv = BitVec::<Lsb0, u32>::new();
+		write!(bv, "{}{}", 4, 2).unwrap();
+		bv.flush().unwrap();
+		assert_eq!(bv.len(), 16);
+		let mut out = [0u8; 2];
+		(&*bv).read_exact(&mut out).unwrap();
+		assert_eq!(&out, b"42");
+	}
 }
@@ -0,0 +1,145 @@
+/*! Morton codes.
+
+A Morton code interleaves the bits of two coordinates, so that points that are
+near each other in two dimensions usually have codes that are near each other
+in one dimension.
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::cmp;
+
+/// Morton-code transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Interleaves two values into a little-endian Morton (Z-order) code.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `x`: The value whose bits fill the even-numbered positions of the
+	///   code.
+	/// - `y`: The value whose bits fill the odd-numbered positions of the code.
+	///
+	/// # Behavior
+	///
+	/// Bit `n` of `x` becomes bit `2 * n` of the code, and bit `n` of `y`
+	/// becomes bit `2 * n + 1`. The code is then written with
+	/// [`.store_le::<u128>()`].
+	///
+	/// An `N`-bit slice holds the low `(N + 1) / 2` bits of `x` and the low
+	/// `N / 2` bits of `y`. Any higher bits of either value are discarded, as
+	/// they would be by [`.store_le()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than either `2 * M::BITS` or
+	/// 128 bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// bits.store_morton2_le(0b0011u8, 0b0101u8);
+	/// assert_eq!(data, 0b0010_0111);
+	/// assert_eq!(
+	///   data.view_bits::<Lsb0>().load_morton2_le::<u8>(),
+	///   (0b0011, 0b0101),
+	/// );
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	/// [`.store_le::<u128>()`]: crate::field::BitField::store_le
+	pub fn store_morton2_le<M>(&mut self, x: M, y: M)
+	where M: BitMemory {
+		check_morton::<M>("interleave", self.len());
+		let x = spread2(super::resize::<M, u64>(x));
+		let y = spread2(super::resize::<M, u64>(y));
+		self.store_le::<u128>(x | y << 1);
+	}
+
+	/// De-interleaves a little-endian Morton (Z-order) code into two values.
+	///
+	/// This is the inverse of [`.store_morton2_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A tuple of the bits in the even-numbered positions of the code, and
+	/// the bits in the odd-numbered positions, each gathered into the low bits
+	/// of an `M`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than either `2 * M::BITS` or
+	/// 128 bits.
+	///
+	/// [`.store_morton2_le()`]: Self::store_morton2_le
+	pub fn load_morton2_le<M>(&self) -> (M, M)
+	where M: BitMemory {
+		check_morton::<M>("de-interleave", self.len());
+		let code = self.load_le::<u128>();
+		(
+			super::resize::<u64, M>(compact2(code)),
+			super::resize::<u64, M>(compact2(code >> 1)),
+		)
+	}
+}
+
+/// Asserts that a region can hold the Morton code of two `M` values.
+///
+/// # Panics
+///
+/// This panics if `len` is 0, or wider than either `2 * M::BITS` or 128.
+fn check_morton<M>(action: &'static str, len: usize)
+where M: BitMemory {
+	let max = cmp::min(2 * M::BITS as usize, 128);
+	if !(1 ..= max).contains(&len) {
+		panic!(
+			"Cannot {} two {}-bit values in a {}-bit region",
+			action,
+			M::BITS,
+			len
+		);
+	}
+}
+
+/// Spreads the bits of `value` apart, so that bit `n` moves to bit `2 * n`.
+fn spread2(value: u64) -> u128 {
+	let mut out = value as u128;
+	out = (out | out << 32) & 0x0000_0000_FFFF_FFFF_0000_0000_FFFF_FFFF;
+	out = (out | out << 16) & 0x0000_FFFF_0000_FFFF_0000_FFFF_0000_FFFF;
+	out = (out | out << 8) & 0x00FF_00FF_00FF_00FF_00FF_00FF_00FF_00FF;
+	out = (out | out << 4) & 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F;
+	out = (out | out << 2) & 0x3333_3333_3333_3333_3333_3333_3333_3333;
+	(out | out << 1) & 0x5555_5555_5555_5555_5555_5555_5555_5555
+}
+
+/// Gathers the even-numbered bits of `code`, so that bit `2 * n` moves to bit
+/// `n`. This is the inverse of `spread2`.
+fn compact2(code: u128) -> u64 {
+	let mut out = code & 0x5555_5555_5555_5555_5555_5555_5555_5555;
+	out = (out | out >> 1) & 0x3333_3333_3333_3333_3333_3333_3333_3333;
+	out = (out | out >> 2) & 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F;
+	out = (out | out >> 4) & 0x00FF_00FF_00FF_00FF_00FF_00FF_00FF_00FF;
+	out = (out | out >> 8) & 0x0000_FFFF_0000_FFFF_0000_FFFF_0000_FFFF;
+	out = (out | out >> 16) & 0x0000_0000_FFFF_FFFF_0000_0000_FFFF_FFFF;
+	(out | out >> 32) as u64
+}
@@ -0,0 +1,148 @@
+/*! Length-prefixed bit strings.
+
+A length-prefixed string is a fixed-width length header followed by that many
+payload bits. These methods write such a string to the end of a bit-vector and
+read one back from the front of a slice.
+!*/
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::{
+	mem::BitMemory,
+	vec::BitVec,
+};
+
+/// Length-prefixed loads.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a length-prefixed bit string from the front of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `len_width`: The width of the length header.
+	///
+	/// # Behavior
+	///
+	/// The first `len_width` bits of `self` are loaded with
+	/// [`.load_le::<usize>()`] as the length of the payload, and the payload
+	/// is the bits that immediately follow the header.
+	///
+	/// # Returns
+	///
+	/// The payload, and the number of bits occupied by the header and payload
+	/// together. This is the index in `self` at which the next item begins.
+	///
+	/// If `self` is shorter than the header, or the header records a payload
+	/// longer than the rest of `self`, this returns `None`.
+	///
+	/// # Panics
+	///
+	/// This panics if `len_width` is zero or greater than `usize::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![Lsb0, u8; 1, 1, 0, 0, 1, 0, 1, 0, 0];
+	/// let (payload, used) = bits.load_lenprefixed(4).unwrap();
+	/// assert_eq!(payload, bits![1, 0, 1]);
+	/// assert_eq!(used, 7);
+	///
+	/// //  A header that claims more bits than remain is rejected.
+	/// assert!(bits![1, 1, 1, 0, 1].load_lenprefixed(3).is_none());
+	/// ```
+	///
+	/// [`.load_le::<usize>()`]: crate::field::BitField::load_le
+	pub fn load_lenprefixed(&self, len_width: usize) -> Option<(&Self, usize)> {
+		super::check::<usize>("load", len_width);
+		if len_width > self.len() {
+			return None;
+		}
+		let (header, rest) = unsafe { self.split_at_unchecked(len_width) };
+		let len = header.load_le::<usize>();
+		if len > rest.len() {
+			return None;
+		}
+		Some((unsafe { rest.get_unchecked(.. len) }, len_width + len))
+	}
+}
+
+/// Length-prefixed stores.
+#[cfg(feature = "alloc")]
+impl<O, T> BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Appends a length-prefixed bit string to the vector.
+	///
+	/// This is the inverse of [`BitSlice::load_lenprefixed`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len_width`: The width of the length header.
+	/// - `payload`: The bit string to append.
+	///
+	/// # Behavior
+	///
+	/// `payload.len()` is appended as a `len_width`-bit field, written with
+	/// [`.store_le::<usize>()`], and then `payload` is appended after it.
+	///
+	/// # Panics
+	///
+	/// This panics if `len_width` is zero or greater than `usize::BITS`, or if
+	/// `payload.len()` does not fit in `len_width` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Lsb0, u8; 1];
+	/// bv.store_lenprefixed(4, bits![1, 0, 1]);
+	/// assert_eq!(bv, bits![1, 1, 1, 0, 0, 1, 0, 1]);
+	///
+	/// let (payload, used) = bv[1 ..].load_lenprefixed(4).unwrap();
+	/// assert_eq!(payload, bits![1, 0, 1]);
+	/// assert_eq!(used, 7);
+	/// ```
+	///
+	/// [`BitSlice::load_lenprefixed`]: crate::slice::BitSlice::load_lenprefixed
+	/// [`.store_le::<usize>()`]: crate::field::BitField::store_le
+	pub fn store_lenprefixed<O2, T2>(
+		&mut self,
+		len_width: usize,
+		payload: &BitSlice<O2, T2>,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		super::check::<usize>("store", len_width);
+		let len = payload.len();
+		assert!(
+			len_width == <usize as BitMemory>::BITS as usize
+				|| len >> len_width == 0,
+			"Payload length {} does not fit in a {}-bit header",
+			len,
+			len_width
+		);
+		let start = self.len();
+		self.grow_filled(len_width, false);
+		unsafe { self.get_unchecked_mut(start ..) }.store_le::<usize>(len);
+		self.extend_from_bitslice(payload);
+	}
+}
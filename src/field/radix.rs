@@ -0,0 +1,157 @@
+/*! Mixed-radix digit packing.
+
+Each digit is stored in the fewest bits that can hold every value of its radix,
+so a sequence of small-radix digits packs more densely than a sequence of
+whole-byte fields.
+!*/
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Mixed-radix transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores a sequence of mixed-radix digits into the front of the slice.
+	///
+	/// Numbers such as times of day, where each digit counts up to a
+	/// different limit, can be packed more tightly than as separate bytes by
+	/// giving each digit only as many bits as its radix needs.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `digits`: A sequence of `(value, radix)` pairs. Each `value` must be
+	///   less than its `radix`.
+	///
+	/// # Behavior
+	///
+	/// Each digit occupies `ceil(log2(radix))` bits, and is written with
+	/// [`.store_le::<u64>()`]. The digits are placed one after another,
+	/// starting at `self[0]`. A digit with a radix of `1` can only be `0`, and
+	/// occupies no bits.
+	///
+	/// # Returns
+	///
+	/// The number of bits written.
+	///
+	/// # Panics
+	///
+	/// This panics if any radix is `0`, if any value is not less than its
+	/// radix, or if the digits are wider in total than `self`. No bits are
+	/// written if it panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u32;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// //  Day of the year, hour, and minute.
+	/// let used = bits.store_mixed_radix(&[(300, 366), (13, 24), (45, 60)]);
+	/// assert_eq!(used, 9 + 5 + 6);
+	///
+	/// let mut digits = [(0, 366), (0, 24), (0, 60)];
+	/// assert_eq!(bits.load_mixed_radix(&mut digits), 20);
+	/// assert_eq!(digits, [(300, 366), (13, 24), (45, 60)]);
+	/// ```
+	///
+	/// [`.store_le::<u64>()`]: crate::field::BitField::store_le
+	pub fn store_mixed_radix(&mut self, digits: &[(u64, u64)]) -> usize {
+		let mut total = 0usize;
+		for &(value, radix) in digits {
+			let width = radix_width(radix);
+			assert!(
+				value < radix,
+				"Digit {} is out of range for radix {}",
+				value,
+				radix
+			);
+			total = total.saturating_add(width);
+		}
+		assert!(
+			total <= self.len(),
+			"Cannot store {} bits of digits in a {}-bit slice",
+			total,
+			self.len()
+		);
+		let mut start = 0;
+		for &(value, radix) in digits {
+			let width = radix_width(radix);
+			if width != 0 {
+				self[start ..][.. width].store_le::<u64>(value);
+			}
+			start += width;
+		}
+		total
+	}
+
+	/// Loads a sequence of mixed-radix digits from the front of the slice.
+	///
+	/// This is the inverse of [`.store_mixed_radix()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `digits`: A sequence of `(value, radix)` pairs. Each radix selects the
+	///   width of its digit, and each value is overwritten with the digit
+	///   loaded from the slice.
+	///
+	/// # Returns
+	///
+	/// The number of bits read.
+	///
+	/// A digit is loaded as the full value of its bits, without reduction by
+	/// its radix. If the slice was not written by [`.store_mixed_radix()`]
+	/// with the same radices, a loaded value may not be less than its radix.
+	///
+	/// # Panics
+	///
+	/// This panics if any radix is `0`, or if the digits are wider in total
+	/// than `self`.
+	///
+	/// [`.store_mixed_radix()`]: Self::store_mixed_radix
+	pub fn load_mixed_radix(&self, digits: &mut [(u64, u64)]) -> usize {
+		let total = digits
+			.iter()
+			.map(|&(_, radix)| radix_width(radix))
+			.fold(0usize, usize::saturating_add);
+		assert!(
+			total <= self.len(),
+			"Cannot load {} bits of digits from a {}-bit slice",
+			total,
+			self.len()
+		);
+		let mut start = 0;
+		for (value, radix) in digits {
+			let width = radix_width(*radix);
+			*value = if width == 0 {
+				0
+			}
+			else {
+				self[start ..][.. width].load_le::<u64>()
+			};
+			start += width;
+		}
+		total
+	}
+}
+
+/// Computes the number of bits needed to hold every digit of a radix.
+///
+/// # Panics
+///
+/// This panics if `radix` is zero.
+fn radix_width(radix: u64) -> usize {
+	assert!(radix != 0, "Cannot use a radix of zero");
+	64 - (radix - 1).leading_zeros() as usize
+}
@@ -0,0 +1,234 @@
+/*! Loads and stores that do not truncate silently.
+
+The [`BitField`] accessors convert between register widths by truncation. The
+methods here instead saturate or clamp a value that does not fit, or reserve a
+sentinel to mark a field as absent.
+
+[`BitField`]: crate::field::BitField
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::cmp;
+
+/// Saturating, clamping, and optional transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a little-endian value from the slice, and saturates it to fit in
+	/// a narrower type.
+	///
+	/// The ordinary [`.load_le()`] family converts between register widths by
+	/// truncation: a field loaded as `u32` and then cast to `u8` keeps only its
+	/// low eight bits. This method instead clamps values that are too large for
+	/// `N` to `N::MAX`, which is usually the better failure mode when a field
+	/// nominally fits in `N` but occasionally overflows.
+	///
+	/// # Type Parameters
+	///
+	/// - `W`: The type in which the field is loaded. `self.len()` must not
+	///   exceed its width.
+	/// - `N`: The type of the returned value.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of [`.load_le::<W>()`], if it fits in `N`; otherwise,
+	/// `N::MAX`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than `W::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 12];
+	///
+	/// bits.store_le(200u16);
+	/// assert_eq!(bits.load_le_saturating::<u16, u8>(), 200);
+	///
+	/// bits.store_le(0x123u16);
+	/// assert_eq!(bits.load_le_saturating::<u16, u8>(), 255);
+	/// //  Compare with truncation:
+	/// assert_eq!(bits.load_le::<u8>(), 0x23);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.load_le::<W>()`]: crate::field::BitField::load_le
+	pub fn load_le_saturating<W, N>(&self) -> N
+	where
+		W: BitMemory,
+		N: BitMemory,
+	{
+		let value = self.load_le::<W>();
+		if value > super::resize::<N, W>(N::MAX) {
+			N::MAX
+		}
+		else {
+			super::resize::<W, N>(value)
+		}
+	}
+
+	/// Stores a signed value as a little-endian two’s-complement field,
+	/// clamping it to the range that the field can represent.
+	///
+	/// The ordinary [`.store_le()`] truncates values that are too wide for the
+	/// slice, which turns an out-of-range signed value into an unrelated
+	/// number, often of the opposite sign. This method instead saturates
+	/// `value` to the nearest representable value before storing it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The signed value to store.
+	///
+	/// # Behavior
+	///
+	/// An `N`-bit field holds the two’s-complement range
+	/// `-2^(N - 1) ..= 2^(N - 1) - 1`. This range is asymmetric: the magnitude
+	/// of its minimum is one greater than that of its maximum. `value` is
+	/// clamped into this range, and the low `N` bits of the result are written
+	/// with [`.store_le::<u64>()`].
+	///
+	/// # Returns
+	///
+	/// The clamped value that was stored in the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 4];
+	///
+	/// assert_eq!(bits.store_clamped_signed_le(-3), -3);
+	/// assert_eq!(data, 0b1101);
+	/// assert_eq!(bits.store_clamped_signed_le(100), 7);
+	/// assert_eq!(bits.store_clamped_signed_le(-100), -8);
+	/// assert_eq!(data, 0b1000);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	/// [`.store_le::<u64>()`]: crate::field::BitField::store_le
+	pub fn store_clamped_signed_le(&mut self, value: i64) -> i64 {
+		let len = self.len();
+		super::check::<u64>("store", len);
+		//  Arithmetic shifts narrow the extrema to the field width.
+		let shamt = 64 - len as u32;
+		let (min, max) = (i64::MIN >> shamt, i64::MAX >> shamt);
+		let clamped = cmp::max(min, cmp::min(value, max));
+		self.store_le::<u64>(clamped as u64);
+		clamped
+	}
+
+	/// Loads an optional little-endian value, guarded by a presence flag.
+	///
+	/// This decodes the common wire pattern of a single "present" bit followed
+	/// by a payload that is only meaningful when the flag is set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `payload_width`: The width of the payload that follows the flag.
+	///
+	/// # Behavior
+	///
+	/// `self[0]` is the flag. If it is clear, no other bits are read, and the
+	/// encoding occupies one bit. If it is set, `self[1 .. 1 + payload_width]`
+	/// is loaded with [`.load_le::<M>()`], and the encoding occupies
+	/// `1 + payload_width` bits.
+	///
+	/// # Returns
+	///
+	/// `None` if the flag is clear; otherwise, the payload.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or if the flag is set and either
+	/// `payload_width` is zero or greater than `M::BITS`, or `self` is shorter
+	/// than `1 + payload_width` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// bits.store_optional_le(6, Some(37u8));
+	/// assert_eq!(bits.load_optional_le::<u8>(6), Some(37));
+	///
+	/// bits.store_optional_le::<u8>(6, None);
+	/// assert!(bits.load_optional_le::<u8>(6).is_none());
+	/// ```
+	///
+	/// [`.load_le::<M>()`]: crate::field::BitField::load_le
+	pub fn load_optional_le<M>(&self, payload_width: usize) -> Option<M>
+	where M: BitMemory {
+		if !self[0] {
+			return None;
+		}
+		Some(self[1 ..][.. payload_width].load_le::<M>())
+	}
+
+	/// Stores an optional little-endian value, guarded by a presence flag.
+	///
+	/// This is the inverse of [`.load_optional_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `payload_width`: The width of the payload that follows the flag.
+	/// - `value`: The payload to store, if any.
+	///
+	/// # Behavior
+	///
+	/// `self[0]` is set to `value.is_some()`. If `value` is `None`, no other
+	/// bits are written, and the encoding occupies one bit. If it is
+	/// `Some(payload)`, `payload` is written into
+	/// `self[1 .. 1 + payload_width]` with [`.store_le::<M>()`], and the
+	/// encoding occupies `1 + payload_width` bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or if `value` is `Some` and either
+	/// `payload_width` is zero or greater than `M::BITS`, or `self` is shorter
+	/// than `1 + payload_width` bits.
+	///
+	/// [`.load_optional_le()`]: Self::load_optional_le
+	/// [`.store_le::<M>()`]: crate::field::BitField::store_le
+	pub fn store_optional_le<M>(
+		&mut self,
+		payload_width: usize,
+		value: Option<M>,
+	) where
+		M: BitMemory,
+	{
+		self.set(0, value.is_some());
+		if let Some(payload) = value {
+			self[1 ..][.. payload_width].store_le::<M>(payload);
+		}
+	}
+}
@@ -0,0 +1,133 @@
+/*! Two’s-complement fields.
+
+The [`BitField`] accessors zero-extend a narrow field into its register. The
+methods here treat the most significant bit of the field as its sign bit, and
+sign-extend it into a signed integer.
+
+[`BitField`]: crate::field::BitField
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitSigned,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Signed transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a little-endian two’s-complement field from the slice, and
+	/// sign-extends it into a signed integer.
+	///
+	/// # Type Parameters
+	///
+	/// - `I`: The signed integer into which the field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of [`.load_le()`] into the unsigned integer of the same width
+	/// as `I`, with its most significant live bit treated as the sign bit. A
+	/// negative field fills every bit of `I` above the field with `1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than the width of `I`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 12];
+	///
+	/// bits.store_le(0xFFFu16);
+	/// assert_eq!(bits.load_le_signed::<i16>(), -1);
+	/// bits.store_le(0x7FFu16);
+	/// assert_eq!(bits.load_le_signed::<i16>(), 2047);
+	/// bits.store_le(0x800u16);
+	/// assert_eq!(bits.load_le_signed::<i32>(), -2048);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	pub fn load_le_signed<I>(&self) -> I
+	where I: BitSigned {
+		let raw = self.load_le::<I::Unsigned>();
+		I::sign_extend(raw, self.len() as u8)
+	}
+
+	/// Loads a big-endian two’s-complement field from the slice, and
+	/// sign-extends it into a signed integer.
+	///
+	/// This is [`.load_le_signed()`], using [`.load_be()`] to read the field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than the width of `I`.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.load_le_signed()`]: Self::load_le_signed
+	pub fn load_be_signed<I>(&self) -> I
+	where I: BitSigned {
+		let raw = self.load_be::<I::Unsigned>();
+		I::sign_extend(raw, self.len() as u8)
+	}
+
+	/// Stores a signed integer into the slice as a little-endian
+	/// two’s-complement field.
+	///
+	/// The low `self.len()` bits of `value` are written with [`.store_le()`].
+	/// Values outside the range of the field are truncated, not clamped; see
+	/// [`.store_clamped_signed_le()`] for a saturating store.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than the width of `I`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.view_bits_mut::<Msb0>()[4 ..];
+	///
+	/// bits.store_le_signed(-2i16);
+	/// assert_eq!(data, 0x0FFE);
+	/// assert_eq!(bits.load_le_signed::<i16>(), -2);
+	/// ```
+	///
+	/// [`.store_clamped_signed_le()`]: Self::store_clamped_signed_le
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn store_le_signed<I>(&mut self, value: I)
+	where I: BitSigned {
+		self.store_le::<I::Unsigned>(value.to_unsigned());
+	}
+
+	/// Stores a signed integer into the slice as a big-endian two’s-complement
+	/// field.
+	///
+	/// This is [`.store_le_signed()`], using [`.store_be()`] to write the
+	/// field.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than the width of `I`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	/// [`.store_le_signed()`]: Self::store_le_signed
+	pub fn store_be_signed<I>(&mut self, value: I)
+	where I: BitSigned {
+		self.store_be::<I::Unsigned>(value.to_unsigned());
+	}
+}
@@ -0,0 +1,164 @@
+/*! Split fields.
+
+Some formats divide one logical value between two separate ranges of a packed
+structure. These methods load and store such a value as a single integer.
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::ops::Range;
+
+/// Split-field transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores a value into a field that is split across two ranges of the
+	/// slice.
+	///
+	/// Some register layouts divide one value into two separate bit ranges,
+	/// such as a base address whose high and low bits live in different parts
+	/// of a register. This writes each part of `value` into its range.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to store.
+	/// - `high_range`: The range of `self` that receives the more-significant
+	///   part of `value`.
+	/// - `low_range`: The range of `self` that receives the less-significant
+	///   part of `value`.
+	///
+	/// # Behavior
+	///
+	/// The low `low_range.len()` bits of `value` are written into
+	/// `self[low_range]`, and the next `high_range.len()` bits are written
+	/// into `self[high_range]`, each with [`.store_le()`]. Bits of `value`
+	/// above the combined width are discarded. The ranges may appear in
+	/// either order within the slice.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is empty or out of bounds, if the ranges
+	/// overlap, or if their combined width is greater than `M::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// //  The high nibble of the byte sits below its low nibble.
+	/// bits.store_split_le(0xA5u8, 0 .. 4, 8 .. 12);
+	/// assert_eq!(bits.load_split_le::<u8>(0 .. 4, 8 .. 12), 0xA5);
+	/// assert_eq!(data, 0x050A);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	pub fn store_split_le<M>(
+		&mut self,
+		value: M,
+		high_range: Range<usize>,
+		low_range: Range<usize>,
+	) where
+		M: BitMemory,
+	{
+		let low_width =
+			check_split::<M>("store", self.len(), &high_range, &low_range);
+		let high = super::resize::<M, u128>(value) >> low_width;
+		self[low_range].store_le::<M>(value);
+		self[high_range].store_le::<u128>(high);
+	}
+
+	/// Loads a value from a field that is split across two ranges of the
+	/// slice.
+	///
+	/// This is the inverse of [`.store_split_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `high_range`: The range of `self` that holds the more-significant
+	///   part of the value.
+	/// - `low_range`: The range of `self` that holds the less-significant part
+	///   of the value.
+	///
+	/// # Returns
+	///
+	/// The value of `self[high_range]`, shifted up by `low_range.len()` bits,
+	/// combined with the value of `self[low_range]`. Each part is loaded with
+	/// [`.load_le()`].
+	///
+	/// # Panics
+	///
+	/// This panics if either range is empty or out of bounds, if the ranges
+	/// overlap, or if their combined width is greater than `M::BITS`.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.store_split_le()`]: Self::store_split_le
+	pub fn load_split_le<M>(
+		&self,
+		high_range: Range<usize>,
+		low_range: Range<usize>,
+	) -> M
+	where
+		M: BitMemory,
+	{
+		let low_width =
+			check_split::<M>("load", self.len(), &high_range, &low_range);
+		let low = self[low_range].load_le::<u128>();
+		let high = self[high_range].load_le::<u128>();
+		super::resize::<u128, M>(high << low_width | low)
+	}
+}
+
+/// Asserts that two ranges can hold the parts of a split `M` field.
+///
+/// # Returns
+///
+/// The width of `low`.
+///
+/// # Panics
+///
+/// This panics if either range is empty or reaches past `len`, if the ranges
+/// overlap, or if their combined width is greater than `M::BITS`. Stores call
+/// this before writing either part, so that a rejected store leaves memory
+/// unchanged.
+fn check_split<M>(
+	action: &'static str,
+	len: usize,
+	high: &Range<usize>,
+	low: &Range<usize>,
+) -> usize
+where
+	M: BitMemory,
+{
+	for range in &[high, low] {
+		assert!(
+			range.start < range.end && range.end <= len,
+			"Split-field range {:?} is empty or out of bounds for length {}",
+			range,
+			len
+		);
+	}
+	assert!(
+		high.end <= low.start || low.end <= high.start,
+		"Split-field ranges {:?} and {:?} overlap",
+		high,
+		low
+	);
+	let low_width = low.end.saturating_sub(low.start);
+	let high_width = high.end.saturating_sub(high.start);
+	super::check::<M>(action, low_width + high_width);
+	low_width
+}
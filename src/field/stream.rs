@@ -0,0 +1,358 @@
+/*! Most-significant-bit-first bitstreams.
+
+Media codecs, such as JPEG and H.264, pack variable-length codes into a byte
+buffer starting from the most significant bit of each byte. [`BitReader`] and
+[`BitWriter`] give such codecs the cursor interface they expect over a
+`BitSlice<Msb0, u8>` and a `BitVec<Msb0, u8>`. Every code is transferred with
+[`BitField::load_be`] or [`BitField::store_be`], so the first bit of a code is
+its most significant bit.
+
+The unary and Elias gamma codes, the usual variable-length codes for integers
+in such streams, are provided on both types. The reader needs only the core
+library; the writer grows its buffer, and so requires the `alloc` feature.
+
+A unary code for `n` is `n` zero bits followed by a one bit. An Elias gamma
+code for a nonzero `n` is the unary code for the number of bits after the most
+significant one bit of `n`, followed by those bits. The most significant bit of
+`n` is the terminating one bit of the unary prefix, so `1` is coded as `1`, `2`
+as `010`, and `5` as `00101`.
+
+[`BitField::load_be`]: crate::field::BitField::load_be
+[`BitField::store_be`]: crate::field::BitField::store_be
+[`BitReader`]: self::BitReader
+[`BitWriter`]: self::BitWriter
+!*/
+
+use crate::{
+	field::{
+		BitField,
+		BitFieldCursor,
+	},
+	order::Msb0,
+	slice::BitSlice,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+/** Reads most-significant-bit-first codes from a byte buffer.
+
+This is a thin wrapper over a [`BitFieldCursor`].
+
+# Lifetimes
+
+- `'a`: The lifetime of the buffer being read.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::BitReader;
+
+let data = [0b1011_0011u8, 0x80];
+let mut reader = BitReader::new(data.view_bits::<Msb0>());
+
+assert_eq!(reader.peek_bits(3), 0b101);
+assert_eq!(reader.read_bits(3), 0b101);
+assert_eq!(reader.read_bits(2), 0b10);
+reader.align_to_byte();
+assert_eq!(reader.bits_consumed(), 8);
+assert_eq!(reader.read_bits(1), 1);
+```
+
+[`BitFieldCursor`]: crate::field::BitFieldCursor
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<'a> {
+	/// The cursor over the buffer.
+	cursor: BitFieldCursor<'a, Msb0, u8>,
+}
+
+impl<'a> BitReader<'a> {
+	/// Creates a reader positioned at the front of a buffer.
+	pub fn new(bits: &'a BitSlice<Msb0, u8>) -> Self {
+		Self {
+			cursor: bits.field_cursor(),
+		}
+	}
+
+	/// Reads an `n`-bit code, and advances past it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The width of the code. A width of zero reads nothing, and
+	///   produces zero.
+	///
+	/// # Returns
+	///
+	/// The `n` bits after the cursor, with the first of them as the most
+	/// significant bit of the returned value.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is greater than 64, or greater than
+	/// [`.bits_remaining()`]. The reader does not move if it panics.
+	///
+	/// [`.bits_remaining()`]: Self::bits_remaining
+	pub fn read_bits(&mut self, n: u32) -> u64 {
+		if n == 0 {
+			return 0;
+		}
+		self.cursor.read_be::<u64>(n as usize)
+	}
+
+	/// Reads an `n`-bit code without advancing past it.
+	///
+	/// # Panics
+	///
+	/// This panics under the same conditions as [`.read_bits()`].
+	///
+	/// [`.read_bits()`]: Self::read_bits
+	pub fn peek_bits(&self, n: u32) -> u64 {
+		let mut copy = *self;
+		copy.read_bits(n)
+	}
+
+	/// Reads a unary code, and advances past it.
+	///
+	/// # Returns
+	///
+	/// The number of zero bits after the cursor, before the first one bit.
+	/// The reader advances past the one bit.
+	///
+	/// # Panics
+	///
+	/// This panics if there is no one bit after the cursor. The reader does
+	/// not move if it panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0b1000_0100u8];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	///
+	/// assert_eq!(reader.read_unary(), 0);
+	/// assert_eq!(reader.read_unary(), 4);
+	/// assert_eq!(reader.bits_remaining(), 2);
+	/// ```
+	pub fn read_unary(&mut self) -> u64 {
+		let zeros = self
+			.cursor
+			.rest()
+			.first_one()
+			.expect("Unary code runs past the end of the buffer");
+		self.cursor.skip(zeros + 1);
+		zeros as u64
+	}
+
+	/// Reads an Elias gamma code, and advances past it.
+	///
+	/// # Returns
+	///
+	/// The nonzero integer that the code represents.
+	///
+	/// # Panics
+	///
+	/// This panics if the code runs past the end of the buffer, or if its
+	/// prefix has more than 63 zero bits, in which case the value would not
+	/// fit in a `u64`. The reader does not move if it panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0b1010_0010u8, 0b1000_0000];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	///
+	/// assert_eq!(reader.read_gamma(), 1);
+	/// assert_eq!(reader.read_gamma(), 2);
+	/// assert_eq!(reader.read_gamma(), 5);
+	/// assert_eq!(reader.bits_consumed(), 9);
+	/// ```
+	pub fn read_gamma(&mut self) -> u64 {
+		let mut copy = *self;
+		let width = copy.read_unary();
+		assert!(width < 64, "Gamma code is too long for a `u64`: {}", width);
+		let value = 1 << width | copy.read_bits(width as u32);
+		*self = copy;
+		value
+	}
+
+	/// Advances to the next byte boundary of the buffer.
+	///
+	/// This does nothing if the reader is already on a byte boundary.
+	///
+	/// # Panics
+	///
+	/// This panics if the buffer ends before the next byte boundary.
+	pub fn align_to_byte(&mut self) {
+		let pad = self.cursor.position().wrapping_neg() & 7;
+		self.cursor.skip(pad);
+	}
+
+	/// Gets the number of bits read or skipped so far.
+	pub fn bits_consumed(&self) -> usize {
+		self.cursor.position()
+	}
+
+	/// Gets the number of bits left in the buffer.
+	pub fn bits_remaining(&self) -> usize {
+		self.cursor.remaining()
+	}
+}
+
+/** Writes most-significant-bit-first codes into a growing byte buffer.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::BitWriter;
+
+let mut writer = BitWriter::new();
+writer.write_bits(0b101, 3);
+writer.write_bits(0b10, 2);
+assert_eq!(writer.bits_written(), 5);
+
+writer.flush();
+assert_eq!(writer.bits_written(), 8);
+assert_eq!(writer.into_bytes(), [0b1011_0000]);
+```
+**/
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct BitWriter {
+	/// The codes written so far.
+	bits: BitVec<Msb0, u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl BitWriter {
+	/// Creates a writer with an empty buffer.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Writes an `n`-bit code after the end of the buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The code. Its low `n` bits are written, most significant
+	///   first, and any bits of it above `n` are discarded.
+	/// - `n`: The width of the code. A width of zero writes nothing.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is greater than 64.
+	pub fn write_bits(&mut self, value: u64, n: u32) {
+		if n == 0 {
+			return;
+		}
+		super::check::<u64>("store", n as usize);
+		let len = self.bits.len();
+		self.bits.grow_filled(n as usize, false);
+		unsafe { self.bits.get_unchecked_mut(len ..) }.store_be::<u64>(value);
+	}
+
+	/// Writes a unary code after the end of the buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The value to code. This writes `n` zero bits, then a one bit.
+	///
+	/// # Panics
+	///
+	/// This panics if the buffer would grow past the maximum length of a
+	/// `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut writer = BitWriter::new();
+	/// writer.write_unary(0);
+	/// writer.write_unary(4);
+	/// assert_eq!(writer.as_bitslice(), bits![1, 0, 0, 0, 0, 1]);
+	/// ```
+	pub fn write_unary(&mut self, n: u64) {
+		let zeros =
+			usize::try_from(n).expect("Unary code is too long for the buffer");
+		self.bits.grow_filled(zeros, false);
+		self.bits.push(true);
+	}
+
+	/// Writes an Elias gamma code after the end of the buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The value to code. The code is between 1 and 127 bits wide.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is zero, which has no gamma code.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut writer = BitWriter::new();
+	/// writer.write_gamma(1);
+	/// writer.write_gamma(2);
+	/// writer.write_gamma(5);
+	/// assert_eq!(writer.into_bytes(), [0b1010_0010, 0b1000_0000]);
+	/// ```
+	pub fn write_gamma(&mut self, n: u64) {
+		assert_ne!(n, 0, "Zero has no gamma code");
+		let width = 63 - n.leading_zeros();
+		self.write_unary(width as u64);
+		self.write_bits(n, width);
+	}
+
+	/// Pads the buffer with zero bits up to the next byte boundary.
+	///
+	/// This does nothing if the buffer already ends on a byte boundary.
+	pub fn flush(&mut self) {
+		let pad = self.bits.len().wrapping_neg() & 7;
+		self.bits.grow_filled(pad, false);
+	}
+
+	/// Gets the number of bits written so far, including any padding.
+	pub fn bits_written(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Views the written bits.
+	pub fn as_bitslice(&self) -> &BitSlice<Msb0, u8> {
+		self.bits.as_bitslice()
+	}
+
+	/// Flushes the buffer, and unwraps it.
+	///
+	/// # Returns
+	///
+	/// The written codes, packed into bytes, with the final byte padded with
+	/// zeros.
+	pub fn into_bytes(mut self) -> Vec<u8> {
+		self.flush();
+		self.bits.into_vec()
+	}
+}
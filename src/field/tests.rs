@@ -97,6 +97,43 @@ fn wide_load() {
 	assert_eq!(data[16 .. 144].load::<u128>(), !0u128);
 }
 
+#[test]
+fn aligned_fields() {
+	let data = [0u8; 3];
+	let bits = data.view_bits::<Lsb0>();
+	assert!(is_aligned(&bits[.. 8]));
+	assert!(is_aligned(&bits[8 .. 24]));
+	assert!(!is_aligned(&bits[.. 7]));
+	assert!(!is_aligned(&bits[1 .. 9]));
+
+	let value = 0x0123_4567_89AB_CDEFu64;
+	let bytes = [0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01];
+
+	let mut data = [0u8; 8];
+	data.view_bits_mut::<Lsb0>().store_le(value);
+	assert_eq!(data, bytes);
+	assert_eq!(data.view_bits::<Lsb0>().load_le::<u64>(), value);
+
+	let mut data = [0u8; 8];
+	data.view_bits_mut::<Msb0>().store_le(value);
+	assert_eq!(data, bytes);
+	assert_eq!(data.view_bits::<Msb0>().load_le::<u64>(), value);
+
+	//  The aligned path writes the same bits as the masked path does.
+	let mut aligned = [0u16; 4];
+	let mut shifted = [0u16; 5];
+	aligned.view_bits_mut::<Lsb0>().store_le(value);
+	shifted.view_bits_mut::<Lsb0>()[4 .. 68].store_le(value);
+	assert_eq!(
+		aligned.view_bits::<Lsb0>(),
+		&shifted.view_bits::<Lsb0>()[4 .. 68],
+	);
+	assert_eq!(
+		aligned.view_bits::<Lsb0>().load_le::<u64>(),
+		shifted.view_bits::<Lsb0>()[4 .. 68].load_le::<u64>(),
+	);
+}
+
 #[test]
 #[should_panic]
 #[cfg(not(target_arch = "riscv64"))]
@@ -104,6 +141,261 @@ fn check_panic() {
 	check::<u8>("fail", 10);
 }
 
+#[test]
+fn signed_fields() {
+	let mut data = [0u8; 4];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 .. 15];
+
+	bits.store_le(0xFFFu16);
+	assert_eq!(bits.load_le_signed::<i16>(), -1);
+	assert_eq!(bits.load_le_signed::<i64>(), -1);
+	bits.store_le(0x7FFu16);
+	assert_eq!(bits.load_le_signed::<i16>(), 2047);
+	assert_eq!(bits.load_le_signed::<i128>(), 2047);
+	bits.store_le(0x800u16);
+	assert_eq!(bits.load_le_signed::<i16>(), -2048);
+
+	for &value in &[-2048i16, -1000, -1, 0, 1, 1000, 2047] {
+		bits.store_le_signed(value);
+		assert_eq!(bits.load_le_signed::<i16>(), value);
+		assert_eq!(bits.load_le_signed::<isize>(), value as isize);
+		bits.store_be_signed(value as i32);
+		assert_eq!(bits.load_be_signed::<i32>(), value as i32);
+	}
+
+	//  Stores truncate to the field width.
+	bits.store_le_signed(2048i16);
+	assert_eq!(bits.load_le_signed::<i16>(), -2048);
+
+	//  A field as wide as the integer needs no extension.
+	let bits = &mut data.view_bits_mut::<Msb0>()[.. 8];
+	bits.store_be_signed(-128i8);
+	assert_eq!(bits.load_be_signed::<i8>(), -128);
+	assert_eq!(data[0], 0x80);
+
+	//  A single bit is either `0` or `-1`.
+	let bit = &mut data.view_bits_mut::<Lsb0>()[31 ..];
+	bit.store_le_signed(-1i32);
+	assert_eq!(bit.load_le_signed::<i32>(), -1);
+	assert_eq!(data[3], 0x80);
+}
+
+#[test]
+#[should_panic]
+fn signed_field_too_wide() {
+	bits![0; 9].load_le_signed::<i8>();
+}
+
+#[test]
+fn gray_fields() {
+	let mut data = [0u8; 3];
+	for width in 1 ..= 12 {
+		for value in 0 .. 1u16 << width {
+			let gray = value ^ (value >> 1);
+
+			let bits = &mut data.view_bits_mut::<Lsb0>()[5 .. 5 + width];
+			bits.store_le_gray(value);
+			assert_eq!(bits.load_le::<u16>(), gray);
+			assert_eq!(bits.load_le_gray::<u16>(), value);
+			assert_eq!(bits.load_le_gray::<u32>(), value as u32);
+
+			let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 3 + width];
+			bits.store_be_gray(value);
+			assert_eq!(bits.load_be::<u16>(), gray);
+			assert_eq!(bits.load_be_gray::<u16>(), value);
+
+			//  Successive values differ in exactly one bit.
+			if value != 0 {
+				let prev = (value - 1) ^ ((value - 1) >> 1);
+				assert_eq!((gray ^ prev).count_ones(), 1);
+			}
+		}
+	}
+
+	//  Bits above the field are discarded before the conversion.
+	let bits = &mut data.view_bits_mut::<Lsb0>()[.. 5];
+	bits.store_le_gray(0xFFu8);
+	assert_eq!(bits.load_le::<u8>(), 0b1_0000);
+	assert_eq!(bits.load_le_gray::<u8>(), 31);
+
+	let bits = &mut data.view_bits_mut::<Msb0>()[.. 8];
+	bits.store_be_gray(0x80u8);
+	assert_eq!(bits.load_be::<u8>(), 0xC0);
+	assert_eq!(bits.load_be_gray::<u8>(), 0x80);
+}
+
+#[test]
+fn hilo_fields() {
+	let data = [0xABu8, 0xCD];
+	let bits = data.view_bits::<HiLo>();
+	assert_eq!(bits[.. 4].load_le::<u8>(), 0xA);
+	assert_eq!(bits[4 .. 8].load_le::<u8>(), 0xB);
+	assert_eq!(bits[12 ..].load_be::<u8>(), 0xD);
+	assert_eq!(bits[.. 8].load_le::<u8>(), 0xBA);
+	assert_eq!(bits.load_le::<u16>(), 0xDCBA);
+	assert_eq!(bits.load_be::<u16>(), 0xBADC);
+
+	//  Fields behave as `Lsb0` fields over nibble-swapped memory.
+	let data = [0x1234u16, 0x5678, 0x9ABC];
+	let swap = [0x2143u16, 0x6587, 0xA9CB];
+	let (hilo, lsb0) = (data.view_bits::<HiLo>(), swap.view_bits::<Lsb0>());
+	for start in 0 .. 40 {
+		for end in start + 1 ..= start + 8 {
+			assert_eq!(
+				hilo[start .. end].load_le::<u8>(),
+				lsb0[start .. end].load_le::<u8>(),
+			);
+			assert_eq!(
+				hilo[start .. end].load_be::<u8>(),
+				lsb0[start .. end].load_be::<u8>(),
+			);
+		}
+		let end = cmp::min(start + 20, hilo.len());
+		assert_eq!(
+			hilo[start .. end].load_le::<u32>(),
+			lsb0[start .. end].load_le::<u32>(),
+		);
+		assert_eq!(
+			hilo[start .. end].load_be::<u32>(),
+			lsb0[start .. end].load_be::<u32>(),
+		);
+	}
+
+	let mut data = [0u16; 3];
+	let mut swap = [0u16; 3];
+	for (start, value) in (0 .. 28).zip(0x5A3Cu32 ..) {
+		let end = start + 18;
+		let hilo = &mut data.view_bits_mut::<HiLo>()[start .. end];
+		hilo.store_le(value);
+		assert_eq!(hilo.load_le::<u32>(), value & 0x3_FFFF);
+		hilo.store_be(!value);
+		assert_eq!(hilo.load_be::<u32>(), !value & 0x3_FFFF);
+
+		let lsb0 = &mut swap.view_bits_mut::<Lsb0>()[start .. end];
+		lsb0.store_le(value);
+		lsb0.store_be(!value);
+		assert_eq!(data.view_bits::<HiLo>(), swap.view_bits::<Lsb0>());
+	}
+}
+
+#[test]
+fn checked_stores() {
+	let mut data = [0u8; 3];
+	let bits = &mut data.view_bits_mut::<Msb0>()[4 .. 16];
+
+	assert!(bits.checked_store_le(0xFFFu16).is_ok());
+	assert_eq!(bits.load_le::<u16>(), 0xFFF);
+	assert!(bits.checked_store_be(0x123u16).is_ok());
+	assert_eq!(bits.load_be::<u16>(), 0x123);
+	assert!(bits.checked_store(0x456u32).is_ok());
+	assert_eq!(bits.load::<u32>(), 0x456);
+
+	let err = bits.checked_store_le(0x1000u16).unwrap_err();
+	assert_eq!(err, bits.checked_store_be(0x1000u16).unwrap_err());
+	assert_eq!((err.value(), err.width()), (0x1000, 12));
+	assert_eq!(bits.load::<u32>(), 0x456);
+	assert!(bits.checked_store(!0u64).is_err());
+
+	//  A full-width region holds every value.
+	let bits = &mut data.view_bits_mut::<Lsb0>()[.. 8];
+	assert!(bits.checked_store_le(0xFFu8).is_ok());
+	assert_eq!(data[0], 0xFF);
+}
+
+#[test]
+#[should_panic]
+fn checked_store_empty() {
+	let mut data = 0u8;
+	let _ = data.view_bits_mut::<Lsb0>()[.. 0].checked_store_le(0u8);
+}
+
+#[test]
+fn byte_buffer_fields() {
+	let mut data = [0u32; 8];
+	for (idx, elem) in data.iter_mut().enumerate() {
+		*elem = 0x0123_4567u32.rotate_left(idx as u32 * 4);
+	}
+
+	//  Narrow fields agree with the integer transfers.
+	let bits = &data.view_bits::<Lsb0>()[5 .. 69];
+	let mut bytes = [0u8; 8];
+	bits.load_be_bytes(&mut bytes);
+	assert_eq!(u64::from_be_bytes(bytes), bits.load_be::<u64>());
+	bits.load_le_bytes(&mut bytes);
+	assert_eq!(u64::from_le_bytes(bytes), bits.load_le::<u64>());
+
+	let bits = &data.view_bits::<Msb0>()[5 .. 69];
+	bits.load_be_bytes(&mut bytes);
+	assert_eq!(u64::from_be_bytes(bytes), bits.load_be::<u64>());
+	bits.load_le_bytes(&mut bytes);
+	assert_eq!(u64::from_le_bytes(bytes), bits.load_le::<u64>());
+
+	//  Wide fields survive a round trip.
+	let src = &data.view_bits::<Lsb0>()[3 .. 203];
+	let mut wide = [0u8; 26];
+	src.load_be_bytes(&mut wide);
+	assert_eq!(&wide[.. 1], &[0]);
+	let mut dst = bitarr![Msb0, u16; 0; 256];
+	dst[7 .. 207].store_be_bytes(&wide);
+	let mut back = [0u8; 26];
+	dst[7 .. 207].load_be_bytes(&mut back);
+	assert_eq!(wide, back);
+
+	src.load_le_bytes(&mut wide);
+	dst[7 .. 207].store_le_bytes(&wide);
+	dst[7 .. 207].load_le_bytes(&mut back);
+	assert_eq!(wide, back);
+}
+
+#[test]
+#[should_panic]
+fn byte_buffer_too_narrow() {
+	bits![0; 17].load_be_bytes(&mut [0u8; 2]);
+}
+
+#[test]
+fn fallible_transfers() {
+	let mut data = [0u8; 4];
+	let bits = data.view_bits_mut::<Lsb0>();
+
+	assert_eq!(bits[4 .. 20].try_store_le(0xABCDu16), Ok(()));
+	assert_eq!(bits[4 .. 20].try_load_le::<u16>(), Ok(0xABCD));
+	assert_eq!(bits[4 .. 20].try_store_be(0x1234u16), Ok(()));
+	assert_eq!(bits[4 .. 20].try_load_be::<u16>(), Ok(0x1234));
+	assert_eq!(bits[.. 32].try_store(!0u32), Ok(()));
+	assert_eq!(bits.try_load::<u32>(), Ok(!0));
+
+	let err = bits[.. 20].try_load_le::<u16>().unwrap_err();
+	assert_eq!((err.width(), err.max_bits()), (20, 16));
+	let err = bits[3 .. 3].try_load_be::<u64>().unwrap_err();
+	assert_eq!((err.width(), err.max_bits()), (0, 64));
+
+	//  A failed store leaves memory untouched.
+	assert!(bits[.. 9].try_store_le(0u8).is_err());
+	assert!(bits[.. 9].try_store_be(0u8).is_err());
+	assert!(bits[.. 0].try_store(0u8).is_err());
+	assert_eq!(data, [!0; 4]);
+
+	let data = [0u16; 3];
+	let err = data.view_bits::<Msb0>().try_load::<u32>().unwrap_err();
+	assert_eq!(err.width(), 48);
+	#[cfg(feature = "std")]
+	assert_eq!(
+		err.to_string(),
+		"Cannot transfer a 32-bit integer through a 48-bit region",
+	);
+
+	let arr = bitarr![Msb0, u8; 1; 12];
+	assert_eq!(arr[.. 12].try_load_be::<u16>(), Ok(0x0FFF));
+	assert!(arr.try_load_be::<u8>().is_err());
+}
+
+#[test]
+#[should_panic]
+fn load_too_wide() {
+	bits![0; 9].load_le::<u8>();
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn wrappers() {
@@ -130,3 +422,771 @@ fn wrappers() {
 	assert_eq!(d.load_le::<u8>(), !0);
 	assert_eq!(d.load_be::<u8>(), !0);
 }
+
+#[test]
+fn for_each_byte() {
+	let data = [0xA5u8, 0x3C, 0x0F];
+	let mut out = [0u8; 3];
+	let mut idx = 0;
+	data.view_bits::<Lsb0>().for_each_byte(|byte| {
+		out[idx] = byte;
+		idx += 1;
+	});
+	assert_eq!(idx, 3);
+	assert_eq!(out, data);
+
+	//  Unaligned starts still produce whole bytes in sequence.
+	let bits = &data.view_bits::<Msb0>()[4 ..];
+	let mut out = [0u8; 3];
+	let mut idx = 0;
+	bits.for_each_byte(|byte| {
+		out[idx] = byte;
+		idx += 1;
+	});
+	assert_eq!(idx, 3);
+	assert_eq!(out, [0x53, 0xC0, 0x0F]);
+
+	//  The final partial byte is zero-extended from its low bits.
+	let mut last = 0xFFu8;
+	data.view_bits::<Msb0>()[.. 11].for_each_byte(|byte| last = byte);
+	assert_eq!(last, 0b001);
+
+	let mut count = 0;
+	BitSlice::<Lsb0, u8>::empty().for_each_byte(|_| count += 1);
+	assert_eq!(count, 0);
+}
+
+#[test]
+fn duration_ticks() {
+	use core::time::Duration;
+
+	let mut data = [0u16; 2];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 23];
+
+	bits.store_duration_le(Duration::from_millis(250), 1_000);
+	assert_eq!(bits.load_le::<u32>(), 250);
+	assert_eq!(bits.load_duration_le(1_000), Duration::from_millis(250));
+
+	//  Sub-tick remainders round down.
+	bits.store_duration_le(Duration::from_micros(1_999), 1_000);
+	assert_eq!(bits.load_le::<u32>(), 1);
+
+	//  Counts beyond the field width saturate.
+	bits.store_duration_le(Duration::from_secs(u64::MAX), 1_000);
+	assert_eq!(bits.load_le::<u32>(), (1 << 20) - 1);
+
+	//  Loading a count wider than `Duration` saturates.
+	let wide = [!0u64; 2];
+	let max = wide.view_bits::<Lsb0>().load_duration_le(1);
+	assert_eq!(max, Duration::new(u64::MAX, 999_999_999));
+}
+
+#[test]
+fn timestamps() {
+	let mut data = [0u8; 8];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 45];
+
+	bits.store_timestamp_le(86_400, 10, 1_023);
+	assert_eq!(bits.load_le::<u64>(), 86_400 << 10 | 1_023);
+	assert_eq!(bits.load_timestamp_le(10), (86_400, 1_023));
+
+	//  Thirty seconds bits hold up to 2^30 - 1 seconds.
+	bits.store_timestamp_le((1 << 30) - 1, 10, 0);
+	assert_eq!(bits.load_timestamp_le(10), ((1 << 30) - 1, 0));
+
+	//  Seconds that overflow their bits saturate the whole timestamp.
+	bits.store_timestamp_le(1 << 30, 10, 0);
+	assert_eq!(bits.load_timestamp_le(10), ((1 << 30) - 1, 1_023));
+	bits.store_timestamp_le(u64::MAX, 32, 5);
+	assert!(bits.all());
+
+	//  Seconds wider than `u64` still load when they fit.
+	let mut wide = [0u64; 2];
+	let bits = wide.view_bits_mut::<Lsb0>();
+	bits.store_timestamp_le(u64::MAX, 32, 7);
+	assert_eq!(bits.load_timestamp_le(32), (u64::MAX, 7));
+}
+
+#[test]
+#[should_panic]
+fn timestamp_sub_value_too_wide() {
+	bits![mut Lsb0, u8; 0; 16].store_timestamp_le(1, 4, 16);
+}
+
+#[test]
+#[should_panic]
+fn timestamp_no_seconds() {
+	bits![mut Lsb0, u8; 0; 8].store_timestamp_le(0, 8, 0);
+}
+
+#[test]
+fn rgb_pixels() {
+	let mut data = [0u8; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 19];
+
+	bits.store_rgb565(0, 0, 0);
+	assert_eq!(bits.load_le::<u16>(), 0);
+	assert_eq!(bits.load_rgb565(), (0, 0, 0));
+	bits.store_rgb565(255, 255, 255);
+	assert_eq!(bits.load_le::<u16>(), !0);
+	assert_eq!(bits.load_rgb565(), (255, 255, 255));
+
+	//  Quantization rounds to the nearest step.
+	bits.store_rgb565(5, 2, 4);
+	assert_eq!(bits.load_le::<u16>(), 1 << 11);
+	bits.store_rgb565(4, 3, 5);
+	assert_eq!(bits.load_le::<u16>(), 1 << 5 | 1);
+
+	//  Every packed value survives a round trip through eight bits.
+	for code in 0 .. 32u16 {
+		bits.store_le::<u16>(code << 11 | code << 6 | code);
+		let (r, g, b) = bits.load_rgb565();
+		bits.store_rgb565(r, g, b);
+		assert_eq!(bits.load_le::<u16>(), code << 11 | code << 6 | code);
+	}
+	for code in 0 .. 64u16 {
+		bits.store_le::<u16>(code << 5);
+		let (r, g, b) = bits.load_rgb565();
+		assert_eq!((r, b), (0, 0));
+		bits.store_rgb565(r, g, b);
+		assert_eq!(bits.load_le::<u16>(), code << 5);
+	}
+
+	let mut pixel = !0u16;
+	let bits = &mut pixel.view_bits_mut::<Lsb0>()[.. 15];
+	bits.store_rgb555(0, 0, 0);
+	assert_eq!(pixel, 0x8000);
+	let bits = &mut pixel.view_bits_mut::<Lsb0>()[.. 15];
+	for code in 0 .. 32u16 {
+		bits.store_le::<u16>(code << 10 | code << 5 | code);
+		let (r, g, b) = bits.load_rgb555();
+		assert_eq!((r, g), (b, b));
+		bits.store_rgb555(r, g, b);
+		assert_eq!(bits.load_le::<u16>(), code << 10 | code << 5 | code);
+	}
+	assert_eq!(bits.load_rgb555(), (255, 255, 255));
+	assert_eq!(pixel, !0);
+}
+
+#[test]
+#[should_panic]
+fn rgb565_wrong_width() {
+	bits![mut Lsb0, u16; 0; 15].store_rgb565(0, 0, 0);
+}
+
+#[test]
+fn load_lenprefixed() {
+	let data = [0b0110_0011u8, 0b0000_0101];
+	let bits = data.view_bits::<Lsb0>();
+
+	let (payload, used) = bits.load_lenprefixed(2).unwrap();
+	assert_eq!(payload, bits![0, 0, 0]);
+	assert_eq!(used, 5);
+	let (payload, used) = bits[used ..].load_lenprefixed(3).unwrap();
+	assert_eq!(payload, bits![1, 0, 1]);
+	assert_eq!(used, 6);
+
+	//  An empty payload occupies only the header.
+	let (payload, used) = bits![0, 0, 1].load_lenprefixed(2).unwrap();
+	assert!(payload.is_empty());
+	assert_eq!(used, 2);
+
+	assert!(bits[.. 4].load_lenprefixed(2).is_none());
+	assert!(bits[.. 1].load_lenprefixed(2).is_none());
+}
+
+#[test]
+#[should_panic]
+fn load_lenprefixed_no_header() {
+	bits![0; 4].load_lenprefixed(0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn store_lenprefixed() {
+	let mut bv = BitVec::<Msb0, u16>::new();
+	bv.store_lenprefixed(5, bits![1, 1, 0]);
+	bv.store_lenprefixed(3, BitSlice::<Lsb0, u8>::empty());
+	bv.store_lenprefixed(5, bits![1; 20]);
+	assert_eq!(bv.len(), 5 + 3 + 3 + 5 + 20);
+
+	let (payload, used) = bv.load_lenprefixed(5).unwrap();
+	assert_eq!(payload, bits![1, 1, 0]);
+	let rest = &bv[used ..];
+	let (payload, used) = rest.load_lenprefixed(3).unwrap();
+	assert!(payload.is_empty());
+	let rest = &rest[used ..];
+	let (payload, used) = rest.load_lenprefixed(5).unwrap();
+	assert_eq!(payload, bits![1; 20]);
+	assert_eq!(used, rest.len());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn store_lenprefixed_too_long() {
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	bv.store_lenprefixed(4, bits![0; 16]);
+}
+
+#[test]
+fn mixed_radix() {
+	let mut data = [0u8; 8];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 ..];
+
+	//  Year offset, month, day, hour, minute, second, and a unit digit.
+	let stamp = [
+		(51, 100),
+		(11, 12),
+		(30, 31),
+		(23, 24),
+		(0, 60),
+		(59, 60),
+		(0, 1),
+	];
+	let used = bits.store_mixed_radix(&stamp);
+	assert_eq!(used, 7 + 4 + 5 + 5 + 6 + 6);
+	assert!(bits[used ..].not_any());
+
+	let mut loaded = stamp;
+	for (value, _) in loaded.iter_mut() {
+		*value = !0;
+	}
+	assert_eq!(bits.load_mixed_radix(&mut loaded), used);
+	assert_eq!(loaded, stamp);
+
+	//  Powers of two fill their digits exactly.
+	let mut data = 0u16;
+	let bits = data.view_bits_mut::<Lsb0>();
+	assert_eq!(bits.store_mixed_radix(&[(3, 4), (255, 256), (1, 2)]), 11);
+	assert_eq!(data, 3 | 255 << 2 | 1 << 10);
+
+	//  Digits with a radix of one take no space.
+	let empty = BitSlice::<Lsb0, u8>::empty_mut();
+	assert_eq!(empty.store_mixed_radix(&[]), 0);
+	assert_eq!(empty.store_mixed_radix(&[(0, 1), (0, 1)]), 0);
+}
+
+#[test]
+#[should_panic]
+fn mixed_radix_digit_too_large() {
+	bits![mut 0; 16].store_mixed_radix(&[(5, 10), (10, 10)]);
+}
+
+#[test]
+#[should_panic]
+fn mixed_radix_zero_radix() {
+	bits![mut 0; 16].store_mixed_radix(&[(0, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn mixed_radix_too_wide() {
+	bits![mut 0; 8].store_mixed_radix(&[(0, 16), (0, 32)]);
+}
+
+#[test]
+fn field_cursors() {
+	let mut data = [0u16; 3];
+	let mut cursor = data.view_bits_mut::<Msb0>().field_cursor_mut();
+	assert_eq!((cursor.position(), cursor.remaining()), (0, 48));
+	cursor.write_be(0x5u8, 3);
+	cursor.write_le(0xABCu16, 12);
+	cursor.skip(1);
+	cursor.write_be(0xDEAD_BEEFu32, 32);
+	assert_eq!((cursor.position(), cursor.remaining()), (48, 0));
+
+	let bits = data.view_bits::<Msb0>();
+	let mut cursor = bits.field_cursor();
+	assert_eq!(cursor.read_be::<u8>(3), 0x5);
+	assert_eq!(cursor.read_le::<u16>(12), 0xABC);
+	cursor.skip(1);
+	let mut copy = cursor;
+	assert_eq!(cursor.read_be::<u32>(32), 0xDEAD_BEEF);
+	assert_eq!(cursor.remaining(), 0);
+	//  Copies of a cursor advance independently.
+	assert_eq!(copy.position(), 16);
+	assert_eq!(copy.read_be::<u16>(16), 0xDEAD);
+
+	//  A writing cursor can also read back.
+	let mut cursor = data.view_bits_mut::<Msb0>().field_cursor_mut();
+	assert_eq!(cursor.read_be::<u8>(3), 0x5);
+	cursor.write_le(0x123u16, 12);
+	assert_eq!(cursor.read_be::<u8>(1), 0);
+	assert_eq!(cursor.read_be::<u32>(32), 0xDEAD_BEEF);
+	assert_eq!(data.view_bits::<Msb0>()[3 .. 15].load_le::<u16>(), 0x123);
+}
+
+#[test]
+#[should_panic]
+fn field_cursor_past_end() {
+	let data = 0u8;
+	let mut cursor = data.view_bits::<Lsb0>().field_cursor();
+	cursor.read_le::<u8>(5);
+	cursor.read_le::<u8>(5);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn field_encoder() {
+	let payload = bits![Msb0, u16; 1, 1, 0, 1];
+	let encoder = FieldEncoder::<Msb0, u8>::with_capacity(64)
+		.push_le(0x5u8, 4)
+		.push_be(0xABCu16, 12)
+		.push_bool(true);
+	assert_eq!(encoder.len(), 17);
+	let msg = encoder
+		.push_le(!0u32, 9)
+		.push_bits(payload)
+		.push_le(0x1_2345_6789u64, 36)
+		.finish();
+
+	assert_eq!(msg.len(), 66);
+	assert_eq!(msg[.. 4].load_le::<u8>(), 0x5);
+	assert_eq!(msg[4 .. 16].load_be::<u16>(), 0xABC);
+	assert!(msg[16]);
+	assert!(msg[17 .. 26].all());
+	assert_eq!(&msg[26 .. 30], payload);
+	assert_eq!(msg[30 ..].load_le::<u64>(), 0x1_2345_6789);
+
+	assert!(FieldEncoder::<Lsb0, usize>::new().finish().is_empty());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn field_encoder_too_wide() {
+	let _ = FieldEncoder::<Lsb0, u8>::new().push_le(0u8, 9);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn bitstreams() {
+	let mut writer = BitWriter::new();
+	writer.write_bits(0b1, 1);
+	writer.write_bits(0, 0);
+	writer.write_bits(0x1FF, 5);
+	writer.write_bits(0xDEAD_BEEF_0123_4567, 64);
+	assert_eq!(writer.bits_written(), 70);
+	assert_eq!(writer.as_bitslice()[.. 6], bits![1, 1, 1, 1, 1, 1]);
+	writer.flush();
+	writer.flush();
+	assert_eq!(writer.bits_written(), 72);
+	writer.write_bits(0b011, 3);
+
+	let bytes = writer.into_bytes();
+	assert_eq!(bytes, [
+		0xFF, 0x7A, 0xB6, 0xFB, 0xBC, 0x04, 0x8D, 0x15, 0x9C, 0x60,
+	]);
+
+	let mut reader = BitReader::new(bytes.view_bits::<Msb0>());
+	assert_eq!(reader.read_bits(1), 1);
+	assert_eq!(reader.peek_bits(5), 0x1F);
+	assert_eq!(reader.bits_consumed(), 1);
+	assert_eq!(reader.read_bits(5), 0x1F);
+	assert_eq!(reader.read_bits(0), 0);
+	assert_eq!(reader.read_bits(64), 0xDEAD_BEEF_0123_4567);
+	assert_eq!(reader.bits_consumed(), 70);
+	reader.align_to_byte();
+	reader.align_to_byte();
+	assert_eq!(reader.bits_consumed(), 72);
+	assert_eq!(reader.read_bits(3), 0b011);
+	assert_eq!(reader.bits_remaining(), 5);
+}
+
+#[test]
+#[should_panic]
+fn bitstream_past_end() {
+	let data = 0u8;
+	let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	reader.read_bits(6);
+	reader.read_bits(6);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn unary_gamma_codes() {
+	let mut state = 0x853C_49E6_748F_EA9Bu64;
+	let mut next = || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	};
+
+	//  Values of every width, from 1 to 64 bits.
+	let mut values = [1u64; 512];
+	for (idx, value) in values.iter_mut().enumerate() {
+		*value = cmp::max(next() >> (idx % 64), 1);
+	}
+	values[.. 5].copy_from_slice(&[1, 2, 3, u64::MAX, 1 << 63]);
+	let mut lengths = [0u64; 64];
+	for len in lengths.iter_mut() {
+		*len = next() % 40;
+	}
+
+	let mut writer = BitWriter::new();
+	for (&value, &len) in values.iter().zip(lengths.iter().cycle()) {
+		writer.write_gamma(value);
+		writer.write_unary(len);
+	}
+	let written = writer.bits_written();
+	let bytes = writer.into_bytes();
+
+	let mut reader = BitReader::new(bytes.view_bits::<Msb0>());
+	for (&value, &len) in values.iter().zip(lengths.iter().cycle()) {
+		assert_eq!(reader.read_gamma(), value);
+		assert_eq!(reader.read_unary(), len);
+	}
+	assert_eq!(reader.bits_consumed(), written);
+	assert!(reader.bits_remaining() < 8);
+
+	let mut writer = BitWriter::new();
+	writer.write_gamma(1);
+	assert_eq!(writer.as_bitslice(), bits![1]);
+	writer.write_gamma(u64::MAX);
+	assert_eq!(writer.bits_written(), 128);
+}
+
+#[test]
+#[should_panic(expected = "Zero has no gamma code")]
+#[cfg(feature = "alloc")]
+fn gamma_zero() {
+	BitWriter::new().write_gamma(0);
+}
+
+#[test]
+#[should_panic(expected = "Unary code runs past the end of the buffer")]
+fn unary_past_end() {
+	let data = [0u8, 0x01, 0];
+	let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	assert_eq!(reader.read_unary(), 15);
+	reader.read_unary();
+}
+
+#[test]
+#[should_panic(expected = "Gamma code is too long for a `u64`: 64")]
+fn gamma_too_long() {
+	let data = [0, 0, 0, 0, 0, 0, 0, 0, 0x80u8];
+	BitReader::new(data.view_bits::<Msb0>()).read_gamma();
+}
+
+#[test]
+fn export_le() {
+	let data = [0xA5u8, 0x3C, 0x0F];
+
+	let mut out = [0u16; 2];
+	data.view_bits::<Lsb0>().export_le(&mut out);
+	assert_eq!(out, [0x3CA5, 0x000F]);
+
+	//  The final, partial chunk is zero-padded in its high bits.
+	let mut out = [0u8; 2];
+	data.view_bits::<Msb0>()[4 .. 17].export_le(&mut out);
+	assert_eq!(out, [0x35, 0x0C]);
+
+	let mut out: [u32; 0] = [];
+	BitSlice::<Lsb0, u8>::empty().export_le(&mut out);
+}
+
+#[test]
+#[should_panic]
+fn export_le_mismatch() {
+	let mut out = [0u8; 1];
+	bits![Lsb0, u8; 0; 9].export_le(&mut out);
+}
+
+#[test]
+fn load_le_saturating() {
+	let mut data = [0u8; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 25];
+
+	bits.store_le(255u32);
+	assert_eq!(bits.load_le_saturating::<u32, u8>(), 255);
+	bits.store_le(256u32);
+	assert_eq!(bits.load_le_saturating::<u32, u8>(), 255);
+	bits.store_le(0xFFFFFu32);
+	assert_eq!(bits.load_le_saturating::<u32, u16>(), !0);
+	assert_eq!(bits.load_le_saturating::<u32, u8>(), !0);
+
+	//  Widening never saturates.
+	bits.store_le(0xABCDEu32);
+	assert_eq!(bits.load_le_saturating::<u32, u64>(), 0xABCDE);
+
+	let bits = &mut data.view_bits_mut::<Lsb0>()[.. 8];
+	bits.store_le(0x80u8);
+	assert_eq!(bits.load_le_saturating::<u8, u16>(), 0x80);
+}
+
+#[test]
+fn store_clamped_signed_le() {
+	let mut data = [0u16; 5];
+	for width in [1usize, 2, 7, 8, 13, 33, 63, 64].iter().copied() {
+		let bits = &mut data.view_bits_mut::<Msb0>()[5 ..][.. width];
+		let max = i64::MAX >> (64 - width);
+		let min = -max - 1;
+		let mask = !0u64 >> (64 - width);
+
+		assert_eq!(bits.store_clamped_signed_le(i64::MAX), max);
+		assert_eq!(bits.load_le::<u64>(), max as u64 & mask);
+		assert_eq!(bits.store_clamped_signed_le(max), max);
+		assert_eq!(bits.store_clamped_signed_le(i64::MIN), min);
+		assert_eq!(bits.load_le::<u64>(), min as u64 & mask);
+		assert_eq!(bits.count_ones(), 1);
+		assert_eq!(bits.store_clamped_signed_le(min), min);
+
+		assert_eq!(bits.store_clamped_signed_le(0), 0);
+		assert!(bits.not_any());
+		assert_eq!(bits.store_clamped_signed_le(-1), -1);
+		assert!(bits.all());
+	}
+
+	//  The asymmetric edges of a byte-wide field.
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 .. 11];
+	assert_eq!(bits.store_clamped_signed_le(128), 127);
+	assert_eq!(bits.store_clamped_signed_le(-128), -128);
+	assert_eq!(bits.store_clamped_signed_le(-129), -128);
+	assert_eq!(bits.load_le::<u8>() as i8, -128);
+}
+
+#[test]
+fn try_fields_le() {
+	let data = [0x0123_4567u32, 0x89AB_CDEF];
+	let bits = &data.view_bits::<Lsb0>()[4 .. 62];
+
+	let mut seen = [0u16; 5];
+	let res = bits.try_fields_le::<u16, (), _>(12, |idx, val| {
+		seen[idx] = val;
+		Ok(())
+	});
+	assert!(res.is_ok());
+	//  The final field holds only ten bits, zero-extended.
+	assert_eq!(seen, [0x456, 0x123, 0xEF0, 0xBCD, 0x09A]);
+
+	let mut calls = 0;
+	let res = bits.try_fields_le::<u16, _, _>(12, |idx, val| {
+		calls += 1;
+		if val > 0xE00 {
+			return Err((idx, val));
+		}
+		Ok(())
+	});
+	assert_eq!(res, Err((2, 0xEF0)));
+	assert_eq!(calls, 3);
+}
+
+#[test]
+#[should_panic]
+fn try_fields_le_too_wide() {
+	let _ = bits![Lsb0, u8; 0; 16].try_fields_le::<u8, (), _>(9, |_, _| Ok(()));
+}
+
+#[test]
+fn optional_fields() {
+	let mut data = [!0u8; 2];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 ..];
+
+	bits.store_optional_le(10, Some(0x2A5u16));
+	assert!(bits[0]);
+	assert_eq!(bits[1 .. 11].load_le::<u16>(), 0x2A5);
+	assert_eq!(bits.load_optional_le::<u16>(10), Some(0x2A5));
+
+	//  Absent values only touch the flag bit.
+	bits.store_optional_le::<u16>(10, None);
+	assert!(!bits[0]);
+	assert_eq!(bits[1 .. 11].load_le::<u16>(), 0x2A5);
+	assert!(bits.load_optional_le::<u16>(10).is_none());
+
+	//  A clear flag needs no room for a payload.
+	assert!(bits[.. 1].load_optional_le::<u16>(10).is_none());
+}
+
+#[test]
+fn morton2() {
+	let mut data = [0u32; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 69];
+
+	let (x, y) = (0xDEAD_BEEFu32, 0x0123_4567u32);
+	bits.store_morton2_le(x, y);
+	for idx in 0 .. 32 {
+		assert_eq!(bits[2 * idx], x & (1 << idx) != 0);
+		assert_eq!(bits[2 * idx + 1], y & (1 << idx) != 0);
+	}
+	assert_eq!(bits.load_morton2_le::<u32>(), (x, y));
+
+	//  The full width of two `u64`s fills a `u128` code.
+	let mut wide = [0u64; 3];
+	let bits = &mut wide.view_bits_mut::<Lsb0>()[7 .. 135];
+	bits.store_morton2_le(!0u64, 0u64);
+	assert_eq!(
+		bits.load_le::<u128>(),
+		0x5555_5555_5555_5555_5555_5555_5555_5555
+	);
+	assert_eq!(bits.load_morton2_le::<u64>(), (!0, 0));
+
+	//  An odd width gives `x` the extra bit, and drops high coordinate bits.
+	let mut data = 0u8;
+	let bits = &mut data.view_bits_mut::<Lsb0>()[.. 7];
+	bits.store_morton2_le(0xFFu8, 0xFFu8);
+	assert_eq!(data, 0x7F);
+	assert_eq!(bits.load_morton2_le::<u8>(), (0x0F, 0x07));
+}
+
+#[test]
+#[should_panic]
+fn morton2_too_wide() {
+	bits![mut Lsb0, u16; 0; 17].store_morton2_le(0u8, 0u8);
+}
+
+#[test]
+fn split_fields() {
+	let mut data = [0u8; 8];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 ..];
+
+	//  A 32-bit base address: the high 20 bits precede the low 12.
+	bits.store_split_le(0xDEAD_BEEFu32, 40 .. 60, 5 .. 17);
+	assert_eq!(bits[5 .. 17].load_le::<u16>(), 0xEEF);
+	assert_eq!(bits[40 .. 60].load_le::<u32>(), 0xDEADB);
+	assert!(bits[.. 5].not_any());
+	assert!(bits[17 .. 40].not_any());
+	assert_eq!(bits.load_split_le::<u32>(40 .. 60, 5 .. 17), 0xDEAD_BEEF);
+
+	//  The high part may also come first, and bits above the width drop.
+	bits.store_split_le(0xFFFF_u16, 0 .. 3, 10 .. 14);
+	assert_eq!(bits.load_split_le::<u16>(0 .. 3, 10 .. 14), 0x7F);
+	assert_eq!(bits.load_split_le::<u8>(0 .. 3, 10 .. 14), 0x7F);
+}
+
+#[test]
+#[should_panic]
+fn split_fields_overlap() {
+	let mut data = 0u32;
+	data.view_bits_mut::<Lsb0>().store_split_le(0u8, 0 .. 4, 3 .. 6);
+}
+
+#[test]
+#[should_panic]
+fn split_fields_too_wide() {
+	let data = 0u32;
+	let _ = data.view_bits::<Lsb0>().load_split_le::<u8>(0 .. 4, 8 .. 13);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn split_fields_checked_before_store() {
+	use std::panic::{
+		catch_unwind,
+		AssertUnwindSafe,
+	};
+
+	let mut data = 0u32;
+	let bits = data.view_bits_mut::<Lsb0>();
+	for high in [4 .. 4, 30 .. 34].iter().cloned() {
+		let res = catch_unwind(AssertUnwindSafe(|| {
+			bits.store_split_le(!0u16, high, 0 .. 4);
+		}));
+		assert!(res.is_err());
+	}
+	assert_eq!(data, 0);
+}
+
+#[test]
+fn blit() {
+	//  Reference: set or combine each bit of the block individually.
+	fn reference(
+		dst: &mut BitSlice<Lsb0, u16>,
+		dst_stride: usize,
+		(dst_x, dst_y): (usize, usize),
+		src: &BitSlice<Lsb0, u16>,
+		src_stride: usize,
+		(width, height): (usize, usize),
+		op: BlitOp,
+	)
+	{
+		for row in 0 .. height {
+			for col in 0 .. width {
+				let idx = (dst_y + row) * dst_stride + dst_x + col;
+				let s = src[row * src_stride + col];
+				let d = dst[idx];
+				dst.set(idx, match op {
+					BlitOp::Copy => s,
+					BlitOp::Or => d | s,
+					BlitOp::And => d & s,
+					BlitOp::Xor => d ^ s,
+				});
+			}
+		}
+	}
+
+	let mut pattern = [0u16; 20];
+	for (idx, elem) in pattern.iter_mut().enumerate() {
+		*elem = (idx as u16).wrapping_mul(0x9E37) ^ 0x5A5A;
+	}
+
+	for &op in &[BlitOp::Copy, BlitOp::Or, BlitOp::And, BlitOp::Xor] {
+		for &(sx, dx) in &[(0, 0), (3, 5), (13, 1), (7, 70)] {
+			let mut expected = [0x0FF0u16; 48];
+			let mut actual = expected;
+			//  The source is read from an unaligned column of `pattern`.
+			let src = &pattern.view_bits::<Lsb0>()[sx ..];
+			let (width, height) = (83, 3);
+
+			reference(
+				expected.view_bits_mut::<Lsb0>(),
+				160,
+				(dx, 1),
+				src,
+				96,
+				(width, height),
+				op,
+			);
+			actual.view_bits_mut::<Lsb0>().blit(
+				160, dx, 1, src, 96, width, height, op,
+			);
+			assert_eq!(actual, expected, "{:?} {} {}", op, sx, dx);
+		}
+	}
+}
+
+#[test]
+#[cfg(all(feature = "trace", feature = "std"))]
+fn trace_events() {
+	use crate::field::trace::{
+		self,
+		FieldAccess,
+		FieldEvent,
+	};
+	use std::cell::RefCell;
+
+	std::thread_local! {
+		static EVENTS: RefCell<Vec<FieldEvent>> = RefCell::new(Vec::new());
+	}
+
+	fn record(event: &FieldEvent) {
+		EVENTS.with(|events| events.borrow_mut().push(*event));
+	}
+
+	let mut data = [0u8; 4];
+	trace::set_field_trace_base(data.as_ptr());
+	trace::set_field_tracer(Some(record));
+	data.view_bits_mut::<Msb0>()[10 .. 22].store_le(0xABCu16);
+	let value = data.view_bits::<Lsb0>()[3 .. 7].load_le::<u8>();
+	trace::set_field_tracer(None);
+	let _ = data.view_bits::<Lsb0>()[.. 8].load_le::<u8>();
+
+	let events = EVENTS.with(|events| events.borrow().clone());
+	//  Accesses made by other test threads also reach `record`, but are logged
+	//  in those threads’ own storage.
+	assert_eq!(events.len(), 2);
+	assert_eq!(events[0], FieldEvent {
+		access: FieldAccess::Store,
+		offset: 10,
+		width: 12,
+		value: 0xABC,
+	});
+	assert_eq!(events[1], FieldEvent {
+		access: FieldAccess::Load,
+		offset: 3,
+		width: 4,
+		value: value as u128,
+	});
+}
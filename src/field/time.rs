@@ -0,0 +1,253 @@
+/*! Time values.
+
+These store a [`Duration`] as an integer tick count, and split a fixed-point
+timestamp into whole seconds and fractional bits.
+
+[`Duration`]: core::time::Duration
+!*/
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	time::Duration,
+};
+
+/// Duration and timestamp transfers.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores a `Duration` into the slice as a little-endian tick count.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `d`: The duration to store.
+	/// - `ticks_per_sec`: The number of ticks in one second. This sets the
+	///   resolution of the stored count.
+	///
+	/// # Behavior
+	///
+	/// The duration is converted to a whole number of ticks, rounding any
+	/// sub-tick remainder down towards zero. If the tick count does not fit in
+	/// `self.len()` bits, it saturates to the largest value the slice can hold.
+	/// The count is then written with [`.store_le::<u128>()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `ticks_per_sec` is zero, or if `self.len()` is zero or
+	/// greater than 128.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::time::Duration;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 12];
+	///
+	/// bits.store_duration_le(Duration::from_millis(1_500), 1_000);
+	/// assert_eq!(bits.load_le::<u16>(), 1_500);
+	///
+	/// //  4095 is the largest 12-bit value.
+	/// bits.store_duration_le(Duration::from_secs(10), 1_000);
+	/// assert_eq!(bits.load_le::<u16>(), 4_095);
+	/// ```
+	///
+	/// [`.store_le::<u128>()`]: crate::field::BitField::store_le
+	pub fn store_duration_le(&mut self, d: Duration, ticks_per_sec: u64) {
+		assert!(ticks_per_sec != 0, "Cannot use a tick rate of zero");
+		super::check::<u128>("store", self.len());
+		let tps = ticks_per_sec as u128;
+		/* Splitting the duration into whole seconds and nanoseconds keeps
+		both products within `u128`, so the conversion is exact before it is
+		rounded down.
+		*/
+		let ticks = d.as_secs() as u128 * tps
+			+ d.subsec_nanos() as u128 * tps / 1_000_000_000;
+		let max = !0u128 >> (128 - self.len());
+		self.store_le::<u128>(cmp::min(ticks, max));
+	}
+
+	/// Loads a `Duration` from a little-endian tick count in the slice.
+	///
+	/// This inverts [`.store_duration_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `ticks_per_sec`: The number of ticks in one second.
+	///
+	/// # Returns
+	///
+	/// The duration represented by the tick count loaded with
+	/// [`.load_le::<u128>()`]. Fractions of a nanosecond are rounded down. If
+	/// the count exceeds the range of `Duration`, the maximum `Duration` is
+	/// returned.
+	///
+	/// # Panics
+	///
+	/// This panics if `ticks_per_sec` is zero, or if `self.len()` is zero or
+	/// greater than 128.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::time::Duration;
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 20];
+	///
+	/// bits.store_duration_le(Duration::from_micros(2_500), 10_000);
+	/// assert_eq!(
+	///   bits.load_duration_le(10_000),
+	///   Duration::from_micros(2_500),
+	/// );
+	/// ```
+	///
+	/// [`.load_le::<u128>()`]: crate::field::BitField::load_le
+	/// [`.store_duration_le()`]: Self::store_duration_le
+	pub fn load_duration_le(&self, ticks_per_sec: u64) -> Duration {
+		assert!(ticks_per_sec != 0, "Cannot use a tick rate of zero");
+		let ticks = self.load_le::<u128>();
+		let tps = ticks_per_sec as u128;
+		let secs = ticks / tps;
+		if secs > u64::MAX as u128 {
+			return Duration::new(u64::MAX, 999_999_999);
+		}
+		let nanos = (ticks % tps) * 1_000_000_000 / tps;
+		Duration::new(secs as u64, nanos as u32)
+	}
+
+	/// Stores a timestamp, as whole seconds and a binary fraction of a second,
+	/// into the slice as one little-endian field.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `secs`: The whole seconds of the timestamp, counted from whatever
+	///   epoch the caller uses.
+	/// - `sub_bits`: The number of bits used for the fraction of a second.
+	/// - `sub_value`: The fraction of a second, in units of `2^-sub_bits`
+	///   seconds.
+	///
+	/// # Behavior
+	///
+	/// The field holds `secs << sub_bits | sub_value`, written with
+	/// [`.store_le::<u128>()`]. The low `sub_bits` bits of the slice hold the
+	/// fraction, and the remaining high bits hold the seconds.
+	///
+	/// If `secs` does not fit in the `self.len() - sub_bits` seconds bits, the
+	/// timestamp saturates: the field is filled with ones, which is the latest
+	/// time it can represent.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than 128, if `sub_bits`
+	/// is greater than 32 or not less than `self.len()`, or if `sub_value`
+	/// does not fit in `sub_bits` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u32;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// //  1000.75 seconds, with quarter-second resolution.
+	/// bits.store_timestamp_le(1_000, 2, 3);
+	/// assert_eq!(bits.load_timestamp_le(2), (1_000, 3));
+	/// assert_eq!(data, 1_000 << 2 | 3);
+	/// ```
+	///
+	/// [`.store_le::<u128>()`]: crate::field::BitField::store_le
+	pub fn store_timestamp_le(
+		&mut self,
+		secs: u64,
+		sub_bits: u32,
+		sub_value: u32,
+	) {
+		let len = self.len();
+		super::check::<u128>("store", len);
+		check_timestamp(len, sub_bits);
+		assert!(
+			(sub_value as u64) >> sub_bits == 0,
+			"Subsecond value {} does not fit in {} bits",
+			sub_value,
+			sub_bits
+		);
+		let max = !0u128 >> (128 - len);
+		let stamp = (secs as u128) << sub_bits | sub_value as u128;
+		if stamp > max {
+			self.store_le::<u128>(max);
+		}
+		else {
+			self.store_le::<u128>(stamp);
+		}
+	}
+
+	/// Loads a timestamp, as whole seconds and a binary fraction of a second,
+	/// from one little-endian field in the slice.
+	///
+	/// This is the inverse of [`.store_timestamp_le()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `sub_bits`: The number of bits used for the fraction of a second.
+	///
+	/// # Returns
+	///
+	/// The whole seconds, from the high bits of the field, and the fraction of
+	/// a second in units of `2^-sub_bits` seconds, from the low `sub_bits`
+	/// bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.len()` is zero or greater than 128, if `sub_bits`
+	/// is greater than 32 or not less than `self.len()`, or if the seconds
+	/// part of the field is wider than 64 bits and holds a value that does not
+	/// fit in a `u64`.
+	///
+	/// [`.store_timestamp_le()`]: Self::store_timestamp_le
+	pub fn load_timestamp_le(&self, sub_bits: u32) -> (u64, u32) {
+		let len = self.len();
+		super::check::<u128>("load", len);
+		check_timestamp(len, sub_bits);
+		let stamp = self.load_le::<u128>();
+		let secs = stamp >> sub_bits;
+		assert!(
+			secs <= u64::MAX as u128,
+			"Timestamp seconds {} do not fit in a `u64`",
+			secs
+		);
+		let sub = stamp & !(!0u128 << sub_bits);
+		(secs as u64, sub as u32)
+	}
+}
+
+/// Asserts that a timestamp can split a `len`-bit field into seconds and
+/// `sub_bits` fractional bits.
+///
+/// # Panics
+///
+/// This panics if `sub_bits` is greater than 32, or not less than `len`.
+fn check_timestamp(len: usize, sub_bits: u32) {
+	assert!(
+		sub_bits <= 32 && (sub_bits as usize) < len,
+		"Cannot split a {}-bit timestamp at {} subsecond bits",
+		len,
+		sub_bits
+	);
+}
@@ -0,0 +1,147 @@
+/*! Tracing of [`BitField`] accesses.
+
+When reverse-engineering a binary format, or debugging a parser for one, it is
+useful to see every field that the parser reads or writes. With the `trace`
+feature enabled, each call to [`.load_le()`] or [`.store_le()`] on a
+[`BitSlice`] reports a [`FieldEvent`] to a function registered with
+[`set_field_tracer`].
+
+Each event records the position of the accessed field as a bit offset from a
+base address registered with [`set_field_trace_base`]. Register the start of the
+buffer being parsed as the base, and the offsets in the trace will be the bit
+positions of each field within that buffer.
+
+The tracer is a plain function pointer, shared by all threads, and the feature
+adds no code to any [`BitField`] method when it is disabled.
+
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+[`FieldEvent`]: self::FieldEvent
+[`set_field_trace_base`]: self::set_field_trace_base
+[`set_field_tracer`]: self::set_field_tracer
+[`.load_le()`]: crate::field::BitField::load_le
+[`.store_le()`]: crate::field::BitField::store_le
+!*/
+
+#![cfg(feature = "trace")]
+
+use crate::{
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	mem,
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
+};
+
+/// The direction of a traced field access.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FieldAccess {
+	/// The field was read by [`.load_le()`].
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	Load,
+	/// The field was written by [`.store_le()`].
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	Store,
+}
+
+/// A record of a single field access.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FieldEvent {
+	/// Whether the field was read or written.
+	pub access: FieldAccess,
+	/// The distance, in bits, from the trace base to the first bit of the
+	/// field. This counts whole bytes between the base and the field’s first
+	/// memory element, plus the index of the field’s first bit within that
+	/// element.
+	pub offset: isize,
+	/// The width of the field, in bits.
+	pub width: usize,
+	/// The value loaded from, or stored into, the field.
+	pub value: u128,
+}
+
+/// The signature of a function that receives field access events.
+pub type FieldTracer = fn(&FieldEvent);
+
+/// The registered tracer function, or zero if there is none.
+static TRACER: AtomicUsize = AtomicUsize::new(0);
+
+/// The address from which event offsets are measured.
+static BASE: AtomicUsize = AtomicUsize::new(0);
+
+/** Registers a function to receive every traced field access.
+
+# Parameters
+
+- `tracer`: The function to call for each access, or `None` to stop tracing.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::trace::{self, FieldEvent};
+
+fn show(event: &FieldEvent) {
+  println!("{:?}", event);
+}
+
+let data = [0u8; 4];
+trace::set_field_trace_base(data.as_ptr());
+trace::set_field_tracer(Some(show));
+let _ = data.view_bits::<Lsb0>()[4 .. 20].load_le::<u16>();
+trace::set_field_tracer(None);
+```
+**/
+pub fn set_field_tracer(tracer: Option<FieldTracer>) {
+	TRACER.store(tracer.map_or(0, |func| func as usize), Ordering::Release);
+}
+
+/** Sets the address from which the offsets of traced accesses are measured.
+
+# Parameters
+
+- `base`: The start of the buffer being traced.
+**/
+pub fn set_field_trace_base<T>(base: *const T) {
+	BASE.store(base as usize, Ordering::Release);
+}
+
+/// Reports a field access to the registered tracer, if there is one.
+pub(super) fn emit<O, T, M>(
+	access: FieldAccess,
+	bits: &BitSlice<O, T>,
+	value: M,
+) where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	let tracer = TRACER.load(Ordering::Acquire);
+	if tracer == 0 {
+		return;
+	}
+	let tracer = unsafe { mem::transmute::<usize, FieldTracer>(tracer) };
+
+	let bitptr = bits.bitptr();
+	let addr = bitptr.pointer().to_const() as usize;
+	let bytes = addr.wrapping_sub(BASE.load(Ordering::Acquire)) as isize;
+	let offset = bytes
+		.wrapping_mul(8)
+		.wrapping_add(bitptr.head().value() as isize);
+
+	tracer(&FieldEvent {
+		access,
+		offset,
+		width: bits.len(),
+		value: super::resize::<M, u128>(value),
+	});
+}
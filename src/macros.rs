@@ -525,5 +525,79 @@ macro_rules! bitbox {
 	};
 }
 
+/** Checks a register’s bitfield layout at compile time.
+
+Register maps are usually written as a list of `(offset, width)` pairs, and
+mistakes in them do not surface until the wrong bits are read or written. This
+macro takes a register type and a list of named fields, and fails to compile if
+any field is empty, extends past the width of the register, or overlaps another
+field. The field offsets and widths are measured in bits, as used by indexing a
+[`BitSlice`] of the register and calling [`BitField`] methods on the result.
+
+The macro expands to anonymous constants, and produces no items or runtime code.
+It may be used at module scope or inside a function.
+
+# Syntax
+
+```rust,ignore
+bitfield_assert_layout!(RegisterType {
+  name: offset, width;
+  …
+});
+```
+
+`RegisterType` may be any sized type; its width is taken from its size in
+memory. Each `offset` and `width` must be a constant expression of type
+`usize`.
+
+# Errors
+
+A failed check is reported as a type mismatch between arrays of zero and one
+elements. The element type names the violated rule and the offending fields:
+
+- `FieldIsEmpty<name>`: the field has a width of zero.
+- `FieldExceedsRegister<name>`: the field runs past the end of the register.
+- `FieldsOverlap<first, second>`: the two fields share at least one bit.
+
+# Examples
+
+```rust
+use bitvec::bitfield_assert_layout;
+
+bitfield_assert_layout!(u16 {
+  enable: 0, 1;
+  mode: 1, 3;
+  divisor: 8, 8;
+});
+```
+
+The `mode` and `speed` fields below both claim bit 3:
+
+```rust,compile_fail
+use bitvec::bitfield_assert_layout;
+
+bitfield_assert_layout!(u8 {
+  mode: 0, 4;
+  speed: 3, 2;
+});
+```
+
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+**/
+#[macro_export]
+macro_rules! bitfield_assert_layout {
+	($reg:ty { $($name:ident : $off:expr, $width:expr);+ $(;)? }) => {
+		const _: () = {
+			const __REG_BITS: usize =
+				$crate::macros::internal::core::mem::size_of::<$reg>() * 8;
+			$( $crate::__bitfield_assert_field!(
+				__REG_BITS; $name: $off, $width
+			); )+
+			$crate::__bitfield_assert_pairs!($($name: $off, $width);+);
+		};
+	};
+}
+
 #[cfg(test)]
 mod tests;
@@ -243,6 +243,82 @@ macro_rules! __count_elts {
 	}};
 }
 
+/** Checks that a single field fits within its register.
+
+Each rule is a zero-length array annotation, which fails to type-check when the
+rule is violated. The element type of the array is a marker generic over a unit
+struct named for the field, so that the compiler error names it.
+**/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_assert_field {
+	($bits:ident; $name:ident : $off:expr, $width:expr) => {
+		const _: () = {
+			#[allow(dead_code, non_camel_case_types)]
+			struct $name;
+			#[allow(dead_code)]
+			struct FieldIsEmpty<F>(F);
+			#[allow(dead_code)]
+			struct FieldExceedsRegister<F>(F);
+
+			let _: [
+				$crate::macros::internal::core::marker::PhantomData<
+					FieldIsEmpty<$name>,
+				>;
+				0
+			] = [
+				$crate::macros::internal::core::marker::PhantomData;
+				(($width) == 0) as usize
+			];
+			let _: [
+				$crate::macros::internal::core::marker::PhantomData<
+					FieldExceedsRegister<$name>,
+				>;
+				0
+			] = [
+				$crate::macros::internal::core::marker::PhantomData;
+				(($off) + ($width) > $bits) as usize
+			];
+		};
+	};
+}
+
+/** Checks that no two fields in a register overlap.
+
+The first field is tested against each of the rest, then the macro recurses on
+the rest, so that every pair is tested exactly once.
+**/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_assert_pairs {
+	() => {};
+	(
+		$name:ident : $off:expr, $width:expr
+		$(; $rest:ident : $roff:expr, $rwidth:expr)*
+	) => {
+		$( const _: () = {
+			#[allow(dead_code, non_camel_case_types)]
+			struct $name;
+			#[allow(dead_code, non_camel_case_types)]
+			struct $rest;
+			#[allow(dead_code)]
+			struct FieldsOverlap<A, B>(A, B);
+
+			let _: [
+				$crate::macros::internal::core::marker::PhantomData<
+					FieldsOverlap<$name, $rest>,
+				>;
+				0
+			] = [
+				$crate::macros::internal::core::marker::PhantomData;
+				((($off) < ($roff) + ($rwidth)) & (($roff) < ($off) + ($width)))
+					as usize
+			];
+		}; )*
+		$crate::__bitfield_assert_pairs!($($rest: $roff, $rwidth);*);
+	};
+}
+
 /** Constructs a `T: BitStore` element from a byte-chunked sequence of bits.
 
 # Arguments
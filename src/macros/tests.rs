@@ -526,3 +526,21 @@ fn make_elem() {
 		invoke_make_elem!(crate::order::Lsb0, Cell<usize> as usize; 0, 0, 1, 1);
 	assert_eq!(cell.get(), 12);
 }
+
+bitfield_assert_layout!(u32 {
+	low: 0, 8;
+	mid: 8, 16;
+	high: 24, 8;
+});
+
+#[test]
+fn compile_bitfield_assert_layout() {
+	bitfield_assert_layout!([u8; 3] {
+		flag: 0, 1;
+		count: 1, 7;
+		wide: 8, 16
+	});
+
+	//  A lone field is only checked against the register width.
+	bitfield_assert_layout!(u8 { all: 0, 8 });
+}
@@ -18,7 +18,10 @@ values, but are unable to be used in buffer type parameters.
 
 use core::mem;
 
-use funty::IsUnsigned;
+use funty::{
+	IsSigned,
+	IsUnsigned,
+};
 
 use radium::marker::BitOps;
 
@@ -98,6 +101,62 @@ impl BitRegister for u64 {
 
 register!(usize);
 
+/** Description of a signed integer that can be transferred through a
+[`BitField`] region.
+
+Each signed integer is paired with the [`BitMemory`] integer of the same width,
+which carries its two’s-complement bit pattern through the unsigned [`BitField`]
+methods.
+
+This trait cannot be implemented outside this crate.
+
+[`BitField`]: crate::field::BitField
+[`BitMemory`]: self::BitMemory
+**/
+pub trait BitSigned: IsSigned + seal::Sealed {
+	/// The unsigned integer of the same width.
+	type Unsigned: BitMemory;
+
+	/// Interprets the low `width` bits of `raw` as a two’s-complement number,
+	/// and sign-extends it to the full width of `Self`.
+	///
+	/// `width` must be in the range `1 ..= Self::Unsigned::BITS`.
+	#[doc(hidden)]
+	fn sign_extend(raw: Self::Unsigned, width: u8) -> Self;
+
+	/// Reinterprets the two’s-complement bit pattern of `self` as an unsigned
+	/// integer.
+	#[doc(hidden)]
+	fn to_unsigned(self) -> Self::Unsigned;
+}
+
+macro_rules! signed {
+	($($i:ident => $u:ident),+ $(,)?) => { $(
+		impl BitSigned for $i {
+			type Unsigned = $u;
+
+			fn sign_extend(raw: $u, width: u8) -> Self {
+				let shamt = <$u as BitMemory>::BITS - width;
+				((raw << shamt) as $i) >> shamt
+			}
+
+			fn to_unsigned(self) -> $u {
+				self as $u
+			}
+		}
+		impl seal::Sealed for $i {}
+	)+ };
+}
+
+signed!(
+	i8 => u8,
+	i16 => u16,
+	i32 => u32,
+	i64 => u64,
+	i128 => u128,
+	isize => usize,
+);
+
 /** Computes the number of elements required to store some number of bits.
 
 # Parameters
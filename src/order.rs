@@ -33,6 +33,12 @@ use crate::{
 	mem::BitRegister,
 };
 
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
 /** An ordering over a register.
 
 # Usage
@@ -64,14 +70,16 @@ for interaction with memory.
 
 The [`verify`] and [`verify_for_type`] functions are available for your test
 suites. They ensure that a `BitOrder` implementation satisfies the requirements
-when invoked for a given register type.
+when invoked for a given register type. The [`try_verify`] and
+[`try_verify_for_type`] functions perform the same checks, and return the first
+violation as an [`OrderError`] rather than panicking.
 
 # Examples
 
 Implementations are not required to remain contiguous over a register. This
-example swizzles the high and low halves of each byte, but any translation is
-valid as long as it satisfies the strict one-to-one requirement of
-index-to-position.
+example swizzles the high and low halves of each byte, as the provided [`HiLo`]
+does, but any translation is valid as long as it satisfies the strict one-to-one
+requirement of index-to-position.
 **/
 ///
 /// ```rust
@@ -99,7 +107,11 @@ index-to-position.
 /// [`BitIdx`]: crate::index::BitIdx
 /// [`BitOrder::at`]: Self::at
 /// [`BitPos`]: crate::index::BitPos
+/// [`HiLo`]: crate::order::HiLo
 /// [`bitvec`]: crate
+/// [`OrderError`]: crate::order::OrderError
+/// [`try_verify`]: crate::order::try_verify
+/// [`try_verify_for_type`]: crate::order::try_verify_for_type
 /// [`verify`]: crate::order::verify
 /// [`verify_for_type`]: crate::order::verify_for_type
 pub unsafe trait BitOrder: 'static {
@@ -346,6 +358,60 @@ unsafe impl BitOrder for Msb0 {
 	}
 }
 
+/** Traverses each byte of a register from its high nibble to its low nibble.
+
+Within each nibble, bits are traversed from least significant to most
+significant, as in [`Lsb0`]. Index `0` of each byte is bit `4`, index `3` is bit
+`7`, index `4` is bit `0`, and index `7` is bit `3`. This is `Lsb0` with the two
+nibbles of every byte exchanged, and matches packed 4-bit formats that store the
+first of the two values in each byte in its high nibble.
+
+[`BitField`] is implemented for `BitSlice<HiLo, _>`. Its fields are assembled
+from the bits of each memory element in index order, so that loading index
+range `0 .. 4` of a byte produces its high nibble, and `4 .. 8` produces its low
+nibble.
+
+[`BitField`]: crate::field::BitField
+[`Lsb0`]: crate::order::Lsb0
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HiLo;
+
+unsafe impl BitOrder for HiLo {
+	fn at<R>(index: BitIdx<R>) -> BitPos<R>
+	where R: BitRegister {
+		unsafe { BitPos::new_unchecked(index.value() ^ 4) }
+	}
+
+	fn select<R>(index: BitIdx<R>) -> BitSel<R>
+	where R: BitRegister {
+		unsafe { BitSel::new_unchecked(R::ONE << (index.value() ^ 4)) }
+	}
+
+	fn mask<R>(
+		from: impl Into<Option<BitIdx<R>>>,
+		upto: impl Into<Option<BitTail<R>>>,
+	) -> BitMask<R>
+	where
+		R: BitRegister,
+	{
+		BitMask::new(swap_nibbles(Lsb0::mask(from, upto).value()))
+	}
+}
+
+/// Exchanges the high and low nibbles of each byte in a register.
+///
+/// This translates between [`HiLo`] and [`Lsb0`] bit positions.
+///
+/// [`HiLo`]: self::HiLo
+/// [`Lsb0`]: self::Lsb0
+pub(crate) fn swap_nibbles<R>(value: R) -> R
+where R: BitRegister {
+	//  `0x0F` repeated in each byte.
+	let low = R::ALL / (R::ONE << 4u8 | R::ONE);
+	(value & low) << 4u8 | (value >> 4u8) & low
+}
+
 /** A default bit ordering.
 
 Typically, your platform’s C compiler uses least-significant-bit-first ordering
@@ -441,136 +507,290 @@ where
 	R: BitRegister,
 {
 	use core::any::type_name;
-	let mut accum = BitMask::<R>::ZERO;
 
 	let oname = type_name::<O>();
-	let mname = type_name::<R>();
-
-	for n in 0 .. R::BITS {
-		//  Wrap the counter as an index.
-		let idx = unsafe { BitIdx::<R>::new_unchecked(n) };
 
-		//  Compute the bit position for the index.
-		let pos = O::at::<R>(idx);
-		if verbose {
-			#[cfg(feature = "std")]
+	if verbose {
+		#[cfg(feature = "std")]
+		for idx in BitIdx::<R>::range_all() {
 			println!(
-				"`<{} as BitOrder>::at::<{}>({})` produces {}",
-				oname,
-				mname,
-				n,
-				pos.value(),
+				"`<{o} as BitOrder>::at::<{m}>({n})` produces {p}, and \
+				 `<{o} as BitOrder>::select::<{m}>({n})` produces {s:b}",
+				o = oname,
+				m = type_name::<R>(),
+				n = idx,
+				p = O::at::<R>(idx).value(),
+				s = O::select::<R>(idx),
 			);
 		}
+	}
 
-		//  If the computed position exceeds the valid range, fail.
-		assert!(
-			pos.value() < R::BITS,
-			"Error when verifying the implementation of `BitOrder` for `{}`: \
-			 Index {} produces a bit position ({}) that exceeds the type width \
-			 {}",
-			oname,
-			n,
-			pos.value(),
-			R::BITS,
+	if let Err(err) = try_verify_for_type::<O, R>() {
+		panic!(
+			"Error when verifying the implementation of `BitOrder` for `{}`: {}",
+			oname, err,
 		);
+	}
+}
 
-		//  Check `O`’s implementation of `select`
-		let sel = O::select::<R>(idx);
-		if verbose {
-			#[cfg(feature = "std")]
-			println!(
-				"`<{} as BitOrder>::select::<{}>({})` produces {:b}",
-				oname, mname, n, sel,
-			);
-		}
+/** Checks a [`BitOrder`] implementation’s adherence to the stated rules, and
+reports the first violation that it finds.
 
-		//  If the selector bit is not one-hot, fail.
-		assert_eq!(
-			sel.value().count_ones(),
-			1,
-			"Error when verifying the implementation of `BitOrder` for `{}`: \
-			 Index {} produces a bit selector ({:b}) that is not a one-hot mask",
-			oname,
-			n,
-			sel,
-		);
+This performs the same checks as [`verify`], without panicking. It can be used
+outside of a test suite, for instance to validate an ordering while it is being
+developed, or to reject a misbehaving ordering at run time.
 
-		//  Check that the selection computed from the index matches the
-		//  selection computed from the position.
-		let shl = pos.select();
-		//  If `O::select(idx)` does not produce `1 << pos`, fail.
-		assert_eq!(
-			sel,
-			shl,
-			"Error when verifying the implementation of `BitOrder` for `{}`: \
-			 Index {} produces a bit selector ({:b}) that is not equal to `1 \
-			 << {}` ({:b})",
-			oname,
-			n,
-			sel,
-			pos.value(),
-			shl,
-		);
+# Type Parameters
 
-		//  Check that the produced selector bit has not already been added to
-		//  the accumulator.
-		assert!(
-			!accum.test(sel),
-			"Error when verifying the implementation of `BitOrder` for `{}`: \
-			 Index {} produces a bit position ({}) that has already been \
-			 produced by a prior index",
-			oname,
-			n,
-			pos.value(),
-		);
-		accum.insert(sel);
-		if verbose {
-			#[cfg(feature = "std")]
-			println!(
-				"`<{} as BitOrder>::at::<{}>({})` accumulates  {:b}",
-				oname, mname, n, accum,
-			);
+- `O`: The [`BitOrder`] implementation to check.
+
+# Returns
+
+`Ok(())` if `O` behaves correctly for every [`BitRegister`] type, or the first
+violation found, checking the registers in order of increasing width.
+
+# Examples
+
+```rust
+use bitvec::{
+  index::{BitIdx, BitPos},
+  mem::BitRegister,
+  order::{self, BitOrder, Lsb0, OrderErrorKind},
+};
+
+assert!(order::try_verify::<Lsb0>().is_ok());
+
+//  Maps indices 0 and 1 to the same position.
+struct Broken;
+unsafe impl BitOrder for Broken {
+  fn at<R: BitRegister>(idx: BitIdx<R>) -> BitPos<R> {
+    BitPos::new(idx.value() & !1).unwrap()
+  }
+}
+
+let err = order::try_verify::<Broken>().unwrap_err();
+assert_eq!(err.width(), 8);
+assert_eq!(err.kind(), OrderErrorKind::DuplicatePosition {
+  index: 1,
+  position: 0,
+  prior: 0,
+});
+```
+
+[`BitOrder`]: crate::order::BitOrder
+[`BitRegister`]: crate::mem::BitRegister
+[`verify`]: crate::order::verify
+**/
+pub fn try_verify<O>() -> Result<(), OrderError>
+where O: BitOrder {
+	try_verify_for_type::<O, u8>()?;
+	try_verify_for_type::<O, u16>()?;
+	try_verify_for_type::<O, u32>()?;
+
+	#[cfg(target_pointer_width = "64")]
+	try_verify_for_type::<O, u64>()?;
+
+	try_verify_for_type::<O, usize>()
+}
+
+/** Checks a [`BitOrder`] implementation’s adherence to the stated rules for
+one register type, and reports the first violation that it finds.
+
+This performs the same checks as [`verify_for_type`], without panicking.
+
+# Type Parameters
+
+- `O`: The [`BitOrder`] implementation to check.
+- `R`: The [`BitRegister`] type for which to check `O`.
+
+# Returns
+
+`Ok(())` if, for every index in `R`:
+
+- `O::at` produces a position inside `R`,
+- `O::select` produces `1 << O::at`,
+- no two indices produce the same position,
+
+and `O::mask` selects exactly the positions of each range of indices.
+Otherwise, the first index or range that breaks one of these rules is returned
+as an error.
+
+[`BitOrder`]: crate::order::BitOrder
+[`BitRegister`]: crate::mem::BitRegister
+[`verify_for_type`]: crate::order::verify_for_type
+**/
+pub fn try_verify_for_type<O, R>() -> Result<(), OrderError>
+where
+	O: BitOrder,
+	R: BitRegister,
+{
+	let fail = |kind| Err(OrderError { width: R::BITS, kind });
+	let mut accum = BitMask::<R>::ZERO;
+
+	for idx in BitIdx::<R>::range_all() {
+		let (index, pos) = (idx.value(), O::at::<R>(idx).value());
+
+		if pos >= R::BITS {
+			return fail(OrderErrorKind::PositionOutOfBounds {
+				index,
+				position: pos,
+			});
 		}
+		//  The position is in bounds, and so is a valid `BitPos`.
+		let pos = unsafe { BitPos::<R>::new_unchecked(pos) };
+
+		if O::select::<R>(idx) != pos.select() {
+			return fail(OrderErrorKind::SelectMismatch {
+				index,
+				position: pos.value(),
+			});
+		}
+
+		if accum.test(pos.select()) {
+			let prior = BitIdx::<R>::range_all()
+				.find(|&prior| O::at::<R>(prior) == pos)
+				.map(BitIdx::value)
+				.unwrap_or(index);
+			return fail(OrderErrorKind::DuplicatePosition {
+				index,
+				position: pos.value(),
+				prior,
+			});
+		}
+		accum.insert(pos.select());
 	}
 
-	//  Check that all indices produced all positions.
-	assert_eq!(
-		accum,
-		BitMask::ALL,
-		"Error when verifying the implementation of `BitOrder` for `{}`: The \
-		 bit positions marked with a `0` here were never produced from an \
-		 index, despite all possible indices being passed in for translation: \
-		 {:b}",
-		oname,
-		accum,
-	);
-
-	//  Check that `O::mask` is correct for all range combinations.
+	//  Every index produced a distinct position in the register, so all
+	//  positions have been produced.
+	debug_assert_eq!(accum, BitMask::ALL);
+
 	for from in BitIdx::<R>::range_all() {
 		for upto in BitTail::<R>::range_from(from) {
-			let mask = O::mask(from, upto);
 			let check = BitIdx::<R>::range(from, upto)
 				.map(O::at::<R>)
 				.map(BitPos::<R>::select)
 				.sum::<BitMask<R>>();
-			assert_eq!(
-				mask,
-				check,
-				"Error when verifying the implementation of `BitOrder` for \
-				 `{o}`: `{o}::mask::<{m}>({f}, {u})` produced {bad:b}, but \
-				 expected {good:b}",
-				o = oname,
-				m = mname,
-				f = from,
-				u = upto,
-				bad = mask,
-				good = check,
-			);
+			if O::mask(from, upto) != check {
+				return fail(OrderErrorKind::MaskMismatch {
+					from: from.value(),
+					upto: upto.value(),
+				});
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/** A violation of the [`BitOrder`] rules, found by [`try_verify`] or
+[`try_verify_for_type`].
+
+[`BitOrder`]: crate::order::BitOrder
+[`try_verify`]: crate::order::try_verify
+[`try_verify_for_type`]: crate::order::try_verify_for_type
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OrderError {
+	/// The width of the register in which the violation was found.
+	width: u8,
+	/// The rule that was broken.
+	kind: OrderErrorKind,
+}
+
+impl OrderError {
+	/// Gets the width of the register in which the violation was found.
+	pub fn width(&self) -> u8 {
+		self.width
+	}
+
+	/// Gets the rule that was broken, and where.
+	pub fn kind(&self) -> OrderErrorKind {
+		self.kind
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for OrderError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "In a {}-bit register, ", self.width)?;
+		match self.kind {
+			OrderErrorKind::PositionOutOfBounds { index, position } => write!(
+				fmt,
+				"index {} produces a bit position ({}) that exceeds the type \
+				 width",
+				index, position,
+			),
+			OrderErrorKind::SelectMismatch { index, position } => write!(
+				fmt,
+				"index {} produces a bit selector that is not equal to `1 << \
+				 {}`",
+				index, position,
+			),
+			OrderErrorKind::DuplicatePosition {
+				index,
+				position,
+				prior,
+			} => write!(
+				fmt,
+				"index {} produces a bit position ({}) that has already been \
+				 produced by index {}",
+				index, position, prior,
+			),
+			OrderErrorKind::MaskMismatch { from, upto } => write!(
+				fmt,
+				"the mask of indices {} .. {} does not select the positions of \
+				 those indices",
+				from, upto,
+			),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for OrderError {
+}
+
+/** The rules of [`BitOrder`] that an implementation can break.
+
+Indices, positions, and range bounds are all counted within one register.
+
+[`BitOrder`]: crate::order::BitOrder
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OrderErrorKind {
+	/// `O::at(index)` produced a position at or beyond the register width.
+	PositionOutOfBounds {
+		/// The index that was translated.
+		index: u8,
+		/// The position that it produced.
+		position: u8,
+	},
+	/// `O::select(index)` did not produce `1 << O::at(index)`.
+	SelectMismatch {
+		/// The index that was translated.
+		index: u8,
+		/// The position that `O::at` produced for it.
+		position: u8,
+	},
+	/// `O::at(index)` produced the same position as an earlier index.
+	DuplicatePosition {
+		/// The index that was translated.
+		index: u8,
+		/// The position that it produced.
+		position: u8,
+		/// The earlier index that produced the same position.
+		prior: u8,
+	},
+	/// `O::mask(from, upto)` did not select exactly the positions of the
+	/// indices in `from .. upto`.
+	MaskMismatch {
+		/// The start of the index range.
+		from: u8,
+		/// The end of the index range.
+		upto: u8,
+	},
+}
+
 #[cfg(all(test, not(miri)))]
 mod tests {
 	use super::*;
@@ -580,6 +800,7 @@ mod tests {
 	fn verify_impls() {
 		verify::<Lsb0>(cfg!(feature = "testing"));
 		verify::<Msb0>(cfg!(feature = "testing"));
+		verify::<HiLo>(cfg!(feature = "testing"));
 	}
 
 	#[test]
@@ -597,4 +818,68 @@ mod tests {
 		}
 		verify::<AmericanDateStyle>(cfg!(feature = "testing"));
 	}
+
+	#[test]
+	fn try_verify_reports() {
+		assert!(try_verify::<Lsb0>().is_ok());
+		assert!(try_verify::<Msb0>().is_ok());
+		assert!(try_verify::<HiLo>().is_ok());
+
+		struct BadSelect;
+		unsafe impl BitOrder for BadSelect {
+			fn at<R>(idx: BitIdx<R>) -> BitPos<R>
+			where R: BitRegister {
+				Lsb0::at::<R>(idx)
+			}
+
+			fn select<R>(idx: BitIdx<R>) -> BitSel<R>
+			where R: BitRegister {
+				Msb0::select::<R>(idx)
+			}
+		}
+		assert_eq!(
+			try_verify_for_type::<BadSelect, u16>().unwrap_err().kind(),
+			OrderErrorKind::SelectMismatch {
+				index: 0,
+				position: 0,
+			},
+		);
+
+		struct BadMask;
+		unsafe impl BitOrder for BadMask {
+			fn at<R>(idx: BitIdx<R>) -> BitPos<R>
+			where R: BitRegister {
+				Msb0::at::<R>(idx)
+			}
+
+			fn mask<R>(
+				from: impl Into<Option<BitIdx<R>>>,
+				upto: impl Into<Option<BitTail<R>>>,
+			) -> BitMask<R>
+			where
+				R: BitRegister,
+			{
+				Lsb0::mask(from, upto)
+			}
+		}
+		let err = try_verify_for_type::<BadMask, u32>().unwrap_err();
+		assert_eq!(err.width(), 32);
+		assert_eq!(err.kind(), OrderErrorKind::MaskMismatch {
+			from: 0,
+			upto: 1,
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "has already been produced by index 0")]
+	fn verify_panics() {
+		struct Paired;
+		unsafe impl BitOrder for Paired {
+			fn at<R>(idx: BitIdx<R>) -> BitPos<R>
+			where R: BitRegister {
+				BitPos::new(idx.value() & !1).unwrap()
+			}
+		}
+		verify::<Paired>(false);
+	}
 }
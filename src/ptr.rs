@@ -1206,6 +1206,26 @@ where
 		(&*base.offset(elt)).write_bit::<O>(bit, value);
 	}
 
+	/// Writes a bit some distance away from `self`, and returns its previous
+	/// value.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The `self` pointer must be describing a write-capable region.
+	/// - `index`: The bit distance away from `self` at which to write,
+	///   according to the `O` ordering.
+	/// - `value`: The bit value to insert at `index`.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index` immediately before it was written. The
+	/// read and the write are a single read-modify-write of the element.
+	pub(crate) unsafe fn fetch_write(&self, index: usize, value: bool) -> bool {
+		let (elt, bit) = self.head().offset(index as isize);
+		let base = self.pointer().to_access();
+		(&*base.offset(elt)).fetch_write_bit::<O>(bit, value)
+	}
+
 	//  Comparators
 
 	/// Computes the distance, in elements and bits, between two bit-pointers.
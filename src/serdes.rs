@@ -15,6 +15,14 @@ it cannot deserialize into any of the owning structures.
 [`BitBox`] and [`BitVec`] implement [`Serialize`] through [`BitSlice`], and can
 deserialize the [`BitSlice`] format into themselves.
 
+The [`BitSlice`] format is a struct named `BitSeq`, with the fields `head` (the
+index of the first live bit in the first element, as a `u8`), `bits` (the
+length, as a `u64`), and `data` (the sequence of memory elements). The elements
+are serialized as integers, so the format does not depend on the byte order of
+the machine that produced it. Bits in `data` that are not part of the slice are
+serialized as zero, and deserialization fails if any of them is set, or if the
+`bits` counter runs past the end of `data`.
+
 If you require de/serialization compatibility between [`BitArray`] and the other
 structures, please file an issue.
 
@@ -33,7 +41,11 @@ structures, please file an issue.
 use crate::{
 	array::BitArray,
 	domain::Domain,
-	index::BitIdxErr,
+	index::{
+		BitIdx,
+		BitIdxErr,
+		BitMask,
+	},
 	mem::BitMemory,
 	order::BitOrder,
 	ptr::BitPtr,
@@ -43,7 +55,6 @@ use crate::{
 };
 
 use core::{
-	cmp,
 	convert::TryInto,
 	fmt::{
 		self,
@@ -92,12 +103,53 @@ where
 
 		state.serialize_field("head", &head.value())?;
 		state.serialize_field("bits", &(self.len() as u64))?;
-		state.serialize_field("data", &self.domain())?;
+		state.serialize_field("data", &MaskedDomain { bits: self })?;
 
 		state.end()
 	}
 }
 
+/** Serializes the memory elements underneath a [`BitSlice`], with every bit
+that is not part of the slice cleared to zero.
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+struct MaskedDomain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The slice whose memory is serialized.
+	bits: &'a BitSlice<O, T>,
+}
+
+impl<O, T> Serialize for MaskedDomain<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	T::Mem: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let bitptr = self.bits.bitptr();
+		let (head, tail) = (bitptr.head(), bitptr.tail());
+		let domain = self.bits.domain();
+		let last = domain.len().saturating_sub(1);
+
+		let mut state = serializer.serialize_seq(Some(domain.len()))?;
+		for (idx, elem) in domain.enumerate() {
+			let mask = match (idx == 0, idx == last) {
+				(true, true) => O::mask(head, tail),
+				(true, false) => O::mask(head, None),
+				(false, true) => O::mask(None, tail),
+				(false, false) => BitMask::ALL,
+			};
+			state.serialize_element(&(elem & mask.value()))?;
+		}
+		state.end()
+	}
+}
+
 impl<T> Serialize for Domain<'_, T>
 where
 	T: BitStore,
@@ -202,13 +254,14 @@ where
 	/// - `&self`: A visitor, only needed for access to an error message.
 	/// - `head`: The deserialized head-bit index.
 	/// - `bits`: The deserialized length counter.
-	/// - `data`: A vector of memory containing the bitslice. Its dest
+	/// - `data`: A vector of memory containing the bitslice.
 	///
 	/// # Returns
 	///
 	/// The result of assembling the deserialized components into a [`BitVec`].
-	/// This can fail if the `head` is invalid, or if the deserialized data
-	/// cannot be encoded into a `BitPtr`.
+	/// This can fail if the `head` is invalid, if `bits` runs past the end of
+	/// `data`, if any bit of `data` outside the `bits` after `head` is set, or
+	/// if the deserialized data cannot be encoded into a `BitPtr`.
 	///
 	/// [`BitVec`]: crate::vec::BitVec
 	fn assemble<E>(
@@ -220,32 +273,49 @@ where
 	where
 		E: de::Error,
 	{
-		//  Disable the destructor on the deserialized buffer
-		let data = ManuallyDrop::new(data);
-		//  Assemble a region pointer
-		BitPtr::new(
-			data.as_ptr() as *mut T,
-			//  Attempt to read the `head` index as a `BitIdx` bounded by the
-			//  destination type.
+		//  Attempt to read the `head` index as a `BitIdx` bounded by the
+		//  destination type.
+		let head: BitIdx<T::Mem> =
 			head.try_into().map_err(|val: BitIdxErr<_>| {
 				de::Error::invalid_value(
 					Unexpected::Unsigned(val.value() as u64),
 					&"a head-bit index less than the deserialized element \
 					  type’s bit width",
 				)
-			})?,
-			//  Ensure that the `bits` counter is not lying about the data size.
-			cmp::min(bits, data.len().saturating_mul(T::Mem::BITS as usize)),
-		)
-		//  Fail if the source cannot be encoded into a bit pointer.
-		.ok_or_else(|| {
-			de::Error::invalid_value(
-				Unexpected::Other("invalid bit-region source data"),
-				self,
-			)
-		})
-		.map(BitPtr::to_bitslice_ptr_mut)
-		.map(|bp| unsafe { BitVec::from_raw_parts(bp, data.capacity()) })
+			})?;
+		let start = head.value() as usize;
+
+		//  Ensure that the `bits` counter is not lying about the data size.
+		let capacity = data.len().saturating_mul(T::Mem::BITS as usize);
+		if bits > capacity.saturating_sub(start) {
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(bits as u64),
+				&"a bit length that fits in the deserialized data",
+			));
+		}
+
+		//  Ensure that every bit outside the bit-slice is cleared.
+		let all = data.as_slice().view_bits::<O>();
+		if all[.. start].any() || all[start + bits ..].any() {
+			return Err(de::Error::invalid_value(
+				Unexpected::Other("a set bit outside the bit-slice"),
+				&"data whose bits outside the bit-slice are all zero",
+			));
+		}
+
+		//  Disable the destructor on the deserialized buffer
+		let data = ManuallyDrop::new(data);
+		//  Assemble a region pointer
+		BitPtr::new(data.as_ptr() as *mut T, head, bits)
+			//  Fail if the source cannot be encoded into a bit pointer.
+			.ok_or_else(|| {
+				de::Error::invalid_value(
+					Unexpected::Other("invalid bit-region source data"),
+					self,
+				)
+			})
+			.map(BitPtr::to_bitslice_ptr_mut)
+			.map(|bp| unsafe { BitVec::from_raw_parts(bp, data.capacity()) })
 	}
 }
 
@@ -409,7 +479,7 @@ mod tests {
 	fn small() {
 		let bits = 0b1111_1000u8.view_bits::<Msb0>();
 		let bits = &bits[1 .. 5];
-		assert_ser_tokens(&bits, bvtok![s 1, 1, 4, U8, 0b1111_1000]);
+		assert_ser_tokens(&bits, bvtok![s 1, 1, 4, U8, 0b0111_1000]);
 
 		let bits = 0b00001111_11111111u16.view_bits::<Lsb0>();
 		let bits = &bits[.. 12];
@@ -423,8 +493,15 @@ mod tests {
 	#[test]
 	fn wide() {
 		let src: &[u8] = &[0, !0];
-		let bs = src.view_bits::<LocalBits>();
-		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
+		let bs = src.view_bits::<Lsb0>();
+		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, 0x7F]);
+
+		let src: &[u16] = &[!0, !0, !0];
+		let bs = src.view_bits::<Msb0>();
+		assert_ser_tokens(
+			&(&bs[4 .. 40]),
+			bvtok![s 3, 4, 36, U16, 0x0F_FF, !0, 0xFF_00],
+		);
 	}
 
 	#[test]
@@ -433,10 +510,66 @@ mod tests {
 		let bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0];
 		let bb = bv.clone().into_boxed_bitslice();
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1000]);
-		//  test that the bits outside the bits domain don't matter in deser
-		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1001]);
-		assert_de_tokens(&bb, bvtok![d 1, 0, 6, U8, 0b0110_1010]);
-		assert_de_tokens(&bb, bvtok![d 1, 0, 6, U8, 0b0110_1011]);
+		assert_de_tokens(&bb, bvtok![d 1, 0, 6, U8, 0b0110_1000]);
+
+		let bv = bitvec![Msb0, u8; 1, 0, 1];
+		assert_de_tokens(&bv, bvtok![d 1, 2, 3, U8, 0b0010_1000]);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn deser_dead_bits() {
+		for &elem in &[0b0110_1001u8, 0b0110_1010, 0b0110_1011] {
+			assert_de_tokens_error::<BitVec<Msb0, u8>>(
+				bvtok![d 1, 0, 6, U8, elem],
+				"invalid value: a set bit outside the bit-slice, expected data \
+				 whose bits outside the bit-slice are all zero",
+			);
+		}
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![d 1, 2, 3, U8, 0b1010_1000],
+			"invalid value: a set bit outside the bit-slice, expected data \
+			 whose bits outside the bit-slice are all zero",
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn deser_overlong() {
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![d 1, 0, 9, U8, 0],
+			"invalid value: integer `9`, expected a bit length that fits in \
+			 the deserialized data",
+		);
+		assert_de_tokens_error::<BitVec<Msb0, u16>>(
+			bvtok![d 2, 4, 29, U16, 0, 0],
+			"invalid value: integer `29`, expected a bit length that fits in \
+			 the deserialized data",
+		);
+		assert_de_tokens::<BitVec<Msb0, u16>>(
+			&BitVec::repeat(false, 28),
+			bvtok![d 2, 4, 28, U16, 0, 0],
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn round_trip() {
+		let mut bv = BitVec::<Lsb0, u16>::repeat(true, 40);
+		bv.set(3, false);
+		bv.set(35, false);
+		let bits = &bv[5 .. 37];
+		assert_ser_tokens(
+			&bits,
+			bvtok![s 3, 5, 32, U16, 0xFF_E0, !0, 0b1_0111],
+		);
+
+		let mut expected = BitVec::<Lsb0, u16>::repeat(true, 32);
+		expected.set(30, false);
+		assert_de_tokens(
+			&expected,
+			bvtok![d 3, 5, 32, U16, 0xFF_E0, !0, 0b1_0111],
+		);
 	}
 
 	#[test]
@@ -516,7 +649,7 @@ mod tests {
 			Token::U8(0),
 			Token::U64(2),
 			Token::Seq { len: Some(1) },
-			Token::U8(66),
+			Token::U8(64),
 			Token::SeqEnd,
 			Token::SeqEnd,
 		]);
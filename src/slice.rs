@@ -53,6 +53,7 @@ use crate::{
 	index::{
 		BitIdx,
 		BitMask,
+		BitTail,
 	},
 	mem::{
 		BitMemory,
@@ -69,8 +70,13 @@ use crate::{
 
 use core::{
 	any::TypeId,
+	cmp,
 	marker::PhantomData,
-	ops::RangeBounds,
+	ops::{
+		Bound,
+		Range,
+		RangeBounds,
+	},
 	ptr,
 	slice,
 };
@@ -803,6 +809,93 @@ where
 		}
 	}
 
+	/// Sets a bit at an index to `1`, through a shared reference, and returns
+	/// its previous value.
+	///
+	/// This allows several handles, including handles on several threads when
+	/// `T` is an [atomic], to cooperatively mark bits in a shared bitmap
+	/// without any other lock. Exactly one of any number of concurrent calls
+	/// on the same bit observes it as `0`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index at which to write. It must be in the range `0
+	///   .. self.len()`.
+	///
+	/// # Returns
+	///
+	/// The value of the bit immediately before it was set.
+	///
+	/// # Effects
+	///
+	/// The bit is set with a single read-modify-write of its memory element,
+	/// which is a `fetch_or` instruction when `T` is an atomic. This uses
+	/// [`Ordering::Relaxed`], as all of `bitvec`’s writes through shared
+	/// references do: the bit itself is updated atomically, but the write does
+	/// not order any other memory accesses. Use a [fence] or another
+	/// synchronization primitive if the bit is used to publish other data.
+	///
+	/// Other bits in the same element are never changed. Other handles may
+	/// read or write them, and the bit at `index`, at the same time.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than [`self.len()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::AtomicU16;
+	///
+	/// let elem = AtomicU16::new(0);
+	/// let bits = elem.view_bits::<Lsb0>();
+	///
+	/// assert!(!bits.fetch_set(3));
+	/// assert!(bits.fetch_set(3));
+	/// assert!(bits.fetch_clear(3));
+	/// assert!(!bits.fetch_clear(3));
+	/// ```
+	///
+	/// [atomic]: core::sync::atomic
+	/// [fence]: core::sync::atomic::fence
+	/// [`Ordering::Relaxed`]: core::sync::atomic::Ordering::Relaxed
+	/// [`self.len()`]: Self::len
+	pub fn fetch_set(&self, index: usize) -> bool
+	where T: radium::Radium {
+		self.assert_in_bounds(index);
+		unsafe { self.bitptr().fetch_write(index, true) }
+	}
+
+	/// Clears a bit at an index to `0`, through a shared reference, and
+	/// returns its previous value.
+	///
+	/// This is the counterpart of [`.fetch_set()`], and uses a `fetch_and`
+	/// instruction with the same memory ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index at which to write. It must be in the range `0
+	///   .. self.len()`.
+	///
+	/// # Returns
+	///
+	/// The value of the bit immediately before it was cleared.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	/// [`.fetch_set()`]: Self::fetch_set
+	pub fn fetch_clear(&self, index: usize) -> bool
+	where T: radium::Radium {
+		self.assert_in_bounds(index);
+		unsafe { self.bitptr().fetch_write(index, false) }
+	}
+
 	/// Tests if *any* bit in the slice is set (logical `∨`).
 	///
 	/// # Truth Table
@@ -1048,11 +1141,8 @@ where
 					(O::mask(head, None) & elem.load_value())
 						.value()
 						.count_ones() as usize
-				}) + body
-					.iter()
-					.map(BitStore::load_value)
-					.map(|e| e.count_ones() as usize)
-					.sum::<usize>() + tail.map_or(0, |(elem, tail)| {
+				}) + popcount::count_ones(body)
+					+ tail.map_or(0, |(elem, tail)| {
 					(O::mask(None, tail) & elem.load_value())
 						.value()
 						.count_ones() as usize
@@ -1106,6 +1196,155 @@ where
 		}
 	}
 
+	/// Counts the number of bits set to `1` in a range of the slice.
+	///
+	/// This is equivalent to `self[range].count_ones()`, but does not
+	/// construct the subslice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of indices in `self` to count.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `self[range]` that are set to `1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is malformed or extends past the end of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 1, 0, 0, 1, 1];
+	/// assert_eq!(bits.count_ones_in(1 .. 4), 2);
+	/// assert_eq!(bits.count_ones_in(.. 0), 0);
+	/// assert_eq!(bits.count_ones_in(..), bits.count_ones());
+	/// ```
+	pub fn count_ones_in<R>(&self, range: R) -> usize
+	where R: RangeBounds<usize> {
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		unsafe { self.count_ones_unchecked(range) }
+	}
+
+	/// Counts the number of bits cleared to `0` in a range of the slice.
+	///
+	/// This is equivalent to `self[range].count_zeros()`, but does not
+	/// construct the subslice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of indices in `self` to count.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `self[range]` that are cleared to `0`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is malformed or extends past the end of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 1, 0, 0, 1, 1];
+	/// assert_eq!(bits.count_zeros_in(1 .. 6), 3);
+	/// assert_eq!(bits.count_zeros_in(6 ..), 0);
+	/// ```
+	pub fn count_zeros_in<R>(&self, range: R) -> usize
+	where R: RangeBounds<usize> {
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		range.len() - unsafe { self.count_ones_unchecked(range) }
+	}
+
+	/// Computes the fraction of bits in the slice that are set to `1`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// [`.count_ones()`] divided by `self.len()`, from `0.0` for a slice with
+	/// no bits set to `1.0` for a slice with every bit set. An empty slice has
+	/// no fraction, and returns `NaN`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 1, 0, 1, 0, 0, 0].ones_fraction(), 0.375);
+	/// assert!(BitSlice::<Lsb0, u8>::empty().ones_fraction().is_nan());
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	pub fn ones_fraction(&self) -> f64 {
+		self.count_ones() as f64 / self.len() as f64
+	}
+
+	/// Estimates the Shannon entropy of one bit of the slice, treating the
+	/// bits as independent samples of one random variable.
+	///
+	/// This is a quick sanity check for data that should be indistinguishable
+	/// from noise, such as encrypted or compressed buffers. It only measures
+	/// the balance of ones and zeros, and cannot detect any pattern in their
+	/// arrangement.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// With `p` as the [`.ones_fraction()`] of the slice, this is
+	/// `-p log2(p) - (1 - p) log2(1 - p)`, in bits. It is `1.0` when exactly
+	/// half of the bits are set, and `0.0` when no bits or every bit is set.
+	/// An empty slice carries no information, and also returns `0.0`.
+	///
+	/// # Availability
+	///
+	/// The logarithm is provided by the standard library when the `std`
+	/// feature is enabled, and by the `libm` crate when only the `libm`
+	/// feature is enabled. This method does not exist without one of them.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 1, 0].shannon_entropy_bit(), 1.0);
+	/// assert_eq!(bits![1; 10].shannon_entropy_bit(), 0.0);
+	///
+	/// let h = bits![0, 0, 0, 1].shannon_entropy_bit();
+	/// assert!((h - 0.811_278).abs() < 1e-6);
+	/// ```
+	///
+	/// [`.ones_fraction()`]: Self::ones_fraction
+	#[cfg(any(feature = "std", feature = "libm"))]
+	pub fn shannon_entropy_bit(&self) -> f64 {
+		#[cfg(feature = "std")]
+		let log2 = f64::log2;
+		#[cfg(not(feature = "std"))]
+		let log2 = libm::log2;
+
+		let (ones, len) = (self.count_ones(), self.len());
+		if ones == 0 || ones == len {
+			return 0.0;
+		}
+		let p = ones as f64 / len as f64;
+		let q = 1.0 - p;
+		-p * log2(p) - q * log2(q)
+	}
+
 	/// Enumerates all bits in a `BitSlice` that are set to `1`.
 	///
 	/// # Examples
@@ -1156,135 +1395,1265 @@ where
 		IterZeros::new(self)
 	}
 
-	/// Copies the bits from `src` into `self`.
-	///
-	/// The length of `src` must be the same as `self.
-	///
-	/// If `src` has the same type arguments as `self`, it can be more
-	/// performant to use [`.copy_from_bitslice()`].
+	/// Finds the index of the first bit in the slice that is set to `1`.
 	///
-	/// # Original
+	/// # Parameters
 	///
-	/// [`slice::clone_from_bitslice`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.clone_from_bitslice)
+	/// - `&self`
 	///
-	/// # API Differences
+	/// # Returns
 	///
-	/// This method is renamed, as it takes a bit slice rather than an element
-	/// slice.
+	/// The lowest index in the slice whose bit is set, or `None` if every bit
+	/// is cleared.
 	///
-	/// # Panics
+	/// # Behavior
 	///
-	/// This function will panic if the two slices have different lengths.
+	/// This is the first item of [`.iter_ones()`], which searches with
+	/// [`.leading_run()`]. When `O` is `Lsb0` or `Msb0`, a slice with no set
+	/// bits is rejected a whole `usize` at a time.
 	///
 	/// # Examples
 	///
-	/// Cloning two bits from a slice into another:
-	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = bits![Msb0, u16; 1; 4];
-	/// let dst = bits![mut Lsb0, u8; 0; 2];
-	///
-	/// dst.clone_from_bitslice(&src[2 ..]);
-	/// assert_eq!(dst, bits![1; 2]);
+	/// assert_eq!(bits![0, 0, 1, 0, 1].first_one(), Some(2));
+	/// assert!(bits![0; 100].first_one().is_none());
 	/// ```
 	///
-	/// Rust enforces that there can only be one mutable reference with no
-	/// immutable references to a particular piece of data in a particular
-	/// scope. Because of this, attempting to use clone_from_slice on a single
-	/// slice will result in a compile failure:
+	/// [`.iter_ones()`]: Self::iter_ones
+	/// [`.leading_run()`]: Self::leading_run
+	pub fn first_one(&self) -> Option<usize> {
+		self.iter_ones().next()
+	}
+
+	/// Finds the index of the last bit in the slice that is set to `1`.
 	///
-	/// ```rust,compile_fail
-	/// use bitvec::prelude::*;
+	/// # Returns
 	///
-	/// let slice = bits![mut 0, 0, 0, 1, 1];
-	/// slice[.. 2].clone_from_bitslice(&slice[3 ..]); // compile fail!
-	/// ```
+	/// The highest index in the slice whose bit is set, or `None` if every bit
+	/// is cleared.
 	///
-	/// To work around this, we can use [`.split_at_mut()`] to create two
-	/// distinct sub-slices from a slice:
+	/// # Behavior
+	///
+	/// This is the last item of [`.iter_ones()`], which searches from the back
+	/// with [`.trailing_run()`].
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let slice = bits![mut 0, 0, 0, 1, 1];
+	/// assert_eq!(bits![0, 0, 1, 0, 1, 0].last_one(), Some(4));
+	/// assert!(bits![0; 100].last_one().is_none());
+	/// ```
 	///
-	/// {
-	///   let (left, right) = slice.split_at_mut(2);
-	///   left.clone_from_bitslice(&right[1 ..]);
-	/// }
+	/// [`.iter_ones()`]: Self::iter_ones
+	/// [`.trailing_run()`]: Self::trailing_run
+	pub fn last_one(&self) -> Option<usize> {
+		self.iter_ones().next_back()
+	}
+
+	/// Finds the index of the first bit in the slice that is cleared to `0`.
 	///
-	/// assert_eq!(slice, bits![1, 1, 0, 1, 1]);
-	/// ```
+	/// This is [`.first_one()`], searching for cleared bits instead of set
+	/// bits. It is the usual query for a free slot in an allocation bitmap.
 	///
-	/// # Performance
+	/// # Examples
 	///
-	/// If `self` and `src` use the same type arguments, this specializes to
-	/// [`.copy_from_bitslice()`]; if you know statically that this is the case,
-	/// prefer to call that method directly and avoid the cost of detection at
-	/// runtime. Otherwise, this is a bit-by-bit crawl across both slices, which
-	/// is a slow process.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// [`.copy_from_bitslice()`]: Self::copy_from_bitslice
-	/// [`.split_at_mut()`]: Self::split_at_mut
-	pub fn clone_from_bitslice<O2, T2>(&mut self, src: &BitSlice<O2, T2>)
-	where
-		O2: BitOrder,
-		T2: BitStore,
-	{
-		assert_eq!(
-			self.len(),
-			src.len(),
-			"Cloning between slices requires equal lengths"
-		);
-
-		if TypeId::of::<O>() == TypeId::of::<O2>()
-			&& TypeId::of::<T>() == TypeId::of::<T2>()
-		{
-			let that = src as *const _ as *const _;
-			unsafe {
-				self.copy_from_bitslice(&*that);
-			}
-		}
-		else {
-			for (to, from) in unsafe { self.iter_mut().remove_alias() }
-				.zip(src.iter().copied())
-			{
-				to.set(from);
-			}
-		}
+	/// assert_eq!(bits![1, 1, 0, 1].first_zero(), Some(2));
+	/// assert!(bits![1; 100].first_zero().is_none());
+	/// ```
+	///
+	/// [`.first_one()`]: Self::first_one
+	pub fn first_zero(&self) -> Option<usize> {
+		self.iter_zeros().next()
 	}
 
-	/// Copies all bits from `src` into `self`, using a memcpy wherever
-	/// possible.
+	/// Finds the index of the last bit in the slice that is cleared to `0`.
 	///
-	/// The length of `src` must be same as `self`.
+	/// This is [`.last_one()`], searching for cleared bits instead of set
+	/// bits.
 	///
-	/// If `src` does not use the same type arguments as `self`, use
-	/// [`.clone_from_bitslice()`].
+	/// # Examples
 	///
-	/// # Original
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// [`slice::copy_from_slice`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.copy_from_slice)
+	/// assert_eq!(bits![0, 1, 0, 1, 1].last_zero(), Some(2));
+	/// assert!(bits![1; 100].last_zero().is_none());
+	/// ```
 	///
-	/// # API Differences
+	/// [`.last_one()`]: Self::last_one
+	pub fn last_zero(&self) -> Option<usize> {
+		self.iter_zeros().next_back()
+	}
+
+	/// Enumerates the runs of identical bits in the slice.
 	///
-	/// This method is renamed, as it takes a bit slice rather than an element
-	/// slice.
+	/// # Parameters
 	///
-	/// # Panics
+	/// - `&self`
 	///
-	/// This function will panic if the two slices have different lengths.
+	/// # Returns
 	///
-	/// # Examples
+	/// An iterator over the maximal runs of identical bits in the slice, in
+	/// order of increasing index. Each run is yielded as its bit value and its
+	/// length. Adjacent runs always have opposite values, and the lengths sum
+	/// to `self.len()`. The iterator can also be walked from the back.
 	///
-	/// Copying two bits from a slice into another:
+	/// This is the primitive beneath run-length encoders such as
+	/// [`.rle_encode()`].
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = bits![1; 4];
+	/// let bits = bits![1, 1, 1, 0, 0];
+	/// let mut runs = bits.iter_runs();
+	/// assert_eq!(runs.next(), Some((true, 3)));
+	/// assert_eq!(runs.next(), Some((false, 2)));
+	/// assert!(runs.next().is_none());
+	///
+	/// assert_eq!(bits.iter_runs().next_back(), Some((false, 2)));
+	/// ```
+	///
+	/// [`.rle_encode()`]: Self::rle_encode
+	pub fn iter_runs(&self) -> IterRuns<O, T> {
+		IterRuns::new(self)
+	}
+
+	/// Enumerates the neighbors of a vertex, treating the slice as one row of
+	/// an adjacency matrix.
+	///
+	/// A graph of `n` vertices, numbered `0 .. n`, can be stored as `n`
+	/// bit-slices of `n` bits each. Bit `j` of row `i` is set when the graph
+	/// has an edge from vertex `i` to vertex `j`, so the index of each set bit
+	/// in a row is the id of a neighboring vertex.
+	///
+	/// This is [`.iter_ones()`], named for that use.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The adjacency row of a vertex.
+	///
+	/// # Returns
+	///
+	/// An iterator over the ids of the vertices that the row’s vertex has an
+	/// edge to, in increasing order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// //  0 -> 1, 0 -> 3, 1 -> 2, 3 -> 0
+	/// let graph = [
+	///   bits![0, 1, 0, 1],
+	///   bits![0, 0, 1, 0],
+	///   bits![0, 0, 0, 0],
+	///   bits![1, 0, 0, 0],
+	/// ];
+	/// assert!(graph[0].neighbors().eq([1, 3].iter().copied()));
+	/// assert_eq!(graph[2].neighbors().next(), None);
+	/// ```
+	///
+	/// [`.iter_ones()`]: Self::iter_ones
+	pub fn neighbors(&self) -> IterOnes<O, T> {
+		self.iter_ones()
+	}
+
+	/// Counts the neighbors of a vertex, treating the slice as one row of an
+	/// adjacency matrix.
+	///
+	/// This is [`.count_ones()`], named for that use. See [`.neighbors()`] for
+	/// the mapping between bits and vertices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The adjacency row of a vertex.
+	///
+	/// # Returns
+	///
+	/// The number of edges leaving the row’s vertex.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let row = bits![0, 1, 1, 0, 1];
+	/// assert_eq!(row.degree(), 3);
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	/// [`.neighbors()`]: Self::neighbors
+	pub fn degree(&self) -> usize {
+		self.count_ones()
+	}
+
+	/// Counts the bits set to `1` in the front of a slice.
+	///
+	/// This is the *rank* operation of succinct data structures.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The number of leading bits to count. This may be equal to
+	///   `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `self[.. index]` that are set to `1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+	/// assert_eq!(bits.rank_ones(0), 0);
+	/// assert_eq!(bits.rank_ones(3), 2);
+	/// assert_eq!(bits.rank_ones(8), 4);
+	/// ```
+	pub fn rank_ones(&self, index: usize) -> usize {
+		self.assert_rank_index(index);
+		unsafe { self.get_unchecked(.. index) }.count_ones()
+	}
+
+	/// Counts the bits cleared to `0` in the front of a slice.
+	///
+	/// This is the complement of [`.rank_ones()`]: `self.rank_zeros(i)` is
+	/// always `i - self.rank_ones(i)`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The number of leading bits to count. This may be equal to
+	///   `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `self[.. index]` that are cleared to `0`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+	/// assert_eq!(bits.rank_zeros(3), 1);
+	/// assert_eq!(bits.rank_zeros(8), 4);
+	/// ```
+	///
+	/// [`.rank_ones()`]: Self::rank_ones
+	pub fn rank_zeros(&self, index: usize) -> usize {
+		index - self.rank_ones(index)
+	}
+
+	/// Finds the index of the `rank`th bit set to `1`, counting from zero.
+	///
+	/// This is the *select* operation of succinct data structures, and is the
+	/// inverse of [`.rank_ones()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of set bits to pass over before stopping.
+	///
+	/// # Returns
+	///
+	/// The index of the set bit with exactly `rank` set bits before it, or
+	/// `None` if the slice has `rank` or fewer set bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+	/// assert_eq!(bits.select_ones(0), Some(1));
+	/// assert_eq!(bits.select_ones(3), Some(7));
+	/// assert!(bits.select_ones(4).is_none());
+	/// ```
+	///
+	/// [`.rank_ones()`]: Self::rank_ones
+	pub fn select_ones(&self, rank: usize) -> Option<usize> {
+		self.select_bit(rank, true)
+	}
+
+	/// Finds the index of the `rank`th bit cleared to `0`, counting from zero.
+	///
+	/// This is the inverse of [`.rank_zeros()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of cleared bits to pass over before stopping.
+	///
+	/// # Returns
+	///
+	/// The index of the cleared bit with exactly `rank` cleared bits before
+	/// it, or `None` if the slice has `rank` or fewer cleared bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+	/// assert_eq!(bits.select_zeros(0), Some(0));
+	/// assert_eq!(bits.select_zeros(3), Some(6));
+	/// assert!(bits.select_zeros(4).is_none());
+	/// ```
+	///
+	/// [`.rank_zeros()`]: Self::rank_zeros
+	pub fn select_zeros(&self, rank: usize) -> Option<usize> {
+		self.select_bit(rank, false)
+	}
+
+	/// Finds the `n`th maximal run of set bits in the slice, counting from
+	/// zero.
+	///
+	/// A run is a sequence of adjacent bits set to `1` that is bounded on each
+	/// side by either a cleared bit or an edge of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The number of runs to pass over before stopping.
+	///
+	/// # Returns
+	///
+	/// The range of indices covered by the `n`th run of set bits, or `None` if
+	/// the slice has `n` or fewer such runs.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0, 1, 0, 1, 1, 1];
+	/// assert_eq!(bits.nth_one_run(0), Some(0 .. 2));
+	/// assert_eq!(bits.nth_one_run(1), Some(4 .. 5));
+	/// assert_eq!(bits.nth_one_run(2), Some(6 .. 9));
+	/// assert!(bits.nth_one_run(3).is_none());
+	/// ```
+	pub fn nth_one_run(&self, mut n: usize) -> Option<Range<usize>> {
+		let len = self.len();
+		let mut cursor = 0;
+		loop {
+			let rest = unsafe { self.get_unchecked(cursor ..) };
+			let start = cursor + rest.iter_ones().next()?;
+			let rest = unsafe { self.get_unchecked(start ..) };
+			let end = rest.iter_zeros().next().map_or(len, |idx| start + idx);
+			if n == 0 {
+				return Some(start .. end);
+			}
+			n -= 1;
+			cursor = end;
+		}
+	}
+
+	/// Tests whether the slice reads the same forwards and backwards.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether each bit at index `n` is equal to the bit at index
+	/// `len - 1 - n`. Empty and single-bit slices are always palindromes.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert!(bits![1, 0, 1, 1, 0, 1].is_palindrome());
+	/// assert!(bits![0, 1, 1, 1, 0].is_palindrome());
+	/// assert!(!bits![1, 1, 0].is_palindrome());
+	/// ```
+	pub fn is_palindrome(&self) -> bool {
+		//  The same `TypeId` dispatch used in `.copy_from_bitslice()`.
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			return this.sp_is_palindrome();
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			return this.sp_is_palindrome();
+		}
+		let len = self.len();
+		let half = len / 2;
+		let front = unsafe { self.get_unchecked(.. half) };
+		let back = unsafe { self.get_unchecked(len - half ..) };
+		front.iter().zip(back.iter().rev()).all(|(a, b)| a == b)
+	}
+
+	/// Validates a range against the length of the slice.
+	///
+	/// This resolves any of the range forms accepted by the slice indexing
+	/// operators into a concrete `start .. end` pair, without producing a
+	/// subslice. It is intended for APIs that accept a range argument and must
+	/// reject it before doing any work.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: Any range of bit indices.
+	///
+	/// # Returns
+	///
+	/// The half-open range equivalent to `range`, if `range` is in bounds for
+	/// `self`. If the start of `range` is after its end, or its end is beyond
+	/// `self.len()`, this returns `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0; 10];
+	/// assert_eq!(bits.check_range(..), Some(0 .. 10));
+	/// assert_eq!(bits.check_range(2 ..), Some(2 .. 10));
+	/// assert_eq!(bits.check_range(.. 4), Some(0 .. 4));
+	/// assert_eq!(bits.check_range(..= 4), Some(0 .. 5));
+	/// assert_eq!(bits.check_range(2 .. 4), Some(2 .. 4));
+	/// assert_eq!(bits.check_range(2 ..= 9), Some(2 .. 10));
+	///
+	/// assert!(bits.check_range(..= 10).is_none());
+	/// assert!(bits.check_range(11 ..).is_none());
+	/// assert!(bits.check_range(5 .. 4).is_none());
+	/// ```
+	pub fn check_range<R>(&self, range: R) -> Option<Range<usize>>
+	where R: RangeBounds<usize> {
+		let start = match range.start_bound() {
+			Bound::Included(&n) => n,
+			Bound::Excluded(&n) => n.checked_add(1)?,
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&n) => n.checked_add(1)?,
+			Bound::Excluded(&n) => n,
+			Bound::Unbounded => self.len(),
+		};
+		if start <= end && end <= self.len() {
+			Some(start .. end)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Finds the first occurrence of a bit pattern in the slice.
+	///
+	/// To find every occurrence, use [`.match_indices()`].
+	///
+	/// # Type Parameters
+	///
+	/// - `O2`, `T2`: The type parameters of `needle`, which need not match
+	///   those of `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit pattern to search for.
+	///
+	/// # Returns
+	///
+	/// The index in `self` at which the first subslice equal to `needle`
+	/// begins. This is `Some(0)` if `needle` is empty, and `None` if `needle`
+	/// is longer than `self`.
+	///
+	/// # Behavior
+	///
+	/// Each `needle.len()`-bit window of `self` is compared against `needle`.
+	/// The first `usize` of each window is loaded as a word and compared
+	/// against the first word of `needle`, so needles of up to a word are
+	/// matched with a single comparison per window, and longer needles only
+	/// compare their remaining bits at windows whose first word matches. When
+	/// `O` is `Lsb0` or `Msb0`, those words are read with [`BitField`] loads.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 1, 0];
+	/// assert_eq!(bits.find(bits![1, 1, 1]), Some(4));
+	/// assert_eq!(bits.find(bits![1, 0]), Some(2));
+	/// assert_eq!(bits.find(bits![0, 0]), None);
+	/// assert_eq!(bits.find(bits![]), Some(0));
+	/// ```
+	///
+	/// [`BitField`]: crate::field::BitField
+	/// [`.match_indices()`]: Self::match_indices
+	pub fn find<O2, T2>(&self, needle: &BitSlice<O2, T2>) -> Option<usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let len = needle.len();
+		if len == 0 {
+			return Some(0);
+		}
+		if len > self.len() {
+			return None;
+		}
+		let width = <usize as BitMemory>::BITS as usize;
+		let (head, rest) =
+			unsafe { needle.split_at_unchecked(cmp::min(len, width)) };
+		let word = head.load_word();
+		self.windows(len).position(|window| {
+			let (w_head, w_rest) =
+				unsafe { window.split_at_unchecked(head.len()) };
+			w_head.load_word() == word && w_rest == rest
+		})
+	}
+
+	/// Finds the set bit nearest to an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index from which to measure distance.
+	///
+	/// # Returns
+	///
+	/// The index of the set bit with the smallest absolute distance from
+	/// `index`, or `None` if no bit in the slice is set. If `self[index]` is
+	/// set, this is `index` itself. When a set bit on each side is equally
+	/// distant, the lower index is returned.
+	///
+	/// # Behavior
+	///
+	/// The slice is searched forward from `index` and backward from it with
+	/// [`.iter_ones()`], which skips over cleared memory elements without
+	/// inspecting their bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 0, 1, 0, 0, 0, 0];
+	/// assert_eq!(bits.nearest_one(1), Some(1));
+	/// assert_eq!(bits.nearest_one(2), Some(1));
+	/// //  A tie resolves to the lower index.
+	/// assert_eq!(bits.nearest_one(3), Some(1));
+	/// assert_eq!(bits.nearest_one(9), Some(5));
+	///
+	/// assert!(bits![0; 10].nearest_one(4).is_none());
+	/// ```
+	///
+	/// [`.iter_ones()`]: Self::iter_ones
+	pub fn nearest_one(&self, index: usize) -> Option<usize> {
+		self.assert_in_bounds(index);
+		let (front, back) = unsafe { self.split_at_unchecked(index) };
+		let after = back.iter_ones().next().map(|idx| index + idx);
+		let before = front.iter_ones().next_back();
+		match (before, after) {
+			(Some(lo), Some(hi)) => {
+				Some(if index - lo <= hi - index { lo } else { hi })
+			},
+			(lo, hi) => lo.or(hi),
+		}
+	}
+
+	/// Measures the run of identical bits at the front of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of `self[0]`, and the number of bits from the front of the
+	/// slice that are equal to it, or `None` if the slice is empty.
+	///
+	/// # Behavior
+	///
+	/// When `O` is `Lsb0` or `Msb0`, the slice is loaded in `usize` chunks,
+	/// and the run is measured with `trailing_ones` or `trailing_zeros` on
+	/// each chunk. Other orderings compare each bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 1, 1, 0, 1].leading_run(), Some((true, 3)));
+	/// assert_eq!(bits![0; 20].leading_run(), Some((false, 20)));
+	/// assert!(BitSlice::<Lsb0, u8>::empty().leading_run().is_none());
+	/// ```
+	pub fn leading_run(&self) -> Option<(bool, usize)> {
+		let value = *self.first()?;
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut run = 0;
+		for chunk in self.chunks(width) {
+			let len = chunk.len();
+			let count = match chunk.try_load_word() {
+				//  The bits above `len` are zero, so they end a run of ones.
+				Some(word) if value => word.trailing_ones() as usize,
+				Some(word) => cmp::min(word.trailing_zeros() as usize, len),
+				None => chunk.iter().take_while(|&&bit| bit == value).count(),
+			};
+			run += count;
+			if count < len {
+				break;
+			}
+		}
+		Some((value, run))
+	}
+
+	/// Measures the run of identical bits at the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the last bit in the slice, and the number of bits from the
+	/// back of the slice that are equal to it, or `None` if the slice is
+	/// empty.
+	///
+	/// # Behavior
+	///
+	/// When `O` is `Lsb0` or `Msb0`, the slice is loaded in `usize` chunks
+	/// from the back, and the run is measured with `leading_ones` or
+	/// `leading_zeros` on each chunk. Other orderings compare each bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 0, 1, 1, 1].trailing_run(), Some((true, 3)));
+	/// assert_eq!(bits![1, 1, 0].trailing_run(), Some((false, 1)));
+	/// assert!(BitSlice::<Lsb0, u8>::empty().trailing_run().is_none());
+	/// ```
+	pub fn trailing_run(&self) -> Option<(bool, usize)> {
+		let value = *self.last()?;
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut run = 0;
+		for chunk in self.rchunks(width) {
+			let len = chunk.len();
+			let count = match chunk.try_load_word() {
+				Some(word) => {
+					/* Move the end of the chunk to the top of the word. The
+					bits shifted in below the chunk are zero, so they end a run
+					of ones.
+					*/
+					let word = word << (width - len);
+					if value {
+						word.leading_ones() as usize
+					}
+					else {
+						cmp::min(word.leading_zeros() as usize, len)
+					}
+				},
+				None => {
+					chunk.iter().rev().take_while(|&&bit| bit == value).count()
+				},
+			};
+			run += count;
+			if count < len {
+				break;
+			}
+		}
+		Some((value, run))
+	}
+
+	/// Counts the `0` bits at the front of the slice.
+	///
+	/// “Leading” refers to the front of the slice, index `0`, rather than to
+	/// the most significant bit of any memory element. For a `BitSlice<Lsb0,
+	/// _>` viewing an integer, this is the integer’s `.trailing_zeros()`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits, starting from index `0`, that are cleared before
+	/// the first set bit. This is `self.len()` if no bit is set.
+	///
+	/// # Behavior
+	///
+	/// This uses [`.leading_run()`], which examines a whole `usize` of the
+	/// slice at a time when `O` is `Lsb0` or `Msb0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 0, 1, 0].leading_zeros(), 2);
+	/// assert_eq!(bits![1, 0].leading_zeros(), 0);
+	/// assert_eq!(bits![0; 70].leading_zeros(), 70);
+	/// ```
+	///
+	/// [`.leading_run()`]: Self::leading_run
+	pub fn leading_zeros(&self) -> usize {
+		match self.leading_run() {
+			Some((false, run)) => run,
+			_ => 0,
+		}
+	}
+
+	/// Counts the `1` bits at the front of the slice.
+	///
+	/// This is [`.leading_zeros()`], counting set bits instead of cleared
+	/// bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 1, 0, 1].leading_ones(), 2);
+	/// assert_eq!(bits![0, 1].leading_ones(), 0);
+	/// ```
+	///
+	/// [`.leading_zeros()`]: Self::leading_zeros
+	pub fn leading_ones(&self) -> usize {
+		match self.leading_run() {
+			Some((true, run)) => run,
+			_ => 0,
+		}
+	}
+
+	/// Counts the `0` bits at the back of the slice.
+	///
+	/// “Trailing” refers to the back of the slice, index `self.len() - 1`,
+	/// rather than to the least significant bit of any memory element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits, ending at the last index, that are cleared after
+	/// the last set bit. This is `self.len()` if no bit is set.
+	///
+	/// # Behavior
+	///
+	/// This uses [`.trailing_run()`], which examines a whole `usize` of the
+	/// slice at a time when `O` is `Lsb0` or `Msb0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 0, 0].trailing_zeros(), 2);
+	/// assert_eq!(bits![0, 1].trailing_zeros(), 0);
+	/// assert_eq!(BitSlice::<Lsb0, u8>::empty().trailing_zeros(), 0);
+	/// ```
+	///
+	/// [`.trailing_run()`]: Self::trailing_run
+	pub fn trailing_zeros(&self) -> usize {
+		match self.trailing_run() {
+			Some((false, run)) => run,
+			_ => 0,
+		}
+	}
+
+	/// Counts the `1` bits at the back of the slice.
+	///
+	/// This is [`.trailing_zeros()`], counting set bits instead of cleared
+	/// bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 1, 1].trailing_ones(), 3);
+	/// assert_eq!(bits![1, 0].trailing_ones(), 0);
+	/// ```
+	///
+	/// [`.trailing_zeros()`]: Self::trailing_zeros
+	pub fn trailing_ones(&self) -> usize {
+		match self.trailing_run() {
+			Some((true, run)) => run,
+			_ => 0,
+		}
+	}
+
+	/// Counts the places where adjacent bits in the slice differ.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of indices `n` for which `self[n] != self[n + 1]`: that is,
+	/// the number of `0 → 1` and `1 → 0` transitions. The first bit has no
+	/// predecessor, so it is not a transition by itself; empty and single-bit
+	/// slices have no transitions.
+	///
+	/// # Behavior
+	///
+	/// The slice is compared against itself offset by one bit. When `O` is
+	/// `Lsb0` or `Msb0`, both views are loaded in `usize` chunks, and the
+	/// population count of the XOR of each pair of chunks is summed. Other
+	/// orderings compare each pair of bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 0, 1, 1, 1, 0, 1].count_transitions(), 3);
+	/// assert_eq!(bits![1; 10].count_transitions(), 0);
+	/// assert_eq!(bits![1].count_transitions(), 0);
+	/// ```
+	pub fn count_transitions(&self) -> usize {
+		let len = self.len();
+		if len < 2 {
+			return 0;
+		}
+		let front = unsafe { self.get_unchecked(.. len - 1) };
+		let back = unsafe { self.get_unchecked(1 ..) };
+		let width = <usize as BitMemory>::BITS as usize;
+		front
+			.chunks(width)
+			.zip(back.chunks(width))
+			.map(|(a, b)| match (a.try_load_word(), b.try_load_word()) {
+				(Some(a), Some(b)) => (a ^ b).count_ones() as usize,
+				_ => a.iter().zip(b.iter()).filter(|(x, y)| x != y).count(),
+			})
+			.sum()
+	}
+
+	/// Tests whether every bit set in `self` is also set in `other`.
+	///
+	/// Treating each slice as a set of the indices of its set bits, this is
+	/// the subset relation.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// Whether `self & !other` has no bits set. The empty set is a subset of
+	/// every set, and every set is a subset of itself.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let small = bits![0, 1, 0, 1, 0];
+	/// let large = bits![1, 1, 0, 1, 0];
+	/// assert!(small.is_subset(large));
+	/// assert!(!large.is_subset(small));
+	/// ```
+	pub fn is_subset<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.all_words(other, |a, b| a & !b == 0)
+	}
+
+	/// Tests whether every bit set in `other` is also set in `self`.
+	///
+	/// This is [`.is_subset()`] with its arguments swapped.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// Whether `other & !self` has no bits set.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 1];
+	/// assert!(bits.is_superset(bits![1, 0, 0, 1]));
+	/// assert!(!bits.is_superset(bits![0, 0, 1, 0]));
+	/// ```
+	///
+	/// [`.is_subset()`]: Self::is_subset
+	pub fn is_superset<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.all_words(other, |a, b| b & !a == 0)
+	}
+
+	/// Tests whether `self` and `other` have no set bits in common.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// Whether `self & other` has no bits set.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1];
+	/// assert!(bits.is_disjoint(bits![0, 1, 1, 0]));
+	/// assert!(!bits.is_disjoint(bits![0, 0, 1, 1]));
+	/// ```
+	pub fn is_disjoint<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.all_words(other, |a, b| a & b == 0)
+	}
+
+	/// Divides the slice into the bits before an index, the bit at it, and
+	/// the bits after it.
+	///
+	/// This is equivalent to two calls to [`.split_at()`], but also produces
+	/// the value of the pivot bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the pivot bit.
+	///
+	/// # Returns
+	///
+	/// - `self[.. index]`
+	/// - `self[index]`
+	/// - `self[index + 1 ..]`
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 0];
+	/// let (left, pivot, right) = bits.split_around(2);
+	/// assert_eq!(left, bits![0, 0]);
+	/// assert!(pivot);
+	/// assert_eq!(right, bits![1, 0]);
+	/// ```
+	///
+	/// [`.split_at()`]: Self::split_at
+	pub fn split_around(&self, index: usize) -> (&Self, bool, &Self) {
+		self.assert_in_bounds(index);
+		unsafe {
+			let (front, rest) = self.split_at_unchecked(index);
+			(front, *rest.get_unchecked(0), rest.get_unchecked(1 ..))
+		}
+	}
+
+	/// Divides the slice into the bits before an index, a proxy for the bit at
+	/// it, and the bits after it.
+	///
+	/// This is the mutable form of [`.split_around()`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The index of the pivot bit.
+	///
+	/// # Returns
+	///
+	/// - `self[.. index]`
+	/// - A write proxy for `self[index]`
+	/// - `self[index + 1 ..]`
+	///
+	/// As with [`.split_at_mut()`], all three handles are marked as aliased,
+	/// because they may share memory elements.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 5];
+	/// let (left, mut pivot, right) = bits.split_around_mut(2);
+	/// left.set(0, true);
+	/// pivot.set(true);
+	/// right.set(1, true);
+	/// drop(pivot);
+	/// assert_eq!(bits, bits![1, 0, 1, 0, 1]);
+	/// ```
+	///
+	/// [`.split_around()`]: Self::split_around
+	/// [`.split_at_mut()`]: Self::split_at_mut
+	pub fn split_around_mut(
+		&mut self,
+		index: usize,
+	) -> (
+		&mut BitSlice<O, T::Alias>,
+		BitMut<O, T::Alias>,
+		&mut BitSlice<O, T::Alias>,
+	) {
+		self.assert_in_bounds(index);
+		unsafe {
+			let (front, rest) = self.split_at_unchecked_mut(index);
+			let (pivot, back) = rest.split_at_unchecked_mut_noalias(1);
+			(front, pivot.get_unchecked_mut(0), back)
+		}
+	}
+
+	/// Enumerates every index at which `needle` occurs in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit pattern to seek.
+	///
+	/// # Returns
+	///
+	/// An iterator over the starting index of each occurrence of `needle`, in
+	/// ascending order. Overlapping occurrences are all reported. As with
+	/// [`str::match_indices`], an empty `needle` matches at every index from
+	/// `0` through `self.len()`, inclusive.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 1, 0, 0, 1, 0];
+	/// let mut hits = bits.match_indices(bits![0, 1, 0]);
+	///
+	/// assert_eq!(hits.next(), Some(0));
+	/// assert_eq!(hits.next(), Some(2));
+	/// assert_eq!(hits.next(), Some(5));
+	/// assert!(hits.next().is_none());
+	/// ```
+	///
+	/// [`str::match_indices`]: https://doc.rust-lang.org/stable/std/primitive.str.html#method.match_indices
+	pub fn match_indices<'a, O2, T2>(
+		&'a self,
+		needle: &'a BitSlice<O2, T2>,
+	) -> MatchIndices<'a, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		MatchIndices::new(self, needle)
+	}
+
+	/// Divides a `BitSlice` into byte-sized subslices.
+	///
+	/// This is a named form of [`.chunks(8)`], for code that processes a bit
+	/// stream in whole-byte units but still needs each byte as a `BitSlice`
+	/// rather than as a loaded `u8` value.
+	///
+	/// # Behavior
+	///
+	/// Chunk boundaries are measured from index `0` of `self`, not from the
+	/// memory elements underneath it, so every chunk but the last has exactly
+	/// eight bits even when `self` does not begin on an element edge. If
+	/// `self.len()` is not a multiple of eight, the final chunk holds the
+	/// remaining `self.len() % 8` bits. The empty slice yields no chunks.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1];
+	/// let mut chunks = bits.byte_chunks();
+	///
+	/// assert_eq!(chunks.next().unwrap(), bits![0, 0, 0, 1, 1, 0, 1, 0]);
+	/// assert_eq!(chunks.next().unwrap(), bits![1, 1, 1]);
+	/// assert!(chunks.next().is_none());
+	/// ```
+	///
+	/// [`.chunks(8)`]: Self::chunks
+	pub fn byte_chunks(&self) -> Chunks<O, T> {
+		self.chunks(8)
+	}
+
+	/// Copies the bits from `src` into `self`.
+	///
+	/// The length of `src` must be the same as `self.
+	///
+	/// If `src` has the same type arguments as `self`, it can be more
+	/// performant to use [`.copy_from_bitslice()`].
+	///
+	/// # Original
+	///
+	/// [`slice::clone_from_bitslice`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.clone_from_bitslice)
+	///
+	/// # API Differences
+	///
+	/// This method is renamed, as it takes a bit slice rather than an element
+	/// slice.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// Cloning two bits from a slice into another:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = bits![Msb0, u16; 1; 4];
+	/// let dst = bits![mut Lsb0, u8; 0; 2];
+	///
+	/// dst.clone_from_bitslice(&src[2 ..]);
+	/// assert_eq!(dst, bits![1; 2]);
+	/// ```
+	///
+	/// Rust enforces that there can only be one mutable reference with no
+	/// immutable references to a particular piece of data in a particular
+	/// scope. Because of this, attempting to use clone_from_slice on a single
+	/// slice will result in a compile failure:
+	///
+	/// ```rust,compile_fail
+	/// use bitvec::prelude::*;
+	///
+	/// let slice = bits![mut 0, 0, 0, 1, 1];
+	/// slice[.. 2].clone_from_bitslice(&slice[3 ..]); // compile fail!
+	/// ```
+	///
+	/// To work around this, we can use [`.split_at_mut()`] to create two
+	/// distinct sub-slices from a slice:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let slice = bits![mut 0, 0, 0, 1, 1];
+	///
+	/// {
+	///   let (left, right) = slice.split_at_mut(2);
+	///   left.clone_from_bitslice(&right[1 ..]);
+	/// }
+	///
+	/// assert_eq!(slice, bits![1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// # Performance
+	///
+	/// If `self` and `src` use the same type arguments, this specializes to
+	/// [`.copy_from_bitslice()`]; if you know statically that this is the case,
+	/// prefer to call that method directly and avoid the cost of detection at
+	/// runtime. Otherwise, this is a bit-by-bit crawl across both slices, which
+	/// is a slow process.
+	///
+	/// [`.copy_from_bitslice()`]: Self::copy_from_bitslice
+	/// [`.split_at_mut()`]: Self::split_at_mut
+	pub fn clone_from_bitslice<O2, T2>(&mut self, src: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			src.len(),
+			"Cloning between slices requires equal lengths"
+		);
+
+		if TypeId::of::<O>() == TypeId::of::<O2>()
+			&& TypeId::of::<T>() == TypeId::of::<T2>()
+		{
+			let that = src as *const _ as *const _;
+			unsafe {
+				self.copy_from_bitslice(&*that);
+			}
+		}
+		else {
+			for (to, from) in unsafe { self.iter_mut().remove_alias() }
+				.zip(src.iter().copied())
+			{
+				to.set(from);
+			}
+		}
+	}
+
+	/// Copies all bits from `src` into `self`, using a memcpy wherever
+	/// possible.
+	///
+	/// The length of `src` must be same as `self`.
+	///
+	/// If `src` does not use the same type arguments as `self`, use
+	/// [`.clone_from_bitslice()`].
+	///
+	/// # Original
+	///
+	/// [`slice::copy_from_slice`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.copy_from_slice)
+	///
+	/// # API Differences
+	///
+	/// This method is renamed, as it takes a bit slice rather than an element
+	/// slice.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// Copying two bits from a slice into another:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = bits![1; 4];
 	/// let dst = bits![mut 0; 2];
 	///
 	/// // Because the slices have to be the same length,
@@ -1488,14 +2857,20 @@ where
 	/// destructive (and linearly expensive) operation, you may prefer instead
 	/// to use range subslicing.
 	///
+	/// “Left” always means towards index `0`, whatever the ordering `O`, and
+	/// so is not necessarily the direction of `<<` on the underlying memory.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `by`: The distance by which to shift the slice contents.
+	/// - `by`: The distance by which to shift the slice contents. If this is
+	///   not less than `self.len()`, every bit is shifted out and the whole
+	///   slice is cleared.
 	///
-	/// # Panics
+	/// # Behavior
 	///
-	/// This panics if `by` is not less than `self.len()`.
+	/// When `O` is `Lsb0` or `Msb0`, the bits are moved with batched element
+	/// loads and stores rather than one at a time.
 	///
 	/// # Examples
 	///
@@ -1505,18 +2880,17 @@ where
 	/// let bits = bits![mut 1; 6];
 	/// bits.shift_left(2);
 	/// assert_eq!(bits, bits![1, 1, 1, 1, 0, 0]);
+	/// bits.shift_left(10);
+	/// assert!(bits.not_any());
 	/// ```
 	pub fn shift_left(&mut self, by: usize) {
 		let len = self.len();
 		if by == 0 {
 			return;
 		}
-		assert!(
-			by < len,
-			"Cannot shift a slice by more than its length: {} exceeds {}",
-			by,
-			len
-		);
+		if by >= len {
+			return self.set_all(false);
+		}
 
 		unsafe {
 			self.copy_within_unchecked(by .., 0);
@@ -1531,14 +2905,21 @@ where
 	/// and erases `.. by` to `0`. As this is a destructive (and linearly
 	/// expensive) operation, you may prefer instead to use range subslicing.
 	///
+	/// “Right” always means towards index `self.len()`, whatever the ordering
+	/// `O`, and so is not necessarily the direction of `>>` on the underlying
+	/// memory.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `by`: The distance by which to shift the slice contents.
+	/// - `by`: The distance by which to shift the slice contents. If this is
+	///   not less than `self.len()`, every bit is shifted out and the whole
+	///   slice is cleared.
 	///
-	/// # Panics
+	/// # Behavior
 	///
-	/// This panics if `by` is not less than `self.len()`.
+	/// When `O` is `Lsb0` or `Msb0`, the bits are moved with batched element
+	/// loads and stores rather than one at a time.
 	///
 	/// # Examples
 	///
@@ -1548,70 +2929,387 @@ where
 	/// let bits = bits![mut 1; 6];
 	/// bits.shift_right(2);
 	/// assert_eq!(bits, bits![0, 0, 1, 1, 1, 1]);
+	/// bits.shift_right(6);
+	/// assert!(bits.not_any());
+	/// ```
+	pub fn shift_right(&mut self, by: usize) {
+		let len = self.len();
+		if by == 0 {
+			return;
+		}
+		if by >= len {
+			return self.set_all(false);
+		}
+
+		let trunc = len - by;
+		unsafe {
+			self.copy_within_unchecked(.. trunc, by);
+		}
+		self[.. by].set_all(false);
+	}
+
+	/// Sets all bits in the slice to a value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.view_bits_mut::<Msb0>();
+	/// bits[2 .. 6].set_all(true);
+	/// assert_eq!(bits.as_slice(), &[0b0011_1100]);
+	/// bits[3 .. 5].set_all(false);
+	/// assert_eq!(bits.as_slice(), &[0b0010_0100]);
+	/// bits[.. 1].set_all(true);
+	/// assert_eq!(bits.as_slice(), &[0b1010_0100]);
+	/// ```
+	pub fn set_all(&mut self, value: bool) {
+		//  Grab the function pointers used to commit bit-masks into memory.
+		let setter = <T::Access>::get_writers(value);
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				setter(elem, O::mask(head, tail));
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					setter(elem, O::mask(head, None));
+				}
+				//  loop assignment is `memset`’s problem, not ours
+				unsafe {
+					ptr::write_bytes(
+						body.as_mut_ptr(),
+						[0, !0][value as usize],
+						body.len(),
+					);
+				}
+				if let Some((elem, tail)) = tail {
+					setter(elem, O::mask(None, tail));
+				}
+			},
+		}
+	}
+
+	/// Fills the slice with a bit value.
+	///
+	/// This is [`.set_all()`], under the name used by the standard library.
+	///
+	/// # Original
+	///
+	/// [`slice::fill`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.fill)
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Behavior
+	///
+	/// Each memory element that the slice fully occupies is overwritten with
+	/// `0` or `!0` in a single `memset`. Partially-occupied edge elements are
+	/// written through a mask, so the bits in them that are outside the slice
+	/// are unchanged. No bit is visited individually.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// data.view_bits_mut::<Lsb0>()[4 .. 28].fill(true);
+	/// assert_eq!(data, [0xF0, 0xFF, 0xFF, 0x0F]);
+	/// ```
+	///
+	/// [`.set_all()`]: Self::set_all
+	pub fn fill(&mut self, value: bool) {
+		self.set_all(value);
+	}
+
+	/// Fills the slice with bits produced by a function of their index.
+	///
+	/// # Original
+	///
+	/// [`slice::fill_with`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.fill_with)
+	///
+	/// # API Differences
+	///
+	/// The function receives the index of each bit that it produces, so that
+	/// it can compute index-driven patterns without its own counter.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives the index of a bit, and returns the
+	///   value to write into it. It is called once for each index, in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// data.view_bits_mut::<Msb0>()[4 ..].fill_with(|idx| idx % 3 == 0);
+	/// assert_eq!(data, 0b0000_1001_0010_0100);
+	/// ```
+	pub fn fill_with<F>(&mut self, mut func: F)
+	where F: FnMut(usize) -> bool {
+		for idx in 0 .. self.len() {
+			unsafe {
+				self.set_unchecked(idx, func(idx));
+			}
+		}
+	}
+
+	/// Applies a function to each memory element underlying the slice.
+	///
+	/// This allows whole-element transforms, such as byte swaps, bit reversals,
+	/// or table lookups, to be applied to a slice without working through its
+	/// [`.domain_mut()`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives the value of a memory element, and
+	///   returns a new value for it.
+	///
+	/// # Behavior
+	///
+	/// `func` is called once for each memory element that the slice touches,
+	/// in order of increasing address. Each element that the slice fully
+	/// occupies is replaced with the result of `func`.
+	///
+	/// A partially-occupied element at either edge of the slice also has its
+	/// whole value passed to `func`, but only the bits of the result that lie
+	/// within the slice are written. The bits of that element that are outside
+	/// the slice keep their values.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0x0Fu8, 0x0F, 0x0F];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 20];
+	///
+	/// bits.transform_elements(|elem| elem.rotate_left(4));
+	/// //  Only bits 4 through 7 of the first element, and bits 0 through 3 of
+	/// //  the last element, are changed.
+	/// assert_eq!(data, [0xFF, 0xF0, 0x00]);
+	/// ```
+	///
+	/// [`.domain_mut()`]: Self::domain_mut
+	pub fn transform_elements<F>(&mut self, mut func: F)
+	where F: FnMut(T::Mem) -> T::Mem {
+		/* Partial elements are written by inverting only the live bits that
+		`func` changed, so that the rest of the element is never touched.
+		*/
+		let partial =
+			|elem: &T::Access, mask: BitMask<T::Mem>, func: &mut F| {
+				let old = radium::Radium::load(
+					elem,
+					core::sync::atomic::Ordering::Relaxed,
+				);
+				let changed = (old ^ func(old)) & mask.value();
+				elem.invert_bits(BitMask::new(changed));
+			};
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				partial(elem, O::mask(head, tail), &mut func);
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					partial(elem, O::mask(head, None), &mut func);
+				}
+				for elem in body {
+					elem.store_value(func(elem.load_value()));
+				}
+				if let Some((elem, tail)) = tail {
+					partial(elem, O::mask(None, tail), &mut func);
+				}
+			},
+		}
+	}
+
+	/// Writes a stream of bits into the slice, starting at an offset.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `offset`: The index at which to write the first bit of `iter`.
+	/// - `iter`: A source of bits to write into `self[offset ..]`.
+	///
+	/// # Returns
+	///
+	/// The number of bits written. This is the lesser of the number of bits
+	/// that `iter` produced, and `self.len() - offset`.
+	///
+	/// # Behavior
+	///
+	/// Bits are pulled from `iter` until either it is exhausted or the end of
+	/// the slice is reached. If `iter` has more bits than the slice has room
+	/// for, the remainder are not pulled, and `iter` is dropped; the slice is
+	/// never extended. Bits outside the written region are not modified.
+	///
+	/// The pulled bits are gathered into a `usize` buffer, and each full
+	/// buffer is written into the slice in one batch when `O` is `Lsb0` or
+	/// `Msb0`. Other orderings write each buffered bit individually.
+	///
+	/// # Panics
+	///
+	/// This panics if `offset` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = data.view_bits_mut::<Msb0>();
+	///
+	/// let stream = [true, false, true].iter().copied();
+	/// assert_eq!(bits.write_bools_at(2, stream), 3);
+	/// assert_eq!(data, 0b0010_1000);
+	///
+	/// //  Only two bits fit after index 6.
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// assert_eq!(bits.write_bools_at(6, core::iter::repeat(true)), 2);
+	/// assert_eq!(data, 0b0010_1011);
+	/// ```
+	pub fn write_bools_at<I>(&mut self, offset: usize, iter: I) -> usize
+	where I: IntoIterator<Item = bool> {
+		let len = self.len();
+		assert!(offset <= len, "Offset out of range: {} > {}", offset, len);
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut iter = iter.into_iter();
+		let mut written = 0;
+		for chunk in unsafe { self.get_unchecked_mut(offset ..) }
+			.chunks_mut(width)
+		{
+			let (mut word, mut count) = (0usize, 0);
+			for bit in iter.by_ref().take(chunk.len()) {
+				word |= (bit as usize) << count;
+				count += 1;
+			}
+			if count == 0 {
+				break;
+			}
+			let dest = unsafe { chunk.get_unchecked_mut(.. count) };
+			if !dest.try_store_word(word) {
+				for idx in 0 .. count {
+					unsafe {
+						dest.set_unchecked(idx, word >> idx & 1 == 1);
+					}
+				}
+			}
+			written += count;
+			if count < chunk.len() {
+				break;
+			}
+		}
+		written
+	}
+
+	/// Moves all set bits to the front of the slice, and all cleared bits to
+	/// the back.
+	///
+	/// Bits have no identity beyond their value, so this is equivalent to
+	/// counting the set bits, then setting that many bits at the front of the
+	/// slice and clearing the rest. Both steps work on whole memory elements
+	/// where possible.
+	///
+	/// This destroys the original order of the slice. Only the number of set
+	/// bits survives it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The partition point: the number of set bits, and the index of the first
+	/// cleared bit if there is one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 0, 0, 1, 1, 0];
+	/// assert_eq!(bits.partition(), 3);
+	/// assert_eq!(bits[..], bits![1, 1, 1, 0, 0, 0, 0]);
 	/// ```
-	pub fn shift_right(&mut self, by: usize) {
-		let len = self.len();
-		if by == 0 {
-			return;
-		}
-		assert!(
-			by < len,
-			"Cannot shift a slice by more than its length: {} exceeds {}",
-			by,
-			len
-		);
-
-		let trunc = len - by;
-		unsafe {
-			self.copy_within_unchecked(.. trunc, by);
-		}
-		self[.. by].set_all(false);
+	pub fn partition(&mut self) -> usize {
+		let ones = self.count_ones();
+		let (front, back) = unsafe { self.split_at_unchecked_mut(ones) };
+		front.set_all(true);
+		back.set_all(false);
+		ones
 	}
 
-	/// Sets all bits in the slice to a value.
+	/// Reverses the order of bits within each `chunk`-bit group of the slice,
+	/// without changing the order of the groups.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `value`: The bit value to which all bits in the slice will be set.
+	/// - `chunk`: The width of each group. Groups are counted from index `0`.
+	///
+	/// # Behavior
+	///
+	/// Each group of [`.chunks_mut(chunk)`] is reversed in place. If
+	/// `self.len()` is not a multiple of `chunk`, the final group is shorter,
+	/// and is reversed within its own length.
+	///
+	/// When `chunk` is the width of `T`, `self` begins at the edge of an
+	/// element, and `O` is [`Lsb0`] or [`Msb0`], each whole element is reversed
+	/// with a single `.reverse_bits()` call.
+	///
+	/// # Panics
+	///
+	/// This panics if `chunk` is zero.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.view_bits_mut::<Msb0>();
-	/// bits[2 .. 6].set_all(true);
-	/// assert_eq!(bits.as_slice(), &[0b0011_1100]);
-	/// bits[3 .. 5].set_all(false);
-	/// assert_eq!(bits.as_slice(), &[0b0010_0100]);
-	/// bits[.. 1].set_all(true);
-	/// assert_eq!(bits.as_slice(), &[0b1010_0100]);
+	/// let bits = bits![mut 1, 1, 0, 0, 1, 0, 0, 0, 1, 0];
+	/// bits.reverse_each_chunk(4);
+	/// assert_eq!(bits[..], bits![0, 0, 1, 1, 0, 0, 0, 1, 0, 1]);
 	/// ```
-	pub fn set_all(&mut self, value: bool) {
-		//  Grab the function pointers used to commit bit-masks into memory.
-		let setter = <T::Access>::get_writers(value);
-		match self.domain_mut() {
-			DomainMut::Enclave { head, elem, tail } => {
-				setter(elem, O::mask(head, tail));
-			},
-			DomainMut::Region { head, body, tail } => {
-				if let Some((head, elem)) = head {
-					setter(elem, O::mask(head, None));
-				}
-				//  loop assignment is `memset`’s problem, not ours
-				unsafe {
-					ptr::write_bytes(
-						body.as_mut_ptr(),
-						[0, !0][value as usize],
-						body.len(),
-					);
-				}
-				if let Some((elem, tail)) = tail {
-					setter(elem, O::mask(None, tail));
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [`.chunks_mut(chunk)`]: Self::chunks_mut
+	pub fn reverse_each_chunk(&mut self, chunk: usize) {
+		assert!(chunk != 0, "Chunk width must be non-zero");
+		let width = T::Mem::BITS as usize;
+		let std_order = TypeId::of::<O>() == TypeId::of::<Lsb0>()
+			|| TypeId::of::<O>() == TypeId::of::<Msb0>();
+		let mut done = 0;
+		/* Both `Lsb0` and `Msb0` map index `i` and index `width - 1 - i` to
+		opposite ends of the element, so reversing a whole element reverses
+		its bit pattern.
+		*/
+		if chunk == width && std_order {
+			if let DomainMut::Region {
+				head: None, body, ..
+			} = self.domain_mut()
+			{
+				for elem in body.iter_mut() {
+					elem.store_value(elem.load_value().reverse_bits());
 				}
-			},
+				done = body.len() * width;
+			}
+		}
+		let rest = unsafe { self.get_unchecked_mut(done ..) };
+		for group in rest.chunks_mut(chunk) {
+			group.reverse();
 		}
 	}
 
@@ -1777,6 +3475,88 @@ where
 		elts.saturating_mul(T::Mem::BITS as isize)
 			.saturating_add(bits as isize)
 	}
+
+	/// Multiplies two elements of the binary field GF(2<sup>n</sup>).
+	///
+	/// Each slice is read as a polynomial over GF(2), with the bit at index
+	/// `i` holding the coefficient of x<sup>i</sup>. The product of `a` and
+	/// `b` is computed by carry-less (shift-and-XOR) multiplication, reduced
+	/// modulo `modulus`, and written into `out`.
+	///
+	/// # Parameters
+	///
+	/// - `a`: The multiplicand, as an `n`-bit polynomial.
+	/// - `b`: The multiplier, as an `n`-bit polynomial.
+	/// - `modulus`: The field polynomial, as an `n + 1`-bit polynomial. It
+	///   should be irreducible for the result to be a field product.
+	/// - `out`: The `n`-bit destination of the product.
+	///
+	/// # Panics
+	///
+	/// This panics if `a`, `b`, and `out` do not all have the same, non-zero,
+	/// length `n`, if `modulus.len()` is not `n + 1`, or if the x<sup>n</sup>
+	/// coefficient of `modulus` is not set.
+	///
+	/// # Examples
+	///
+	/// The AES field, GF(2<sup>8</sup>) modulo x<sup>8</sup> + x<sup>4</sup> +
+	/// x<sup>3</sup> + x + 1:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let poly = 0x11Bu16;
+	/// let modulus = &poly.view_bits::<Lsb0>()[.. 9];
+	///
+	/// let (a, b, mut c) = (0x57u8, 0x83u8, 0u8);
+	/// BitSlice::gf2_mul(
+	///   a.view_bits::<Lsb0>(),
+	///   b.view_bits::<Lsb0>(),
+	///   modulus,
+	///   c.view_bits_mut::<Lsb0>(),
+	/// );
+	/// assert_eq!(c, 0xC1);
+	/// ```
+	pub fn gf2_mul(a: &Self, b: &Self, modulus: &Self, out: &mut Self) {
+		let n = out.len();
+		assert!(n > 0, "Cannot multiply in a zero-width field");
+		assert!(
+			a.len() == n && b.len() == n,
+			"Field operands must have the same width: {}, {}, and {}",
+			a.len(),
+			b.len(),
+			n,
+		);
+		assert_eq!(
+			modulus.len(),
+			n + 1,
+			"Field modulus must be one bit wider than the field width {}",
+			n,
+		);
+		assert!(modulus[n], "Field modulus must have degree {}", n);
+
+		let reduce = &modulus[.. n];
+		out.set_all(false);
+		/* Horner’s method, from the highest coefficient of `b` down: each step
+		multiplies the accumulator by x, reduces it back into the field, and
+		then adds `a` if the current coefficient of `b` is set.
+		*/
+		for bit in b.iter().rev() {
+			let carry = out[n - 1];
+			if n > 1 {
+				out.shift_right(1);
+			}
+			else {
+				out.set(0, false);
+			}
+			if carry {
+				*out ^= reduce.iter().copied();
+			}
+			if *bit {
+				*out ^= a.iter().copied();
+			}
+		}
+	}
 }
 
 /// Unchecked variants of checked accessors.
@@ -1892,6 +3672,9 @@ where
 	///
 	/// See [`.swap()`].
 	///
+	/// The two bits may be in the same memory element or in different ones.
+	/// When they are equal, neither is written.
+	///
 	/// # Safety
 	///
 	/// `a` and `b` must both be less than [`self.len()`].
@@ -1901,6 +3684,9 @@ where
 	pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
 		let bit_a = *self.get_unchecked(a);
 		let bit_b = *self.get_unchecked(b);
+		if bit_a == bit_b {
+			return;
+		}
 		self.set_unchecked(a, bit_b);
 		self.set_unchecked(b, bit_a);
 	}
@@ -1982,6 +3768,10 @@ where
 	/// [`self.len()`]: Self::len
 	pub unsafe fn copy_within_unchecked<R>(&mut self, src: R, dest: usize)
 	where R: RangeBounds<usize> {
+		let src = dvl::normalize_range(src, self.len());
+		if self.copy_elements(src.clone(), dest) {
+			return;
+		}
 		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
 			let this: &mut BitSlice<Lsb0, T> = &mut *(self as *mut _ as *mut _);
 			this.sp_copy_within_unchecked(src, dest);
@@ -2044,54 +3834,461 @@ where
 		Domain::new(self)
 	}
 
-	/// Views the underlying memory containing the slice, split at alias
-	/// boundaries.
-	pub fn domain_mut(&mut self) -> DomainMut<T> {
-		DomainMut::new(self)
+	/// Views the underlying memory containing the slice, split at alias
+	/// boundaries.
+	pub fn domain_mut(&mut self) -> DomainMut<T> {
+		DomainMut::new(self)
+	}
+
+	/// Views the underlying memory containing the slice.
+	///
+	/// The returned slice handle views all elements touched by `self`, and
+	/// marks them all with `self`’s current aliasing state. For a more precise
+	/// view, or one that permits mutation, use [`.domain()`] or
+	/// [`.domain_mut()`].
+	///
+	/// [`.domain()`]: Self::domain
+	/// [`.domain_mut()`]: Self::domain_mut
+	pub fn as_slice(&self) -> &[T] {
+		let bitptr = self.bitptr();
+		let (base, elts) = (bitptr.pointer().to_const(), bitptr.elements());
+		unsafe { slice::from_raw_parts(base, elts) }
+	}
+
+	/// Views the memory elements underlying the slice.
+	///
+	/// This is the same view as [`.as_slice()`], named for its use in
+	/// handing the storage to element-oriented APIs such as [`std::io`] or
+	/// FFI.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of every memory element that `self` touches, marked with the
+	/// current aliasing state of `self`.
+	///
+	/// # Behavior
+	///
+	/// The first and last elements of the returned slice may be only
+	/// partially occupied by `self`. The bits in them that are outside `self`
+	/// are dead to this handle: they may belong to other [`BitSlice`]s, and
+	/// may change while the returned slice is alive if `T` is an aliased
+	/// type. Callers that need to treat the partial edges separately from the
+	/// fully occupied elements should use [`.domain()`], which splits them
+	/// apart.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let bits = &data.view_bits::<Msb0>()[4 .. 20];
+	///
+	/// //  The partial first and last bytes are included whole.
+	/// assert_eq!(bits.as_raw_slice(), &data);
+	///
+	/// let (head, body, tail) = bits.domain().region().unwrap();
+	/// assert_eq!(head.unwrap().1, &0x12);
+	/// assert_eq!(body, &[0x34]);
+	/// assert_eq!(tail.unwrap().0, &0x56);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`std::io`]: https://doc.rust-lang.org/stable/std/io
+	/// [`.as_slice()`]: Self::as_slice
+	/// [`.domain()`]: Self::domain
+	pub fn as_raw_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+
+	/// Views the memory elements underlying the slice, mutably.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable slice of every memory element that `self` touches, marked
+	/// with the current aliasing state of `self`. Writes to it may modify
+	/// bits in the first and last elements that are outside `self`.
+	///
+	/// # Safety
+	///
+	/// A `&mut BitSlice` is only exclusive over its own bits. When `T` is an
+	/// aliased type, such as the halves produced by [`.split_at_mut()`], the
+	/// first and last elements of `self` may also be referenced by other
+	/// handles. The caller must ensure that no other handle accesses those
+	/// elements while the returned slice is alive, and must not change any
+	/// bits that do not belong to `self`.
+	///
+	/// The fully occupied elements can be viewed mutably without these
+	/// restrictions through [`.domain_mut()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 2];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// unsafe { bits.as_raw_mut_slice() }[1] = 0xFFFF;
+	/// assert!(bits[16 ..].all());
+	/// ```
+	///
+	/// [`.domain_mut()`]: Self::domain_mut
+	/// [`.split_at_mut()`]: Self::split_at_mut
+	pub unsafe fn as_raw_mut_slice(&mut self) -> &mut [T] {
+		let bitptr = self.bitptr();
+		slice::from_raw_parts_mut(bitptr.pointer().to_mut(), bitptr.elements())
+	}
+}
+
+/// Crate-internal functions.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Type-cast the slice reference to its pointer structure.
+	pub(crate) fn bitptr(&self) -> BitPtr<O, T> {
+		self.as_bitptr().pipe(BitPtr::from_bitslice_ptr)
+	}
+
+	/// Asserts that `index` is less than [`self.len()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index to test against [`self.len()`].
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than `self.len()`.
+	///
+	/// [`self.len()`]: Self::len
+	pub(crate) fn assert_in_bounds(&self, index: usize) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+	}
+
+	/// Asserts that `index` is a valid argument to the rank functions.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is greater than `self.len()`.
+	pub(crate) fn assert_rank_index(&self, index: usize) {
+		let len = self.len();
+		assert!(index <= len, "Rank index out of range: {} > {}", index, len);
+	}
+
+	/// Finds the `rank`th bit in the slice equal to `value`.
+	///
+	/// The slice is walked one memory element at a time, and the population
+	/// count of each element is used to skip over it in a single step. Only
+	/// the element containing the requested bit is scanned bit by bit.
+	pub(crate) fn select_bit(
+		&self,
+		mut rank: usize,
+		value: bool,
+	) -> Option<usize>
+	{
+		let width = T::Mem::BITS as usize;
+		let len = self.len();
+		let head = self.bitptr().head().value() as usize;
+		let mut start = 0;
+		//  The first segment runs only to the end of the first element.
+		let mut end = cmp::min(len, width - head);
+		while start < len {
+			let seg = unsafe { self.get_unchecked(start .. end) };
+			let count = if value {
+				seg.count_ones()
+			}
+			else {
+				seg.count_zeros()
+			};
+			if rank < count {
+				let found = if value {
+					seg.iter_ones().nth(rank)
+				}
+				else {
+					seg.iter_zeros().nth(rank)
+				};
+				return found.map(|idx| start + idx);
+			}
+			rank -= count;
+			start = end;
+			end = cmp::min(len, end + width);
+		}
+		None
+	}
+
+	/// Counts the bits set to `1` in a range of the slice, by reading the
+	/// elements that hold it directly from the region pointer.
+	///
+	/// # Safety
+	///
+	/// `range` must be well-formed, and within `self`.
+	pub(crate) unsafe fn count_ones_unchecked(
+		&self,
+		range: Range<usize>,
+	) -> usize {
+		if range.start == range.end {
+			return 0;
+		}
+		let bitptr = self.bitptr();
+		let width = T::Mem::BITS as usize;
+		let head = bitptr.head().value() as usize;
+		let (from, upto) = (head + range.start, head + range.end);
+		let (first, last) = (from / width, (upto - 1) / width);
+		let from = BitIdx::<T::Mem>::new_unchecked((from % width) as u8);
+		let upto =
+			BitTail::<T::Mem>::new_unchecked(((upto - 1) % width + 1) as u8);
+
+		let base = bitptr.pointer().to_const();
+		let count = |idx: usize, mask: BitMask<T::Mem>| {
+			(mask & (&*base.add(idx)).load_value()).value().count_ones() as usize
+		};
+		if first == last {
+			return count(first, O::mask(from, upto));
+		}
+		count(first, O::mask(from, None))
+			+ (first + 1 .. last)
+				.map(|idx| count(idx, BitMask::ALL))
+				.sum::<usize>()
+			+ count(last, O::mask(None, upto))
+	}
+
+	/// Rotates the slice by whole memory elements, if it can.
+	///
+	/// This is possible only when the slice exactly covers its elements and
+	/// `by` is a multiple of their width. Every ordering maps each bit of a
+	/// whole element to the same element in the rotated slice, so moving the
+	/// elements moves their bits.
+	///
+	/// # Returns
+	///
+	/// Whether the rotation was performed. If this is `false`, the slice is
+	/// unchanged.
+	pub(crate) fn rotate_elements(&mut self, by: usize, left: bool) -> bool {
+		let width = T::Mem::BITS as usize;
+		if by % width != 0 {
+			return false;
+		}
+		if let DomainMut::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.domain_mut()
+		{
+			if left {
+				body.rotate_left(by / width);
+			}
+			else {
+				body.rotate_right(by / width);
+			}
+			return true;
+		}
+		false
+	}
+
+	/// Copies whole memory elements within the slice, if it can.
+	///
+	/// This is possible only when both ranges begin on element boundaries and
+	/// cover whole elements. Every ordering keeps each bit of a whole element
+	/// in that element, so moving the elements moves their bits. The ranges
+	/// may overlap.
+	///
+	/// # Returns
+	///
+	/// Whether the copy was performed. If this is `false`, the slice is
+	/// unchanged.
+	///
+	/// # Safety
+	///
+	/// `src` and `dest .. dest + src.len()` must be entirely within the slice.
+	pub(crate) unsafe fn copy_elements(
+		&mut self,
+		src: Range<usize>,
+		dest: usize,
+	) -> bool {
+		let width = T::Mem::BITS as usize;
+		let len = src.len();
+		if len == 0 || len % width != 0 || src.start % width != dest % width {
+			return false;
+		}
+		let start = cmp::min(src.start, dest);
+		let end = cmp::max(src.end, dest + len);
+		if let DomainMut::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.get_unchecked_mut(start .. end).domain_mut()
+		{
+			let base = body.as_mut_ptr();
+			ptr::copy(
+				base.add((src.start - start) / width),
+				base.add((dest - start) / width),
+				len / width,
+			);
+			return true;
+		}
+		false
+	}
+
+	/// Tests a relation between each pair of corresponding words of `self`
+	/// and `other`.
+	///
+	/// Both slices are walked in `usize`-wide chunks, with each chunk loaded
+	/// by [`.load_word()`]. This stops at the first pair for which `func`
+	/// returns `false`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// [`.load_word()`]: Self::load_word
+	pub(crate) fn all_words<O2, T2, F>(
+		&self,
+		other: &BitSlice<O2, T2>,
+		func: F,
+	) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		F: Fn(usize, usize) -> bool,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Set relations require slices of equal length"
+		);
+		let width = <usize as BitMemory>::BITS as usize;
+		self.chunks(width)
+			.zip(other.chunks(width))
+			.all(|(a, b)| func(a.load_word(), b.load_word()))
+	}
+
+	/// Loads up to one `usize` of bits into a word, with bit `n` of the slice
+	/// in bit `n` of the word.
+	///
+	/// This is only possible when `O` is one of the orderings for which
+	/// `BitSlice` implements `BitField`; for all other orderings, it returns
+	/// `None`.
+	pub(crate) fn try_load_word(&self) -> Option<usize> {
+		debug_assert!(
+			self.len() <= <usize as BitMemory>::BITS as usize,
+			"Cannot load {} bits into a word",
+			self.len(),
+		);
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			Some(this.sp_load_word())
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			Some(this.sp_load_word())
+		}
+		else {
+			None
+		}
 	}
 
-	/// Views the underlying memory containing the slice.
+	/// Loads up to one `usize` of bits into a word, with bit `n` of the slice
+	/// in bit `n` of the word.
 	///
-	/// The returned slice handle views all elements touched by `self`, and
-	/// marks them all with `self`’s current aliasing state. For a more precise
-	/// view, or one that permits mutation, use [`.domain()`] or
-	/// [`.domain_mut()`].
+	/// This uses [`.try_load_word()`] when it can, and otherwise collects the
+	/// bits one at a time.
 	///
-	/// [`.domain()`]: Self::domain
-	/// [`.domain_mut()`]: Self::domain_mut
-	pub fn as_slice(&self) -> &[T] {
-		let bitptr = self.bitptr();
-		let (base, elts) = (bitptr.pointer().to_const(), bitptr.elements());
-		unsafe { slice::from_raw_parts(base, elts) }
+	/// [`.try_load_word()`]: Self::try_load_word
+	pub(crate) fn load_word(&self) -> usize {
+		self.try_load_word().unwrap_or_else(|| {
+			self.iter()
+				.enumerate()
+				.fold(0, |word, (idx, bit)| word | (*bit as usize) << idx)
+		})
 	}
-}
 
-/// Crate-internal functions.
-impl<O, T> BitSlice<O, T>
-where
-	O: BitOrder,
-	T: BitStore,
-{
-	/// Type-cast the slice reference to its pointer structure.
-	pub(crate) fn bitptr(&self) -> BitPtr<O, T> {
-		self.as_bitptr().pipe(BitPtr::from_bitslice_ptr)
+	/// Stores up to one `usize` of bits from a word, with bit `n` of the word
+	/// written into bit `n` of the slice.
+	///
+	/// This is only possible when `O` is one of the orderings for which
+	/// `BitSlice` implements `BitField`; for all other orderings, it returns
+	/// `false` and does not modify the slice.
+	pub(crate) fn try_store_word(&mut self, word: usize) -> bool {
+		debug_assert!(
+			self.len() <= <usize as BitMemory>::BITS as usize,
+			"Cannot store {} bits from a word",
+			self.len(),
+		);
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &mut BitSlice<Lsb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			this.sp_store_word(word);
+			true
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &mut BitSlice<Msb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			this.sp_store_word(word);
+			true
+		}
+		else {
+			false
+		}
 	}
 
-	/// Asserts that `index` is less than [`self.len()`].
+	/// Stores up to one `usize` of bits from a word, with bit `n` of the word
+	/// written into bit `n` of the slice.
 	///
-	/// # Parameters
+	/// This uses [`.try_store_word()`] when it can, and otherwise writes the
+	/// bits one at a time.
 	///
-	/// - `&self`
-	/// - `index`: The index to test against [`self.len()`].
+	/// [`.try_store_word()`]: Self::try_store_word
+	pub(crate) fn store_word(&mut self, word: usize) {
+		if !self.try_store_word(word) {
+			self.for_each(|idx, _| word >> idx & 1 != 0);
+		}
+	}
+
+	/// Combines each pair of corresponding words of `self` and `other` into a
+	/// new bit-vector.
 	///
 	/// # Panics
 	///
-	/// This method panics if `index` is not less than `self.len()`.
-	///
-	/// [`self.len()`]: Self::len
-	pub(crate) fn assert_in_bounds(&self, index: usize) {
-		let len = self.len();
-		assert!(index < len, "Index out of range: {} >= {}", index, len);
+	/// This panics if `self` and `other` have different lengths.
+	#[cfg(feature = "alloc")]
+	pub(crate) fn combine_words<O2, T2, F>(
+		&self,
+		other: &BitSlice<O2, T2>,
+		func: F,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		F: Fn(usize, usize) -> usize,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Set operations require slices of equal length"
+		);
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut out = BitVec::repeat(false, self.len());
+		for ((a, b), dst) in self
+			.chunks(width)
+			.zip(other.chunks(width))
+			.zip(out.chunks_mut(width))
+		{
+			dst.store_word(func(a.load_word(), b.load_word()));
+		}
+		out
 	}
 
 	/// Marks an immutable slice as referring to aliased memory region.
@@ -2243,6 +4440,358 @@ where
 			BitVec::from_raw_parts(ptr as *mut BitSlice<O, T::Unalias>, capa)
 		}
 	}
+
+	/// Counts the set bits in each `chunk`-bit group of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `chunk`: The width of each group. Groups are counted from index `0`.
+	///
+	/// # Returns
+	///
+	/// A vector with one entry for each group of [`.chunks(chunk)`], holding
+	/// the number of bits in that group that are set to `1`. If `self.len()` is
+	/// not a multiple of `chunk`, the final group is shorter, and its count
+	/// covers only the bits it has.
+	///
+	/// When `chunk` is the width of `T` and `self` begins at the edge of an
+	/// element, each whole element is counted with a single `.count_ones()`
+	/// call.
+	///
+	/// # Panics
+	///
+	/// This panics if `chunk` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0, 1, 0, 1, 1, 1, 1];
+	/// assert_eq!(bits.popcount_chunks(4), vec![2, 3, 2]);
+	/// ```
+	///
+	/// [`.chunks(chunk)`]: Self::chunks
+	pub fn popcount_chunks(&self, chunk: usize) -> alloc::vec::Vec<u32> {
+		assert!(chunk != 0, "Chunk width must be non-zero");
+		let mut out = alloc::vec::Vec::with_capacity(
+			self.len() / chunk + (self.len() % chunk != 0) as usize,
+		);
+		let width = T::Mem::BITS as usize;
+		let mut done = 0;
+		if chunk == width {
+			if let Domain::Region {
+				head: None, body, ..
+			} = self.domain()
+			{
+				out.extend(
+					body.iter().map(|elem| elem.load_value().count_ones()),
+				);
+				done = body.len() * width;
+			}
+		}
+		let rest = unsafe { self.get_unchecked(done ..) };
+		out.extend(rest.chunks(chunk).map(|group| group.count_ones() as u32));
+		out
+	}
+
+	/// Finds the ranges of indices at which two slices hold different bits.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice to compare against.
+	///
+	/// # Returns
+	///
+	/// A `(start, end)` pair for each maximal run of indices at which
+	/// `self[idx] != other[idx]`, in ascending order. `start` is the first
+	/// differing index of the run, and `end` is one past the last. The runs
+	/// never touch or overlap, and equal slices produce an empty vector.
+	///
+	/// # Behavior
+	///
+	/// The slices are walked in `usize`-wide chunks. When both slices use
+	/// `Lsb0` or `Msb0` ordering, each pair of chunks is loaded as integers
+	/// and XORed, and the runs of set bits in the XOR are found with
+	/// `trailing_zeros` and `trailing_ones`. Other orderings compare the
+	/// chunks bit by bit.
+	///
+	/// # Panics
+	///
+	/// This panics if the slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1, 0, 0, 0, 1, 1, 0];
+	/// assert_eq!(a.diff(b), vec![(1, 3), (6, 8), (9, 10)]);
+	/// ```
+	pub fn diff<O2, T2>(
+		&self,
+		other: &BitSlice<O2, T2>,
+	) -> alloc::vec::Vec<(usize, usize)>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Diffing requires slices of equal length"
+		);
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut runs = alloc::vec::Vec::new();
+		//  The start of a run that has not yet reached its end.
+		let mut open = None;
+		for (base, (a, b)) in self
+			.chunks(width)
+			.zip(other.chunks(width))
+			.enumerate()
+			.map(|(idx, pair)| (idx * width, pair))
+		{
+			let word = match (a.try_load_word(), b.try_load_word()) {
+				(Some(a), Some(b)) => a ^ b,
+				_ => a
+					.iter()
+					.zip(b.iter())
+					.enumerate()
+					.filter(|(_, (x, y))| x != y)
+					.fold(0, |word, (idx, _)| word | 1 << idx),
+			};
+			let len = a.len();
+			let mut pos = 0;
+			while pos < len {
+				match open {
+					Some(start) => {
+						pos += (word >> pos).trailing_ones() as usize;
+						if pos < len {
+							runs.push((start, base + pos));
+							open = None;
+						}
+					},
+					None => {
+						pos += (word >> pos).trailing_zeros() as usize;
+						if pos < len {
+							open = Some(base + pos);
+						}
+					},
+				}
+			}
+		}
+		if let Some(start) = open {
+			runs.push((start, self.len()));
+		}
+		runs
+	}
+
+	/// Encodes the slice as a sequence of alternating run lengths.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The lengths of the maximal runs of identical bits in the slice, in
+	/// order. The first entry counts the leading `0` bits, and is `0` if the
+	/// slice begins with a `1`; after that, entries alternate between runs of
+	/// `1`s and runs of `0`s. An empty slice produces an empty vector.
+	///
+	/// A run longer than `u32::MAX` is written as `u32::MAX`, then `0` for an
+	/// empty run of the opposite bit, then the rest of its length, so that
+	/// the alternation is preserved.
+	///
+	/// The runs are measured with [`.leading_run()`]. The encoding can be
+	/// reversed with [`BitVec::rle_decode`], with a `start_bit` of `false`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// assert_eq!(bits.rle_encode(), vec![2, 3, 1, 1]);
+	///
+	/// let bits = bits![1, 1, 0];
+	/// assert_eq!(bits.rle_encode(), vec![0, 2, 1]);
+	/// ```
+	///
+	/// [`BitVec::rle_decode`]: crate::vec::BitVec::rle_decode
+	/// [`.leading_run()`]: Self::leading_run
+	pub fn rle_encode(&self) -> alloc::vec::Vec<u32> {
+		let mut out = alloc::vec::Vec::new();
+		let mut rest = self;
+		while let Some((value, mut run)) = rest.leading_run() {
+			if value && out.is_empty() {
+				out.push(0);
+			}
+			rest = unsafe { rest.get_unchecked(run ..) };
+			while run > u32::MAX as usize {
+				out.push(u32::MAX);
+				out.push(0);
+				run -= u32::MAX as usize;
+			}
+			out.push(run as u32);
+		}
+		out
+	}
+
+	/// Computes the union of two equal-length bit-slices.
+	///
+	/// Treating each slice as a set of the indices of its set bits, this is
+	/// the set of indices that are set in either slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// A new bit-vector holding `self | other`. The memory elements of the
+	/// bit-vector have every bit outside its live region cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0];
+	/// assert_eq!(bits.union(bits![1, 0, 1, 0]), bits![1, 1, 1, 0]);
+	/// ```
+	pub fn union<O2, T2>(
+		&self,
+		other: &BitSlice<O2, T2>,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.combine_words(other, |a, b| a | b)
+	}
+
+	/// Computes the intersection of two equal-length bit-slices.
+	///
+	/// This is the set of indices that are set in both slices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// A new bit-vector holding `self & other`. The memory elements of the
+	/// bit-vector have every bit outside its live region cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0];
+	/// assert_eq!(bits.intersection(bits![1, 0, 1, 0]), bits![1, 0, 0, 0]);
+	/// ```
+	pub fn intersection<O2, T2>(
+		&self,
+		other: &BitSlice<O2, T2>,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.combine_words(other, |a, b| a & b)
+	}
+
+	/// Computes the difference of two equal-length bit-slices.
+	///
+	/// This is the set of indices that are set in `self` but not in `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// A new bit-vector holding `self & !other`. The memory elements of the
+	/// bit-vector have every bit outside its live region cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0];
+	/// assert_eq!(bits.difference(bits![1, 0, 1, 0]), bits![0, 1, 0, 0]);
+	/// ```
+	pub fn difference<O2, T2>(
+		&self,
+		other: &BitSlice<O2, T2>,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.combine_words(other, |a, b| a & !b)
+	}
+
+	/// Computes the symmetric difference of two equal-length bit-slices.
+	///
+	/// This is the set of indices that are set in exactly one of the slices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Returns
+	///
+	/// A new bit-vector holding `self ^ other`. The memory elements of the
+	/// bit-vector have every bit outside its live region cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0];
+	/// assert_eq!(
+	///   bits.symmetric_difference(bits![1, 0, 1, 0]),
+	///   bits![0, 1, 1, 0],
+	/// );
+	/// ```
+	pub fn symmetric_difference<O2, T2>(
+		&self,
+		other: &BitSlice<O2, T2>,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.combine_words(other, |a, b| a ^ b)
+	}
 }
 
 /** Constructs a [`BitSlice`] reference from its component data.
@@ -2372,9 +4921,14 @@ where
 }
 
 mod api;
+mod groups;
 mod iter;
 mod ops;
+mod parallel;
+mod popcount;
 mod proxy;
+mod random;
+mod rank;
 mod specialization;
 mod traits;
 
@@ -2388,6 +4942,7 @@ pub use self::{
 		from_ref,
 		BitSliceIndex,
 	},
+	groups::DisplayGroups,
 	iter::{
 		Chunks,
 		ChunksExact,
@@ -2396,7 +4951,9 @@ pub use self::{
 		Iter,
 		IterMut,
 		IterOnes,
+		IterRuns,
 		IterZeros,
+		MatchIndices,
 		RChunks,
 		RChunksExact,
 		RChunksExactMut,
@@ -2414,5 +4971,8 @@ pub use self::{
 	proxy::BitMut,
 };
 
+#[cfg(feature = "alloc")]
+pub use self::rank::RankIndex;
+
 #[cfg(test)]
 mod tests;
@@ -619,6 +619,25 @@ where
 	/// v.swap(1, 3);
 	/// assert_eq!(v, bits![0, 0, 1, 1]);
 	/// ```
+	///
+	/// A Fisher–Yates shuffle, driven by any source of indices:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+	/// let mut seed = 7usize;
+	/// for idx in (1 .. bits.len()).rev() {
+	///   seed = seed.wrapping_mul(31).wrapping_add(17);
+	///   bits.swap(idx, seed % (idx + 1));
+	/// }
+	/// assert_eq!(bits.count_ones(), 3);
+	/// ```
+	///
+	/// [`.swap_unchecked()`] skips the bounds checks, for loops that have
+	/// already performed them.
+	///
+	/// [`.swap_unchecked()`]: Self::swap_unchecked
 	pub fn swap(&mut self, a: usize, b: usize) {
 		self.assert_in_bounds(a);
 		self.assert_in_bounds(b);
@@ -739,6 +758,10 @@ where
 	/// windows overlap. If the slice is shorter than `size`, the iterator
 	/// returns no values.
 	///
+	/// Each window is a view of the same memory as `self`, starting one bit
+	/// after the previous window, and nothing is copied. The windows borrow
+	/// `self`, so it cannot be modified while any of them are alive.
+	///
 	/// # Original
 	///
 	/// [`slice::windows`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.windows)
@@ -769,6 +792,16 @@ where
 	/// let mut iter = slice.windows(4);
 	/// assert!(iter.next().is_none());
 	/// ```
+	///
+	/// Searching a bitstream for a pattern:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let stream = bits![0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0];
+	/// let sync = bits![0, 1, 1, 1, 0];
+	/// assert_eq!(stream.windows(sync.len()).position(|w| w == sync), Some(3));
+	/// ```
 	pub fn windows(&self, size: usize) -> Windows<O, T> {
 		assert_ne!(size, 0, "Window width cannot be 0");
 		Windows::new(self, size)
@@ -1641,6 +1674,9 @@ where
 	/// optimized searchers for any `true` or `false` bit, respectively, in a
 	/// sequence.
 	///
+	/// This is [`.find()`]`.is_some()`; use that method to learn where the
+	/// subslice occurs.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -1657,17 +1693,14 @@ where
 	/// slice being searched.
 	///
 	/// [`any`]: Self::any
+	/// [`.find()`]: Self::find
 	/// [`not_all`]: Self::not_all
 	pub fn contains<O2, T2>(&self, x: &BitSlice<O2, T2>) -> bool
 	where
 		O2: BitOrder,
 		T2: BitStore,
 	{
-		let len = x.len();
-		if len > self.len() {
-			return false;
-		};
-		self.windows(len).any(|s| s == x)
+		self.find(x).is_some()
 	}
 
 	/// Returns `true` if `needle` is a prefix of the slice.
@@ -1754,14 +1787,17 @@ where
 	///
 	/// [`slice::rotate_left`](https://doc.rust-lang.org/stable/std/primitive.slice.html#rotate_left)
 	///
-	/// # Panics
+	/// # API Differences
 	///
-	/// This function will panic if `by` is greater than the length of the
-	/// slice. Note that `by == self.len()` does *not* panic and is a noöp.
+	/// The standard library panics if `by` is greater than the length of the
+	/// slice. This instead reduces `by` modulo [`self.len()`], so rotating by
+	/// any multiple of the length is a noöp.
 	///
 	/// # Complexity
 	///
-	/// Takes linear (in [`self.len()`]) time.
+	/// Takes linear (in [`self.len()`]) time. When the slice exactly covers
+	/// its memory elements, and `by` is a multiple of the element width, the
+	/// elements themselves are rotated, as with [`slice::rotate_left`].
 	///
 	/// # Examples
 	///
@@ -1771,6 +1807,8 @@ where
 	/// let a = bits![mut 0, 0, 1, 0, 1, 0];
 	/// a.rotate_left(2);
 	/// assert_eq!(a, bits![1, 0, 1, 0, 0, 0]);
+	/// a.rotate_left(8);
+	/// assert_eq!(a, bits![1, 0, 0, 0, 1, 0]);
 	/// ```
 	///
 	/// Rotating a subslice:
@@ -1784,13 +1822,14 @@ where
 	/// ```
 	///
 	/// [`self.len()`]: Self::len
+	/// [`slice::rotate_left`]: https://doc.rust-lang.org/stable/std/primitive.slice.html#method.rotate_left
 	pub fn rotate_left(&mut self, mut by: usize) {
 		let len = self.len();
-		assert!(
-			by <= len,
-			"Slices cannot be rotated by more than their length"
-		);
-		if by == 0 || by == len {
+		if len == 0 {
+			return;
+		}
+		by %= len;
+		if by == 0 || self.rotate_elements(by, true) {
 			return;
 		}
 		/* The standard one-element-at-a-time algorithm is necessary for `[T]`
@@ -1828,14 +1867,17 @@ where
 	///
 	/// [`slice::rotate_right`](https://doc.rust-lang.org/stable/std/primitive.slice.html#rotate_right)
 	///
-	/// # Panics
+	/// # API Differences
 	///
-	/// This function will panic if `by` is greater than the length of the
-	/// slice. Note that `by == self.len()` does *not* panic and is a noöp.
+	/// The standard library panics if `by` is greater than the length of the
+	/// slice. This instead reduces `by` modulo [`self.len()`], so rotating by
+	/// any multiple of the length is a noöp.
 	///
 	/// # Complexity
 	///
-	/// Takes linear (in [`self.len()`]) time.
+	/// Takes linear (in [`self.len()`]) time. When the slice exactly covers
+	/// its memory elements, and `by` is a multiple of the element width, the
+	/// elements themselves are rotated, as with [`slice::rotate_right`].
 	///
 	/// # Examples
 	///
@@ -1845,6 +1887,8 @@ where
 	/// let a = bits![mut 0, 0, 1, 1, 1, 0];
 	/// a.rotate_right(2);
 	/// assert_eq!(a, bits![1, 0, 0, 0, 1, 1]);
+	/// a.rotate_right(13);
+	/// assert_eq!(a, bits![1, 1, 0, 0, 0, 1]);
 	/// ```
 	///
 	/// Rotating a subslice:
@@ -1858,13 +1902,14 @@ where
 	/// ```
 	///
 	/// [`self.len()`]: Self::len
+	/// [`slice::rotate_right`]: https://doc.rust-lang.org/stable/std/primitive.slice.html#method.rotate_right
 	pub fn rotate_right(&mut self, mut by: usize) {
 		let len = self.len();
-		assert!(
-			by <= len,
-			"Slices cannot be rotated by more than their length"
-		);
-		if by == 0 || by == len {
+		if len == 0 {
+			return;
+		}
+		by %= len;
+		if by == 0 || self.rotate_elements(by, false) {
 			return;
 		}
 		let mut tmp = BitArray::<O, usize>::zeroed();
@@ -1922,6 +1967,15 @@ where
 	/// This function will panic if either range exceeds the end of the slice,
 	/// or if the end of `src` is before the start.
 	///
+	/// # Behavior
+	///
+	/// When both ranges begin on memory-element boundaries and span whole
+	/// elements, the elements are moved directly, as `<[T]>::copy_within`
+	/// would. Otherwise, when `O` is `Lsb0` or `Msb0`, the bits are moved a
+	/// `usize` at a time, and for other orderings, one bit at a time. In all
+	/// cases, overlapping ranges are copied in the direction that reads each
+	/// source bit before it is overwritten.
+	///
 	/// # Examples
 	///
 	/// Copying four bits within a slice:
@@ -2027,9 +2081,24 @@ where
 	/// }
 	/// ```
 	///
+	/// The middle slice always fully spans its elements, so it can be viewed
+	/// as a plain slice of `U` with [`.as_raw_slice()`], for handoff to APIs
+	/// that work in wider elements:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bytes = [0u8; 64];
+	/// let bits = &bytes.view_bits::<Lsb0>()[3 ..];
+	/// let (head, words, tail) = unsafe { bits.align_to::<u32>() };
+	/// assert_eq!(words.len(), words.as_raw_slice().len() * 32);
+	/// assert_eq!(head.len() + words.len() + tail.len(), bits.len());
+	/// ```
+	///
 	/// [mt]: core::mem::transmute
 	/// [`BitStore`]: crate::store::BitStore
 	/// [`Domain`]: crate::domain::Domain
+	/// [`.as_raw_slice()`]: Self::as_raw_slice
 	/// [`slice::align_to`]: https://doc.rust-lang.org/stable/std/primitive.slice.html#method.align_to
 	/// [`::Alias`]: crate::store::BitStore::Alias
 	pub unsafe fn align_to<U>(&self) -> (&Self, &BitSlice<O, U>, &Self)
@@ -2139,6 +2208,15 @@ where
 	///
 	/// [`slice::repeat`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.repeat)
 	///
+	/// # Behavior
+	///
+	/// The vector is allocated once, at its final size. `self` is copied into
+	/// it once, and then the vector doubles by copying its own contents onto
+	/// its end with [`BitVec::extend_from_within`], so the pattern is copied
+	/// `log2(n)` times rather than `n` times. The copies need not begin at an
+	/// element boundary when `self.len()` is not a multiple of the element
+	/// width.
+	///
 	/// # Panics
 	///
 	/// This function will panic if the capacity would overflow.
@@ -2161,17 +2239,20 @@ where
 	/// // this will panic at runtime
 	/// bits![0, 1].repeat(BitSlice::<LocalBits, usize>::MAX_BITS);
 	/// ```
+	///
+	/// [`BitVec::extend_from_within`]: crate::vec::BitVec::extend_from_within
 	pub fn repeat(&self, n: usize) -> BitVec<O, T::Mem> {
 		let len = self.len();
 		let total = len.checked_mul(n).expect("capacity overflow");
 
-		//  The memory has to be initialized before `.clone_from_bitslice` can
-		//  write into it.
-		let mut out = BitVec::repeat(false, total);
-
-		for chunk in unsafe { out.chunks_exact_mut(len).remove_alias() } {
-			//  TODO(myrrlyn): Specialize for `BitField` access
-			chunk.clone_from_bitslice(self);
+		let mut out = BitVec::with_capacity(total);
+		if total == 0 {
+			return out;
+		}
+		out.extend_from_bitslice(self);
+		while out.len() < total {
+			let step = cmp::min(out.len(), total - out.len());
+			out.extend_from_within(.. step);
 		}
 
 		out
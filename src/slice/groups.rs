@@ -0,0 +1,190 @@
+/*! Grouped text rendering.
+
+The [`Binary`] and hexadecimal implementations on [`BitSlice`] render one word
+per memory element. [`DisplayGroups`] instead renders the slice as a single run
+of digits in index order, with a separator between fixed-width groups of bits,
+which is easier to read when inspecting wide bitmaps.
+
+[`Binary`]: core::fmt::Binary
+[`BitSlice`]: crate::slice::BitSlice
+[`DisplayGroups`]: self::DisplayGroups
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+	LowerHex,
+	UpperHex,
+	Write,
+};
+
+/// Grouped formatting.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates an adaptor that renders the slice in groups of `chunk` bits.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `chunk`: The number of bits in each group.
+	/// - `sep`: The character written between adjacent groups.
+	///
+	/// # Returns
+	///
+	/// An adaptor whose [`Display`] implementation writes each bit as `0` or
+	/// `1`, and whose [`LowerHex`] and [`UpperHex`] implementations write each
+	/// four bits as a hexadecimal digit. Both render the slice in index order,
+	/// with `sep` between groups, and write directly into the formatter
+	/// without allocating.
+	///
+	/// # Panics
+	///
+	/// This panics if `chunk` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![Msb0, u8; 1, 0, 1, 0, 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(
+	///   format!("{}", bits.display_groups(4, '_')),
+	///   "1010_1100_11",
+	/// );
+	/// assert_eq!(format!("{:x}", bits.display_groups(8, ' ')), "ac c");
+	/// ```
+	///
+	/// [`Display`]: core::fmt::Display
+	/// [`LowerHex`]: core::fmt::LowerHex
+	/// [`UpperHex`]: core::fmt::UpperHex
+	pub fn display_groups(
+		&self,
+		chunk: usize,
+		sep: char,
+	) -> DisplayGroups<O, T> {
+		assert!(chunk != 0, "Display groups cannot be empty");
+		DisplayGroups {
+			bits: self,
+			chunk,
+			sep,
+		}
+	}
+}
+
+/** Renders a [`BitSlice`] as text, in groups of bits.
+
+This is created by [`BitSlice::display_groups`].
+
+The [`Display`] implementation writes each bit as `0` or `1`, in index order.
+
+The [`LowerHex`] and [`UpperHex`] implementations write each four bits, counted
+from the start of the slice, as one hexadecimal digit. The first bit of the
+four is the most significant bit of the digit. When the length of the slice is
+not a multiple of four, the final digit is filled out with zeros in its least
+significant bits. Each group holds as many digits as are needed to cover its
+bits, so the group width should be a multiple of four for the separators to fall
+on the same bit boundaries as in the binary rendering.
+
+All three write `sep` between adjacent groups, and write the radix prefix first
+when the alternate flag (`#`) is set.
+
+# Lifetimes
+
+- `'a`: The lifetime of the rendered slice.
+
+# Type Parameters
+
+- `O`: The ordering of the rendered slice.
+- `T`: The storage type of the rendered slice.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::display_groups`]: crate::slice::BitSlice::display_groups
+[`Display`]: core::fmt::Display
+[`LowerHex`]: core::fmt::LowerHex
+[`UpperHex`]: core::fmt::UpperHex
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayGroups<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The slice being rendered.
+	bits: &'a BitSlice<O, T>,
+	/// The number of bits in each group.
+	chunk: usize,
+	/// The separator written between groups.
+	sep: char,
+}
+
+impl<O, T> DisplayGroups<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Writes the slice as hexadecimal digits drawn from `glyphs`.
+	fn hex(&self, fmt: &mut Formatter, glyphs: &[u8; 16]) -> fmt::Result {
+		if fmt.alternate() {
+			fmt.write_str("0x")?;
+		}
+		let digits = (self.chunk + 3) / 4;
+		for (idx, nibble) in self.bits.chunks(4).enumerate() {
+			if idx != 0 && idx % digits == 0 {
+				fmt.write_char(self.sep)?;
+			}
+			let val = nibble.iter().fold(0u8, |val, bit| val << 1 | *bit as u8)
+				<< (4 - nibble.len());
+			fmt.write_char(glyphs[val as usize] as char)?;
+		}
+		Ok(())
+	}
+}
+
+impl<O, T> Display for DisplayGroups<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		if fmt.alternate() {
+			fmt.write_str("0b")?;
+		}
+		for (idx, bit) in self.bits.iter().enumerate() {
+			if idx != 0 && idx % self.chunk == 0 {
+				fmt.write_char(self.sep)?;
+			}
+			fmt.write_char(if *bit { '1' } else { '0' })?;
+		}
+		Ok(())
+	}
+}
+
+impl<O, T> LowerHex for DisplayGroups<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.hex(fmt, b"0123456789abcdef")
+	}
+}
+
+impl<O, T> UpperHex for DisplayGroups<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.hex(fmt, b"0123456789ABCDEF")
+	}
+}
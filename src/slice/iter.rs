@@ -1068,7 +1068,9 @@ group!(ChunksExact => &'a BitSlice<O, T> {
 
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		let (start, ovf) = n.overflowing_mul(self.width);
-		if start + self.width >= self.slice.len() || ovf {
+		//  The slice length is a multiple of the width, so any start point
+		//  inside it has a full chunk after it.
+		if ovf || start >= self.slice.len() {
 			self.slice = Default::default();
 			return None;
 		}
@@ -1203,7 +1205,7 @@ group!(ChunksExactMut => &'a mut BitSlice<O, T::Alias> {
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		let slice = mem::take(&mut self.slice);
 		let (start, ovf) = n.overflowing_mul(self.width);
-		if start + self.width >= slice.len() || ovf {
+		if ovf || start >= slice.len() {
 			return None;
 		}
 		let (out, rest) = unsafe {
@@ -2324,6 +2326,10 @@ split_n!(RSplitNMut => RSplitMut => &'a mut BitSlice<O, T::Alias> );
 
 This struct is created by the [`.iter_ones()`] method on [`BitSlice`]s.
 
+Each step finds the next `1` bit by measuring the run of opposite bits before
+it, which examines a whole `usize` of the slice at a time when the ordering is
+`Lsb0` or `Msb0`. Sparse slices therefore skip over empty words quickly.
+
 [`BitSlice`]: crate::slice::BitSlice
 [`.iter_ones()`]: crate::slice::BitSlice::iter_ones
 **/
@@ -2373,7 +2379,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| b) {
+		match first_bit(self.inner, true) {
 			Some(n) => {
 				//  Split on the far side of the found index. This is always
 				//  safe, as split(len) yields (self, empty).
@@ -2411,7 +2417,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| b) {
+		match last_bit(self.inner, true) {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2446,6 +2452,10 @@ where
 
 This struct is created by the [`.iter_zeros()`] method on [`BitSlice`]s.
 
+Each step finds the next `0` bit by measuring the run of opposite bits before
+it, which examines a whole `usize` of the slice at a time when the ordering is
+`Lsb0` or `Msb0`. Sparse slices therefore skip over empty words quickly.
+
 [`BitSlice`]: crate::slice::BitSlice
 [`.iter_zeros()`]: crate::slice::BitSlice::iter_zeros
 **/
@@ -2495,7 +2505,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| !b) {
+		match first_bit(self.inner, false) {
 			Some(n) => {
 				let (_, rest) = unsafe { self.inner.split_at_unchecked(n + 1) };
 				self.inner = rest;
@@ -2530,7 +2540,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| !b) {
+		match last_bit(self.inner, false) {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2561,6 +2571,181 @@ where
 {
 }
 
+/** Enumerates the runs of identical bits in a [`BitSlice`].
+
+This struct is created by the [`.iter_runs()`] method on [`BitSlice`]s.
+
+Each run is measured with [`.leading_run()`] or [`.trailing_run()`], which
+examine a whole `usize` of the slice at a time when the ordering is `Lsb0` or
+`Msb0`, so long runs are skipped quickly, including across element boundaries.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_runs()`]: crate::slice::BitSlice::iter_runs
+[`.leading_run()`]: crate::slice::BitSlice::leading_run
+[`.trailing_run()`]: crate::slice::BitSlice::trailing_run
+**/
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining slice whose runs are to be measured.
+	inner: &'a BitSlice<O, T>,
+}
+
+impl<'a, O, T> IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(crate) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self { inner: slice }
+	}
+}
+
+impl<O, T> Default for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+		}
+	}
+}
+
+impl<O, T> Iterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = (bool, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (value, run) = self.inner.leading_run()?;
+		self.inner = unsafe { self.inner.get_unchecked(run ..) };
+		Some((value, run))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		//  A non-empty slice has at least one run, and at most one per bit.
+		let len = self.inner.len();
+		(cmp::min(len, 1), Some(len))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (value, run) = self.inner.trailing_run()?;
+		let len = self.inner.len();
+		self.inner = unsafe { self.inner.get_unchecked(.. len - run) };
+		Some((value, run))
+	}
+}
+
+impl<O, T> FusedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Enumerates every index in a [`BitSlice`] at which a pattern begins.
+
+This struct is created by the [`.match_indices()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.match_indices()`]: crate::slice::BitSlice::match_indices
+**/
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// The slice being searched.
+	haystack: &'a BitSlice<O1, T1>,
+	/// The pattern being sought.
+	needle: &'a BitSlice<O2, T2>,
+	/// The next index in `haystack` at which to test for `needle`.
+	front: usize,
+}
+
+impl<'a, O1, T1, O2, T2> MatchIndices<'a, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	pub(crate) fn new(
+		haystack: &'a BitSlice<O1, T1>,
+		needle: &'a BitSlice<O2, T2>,
+	) -> Self
+	{
+		Self {
+			haystack,
+			needle,
+			front: 0,
+		}
+	}
+}
+
+impl<O1, T1, O2, T2> Iterator for MatchIndices<'_, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let width = self.needle.len();
+		let len = self.haystack.len();
+		//  Each candidate advances by one bit, so overlapping matches are
+		//  found. An empty needle matches at every index, including `len`.
+		while self.front + width <= len {
+			let here = self.front;
+			self.front += 1;
+			let window =
+				unsafe { self.haystack.get_unchecked(here .. here + width) };
+			if window == self.needle {
+				return Some(here);
+			}
+		}
+		self.front = len + 1;
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.haystack.len();
+		let width = self.needle.len();
+		(0, Some((len + 1).saturating_sub(self.front + width)))
+	}
+}
+
+impl<O1, T1, O2, T2> FusedIterator for MatchIndices<'_, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -2776,3 +2961,40 @@ noalias! {
 	=> RSplitNMutNoAlias => &'a mut BitSlice<O, T>
 	=> BitSlice::unalias_mut;
 }
+
+/// Finds the first bit in a slice that is equal to `value`.
+///
+/// This uses [`.leading_run()`], which examines the slice a word at a time
+/// when it can.
+///
+/// [`.leading_run()`]: crate::slice::BitSlice::leading_run
+fn first_bit<O, T>(bits: &BitSlice<O, T>, value: bool) -> Option<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	match bits.leading_run()? {
+		(bit, _) if bit == value => Some(0),
+		(_, run) if run < bits.len() => Some(run),
+		_ => None,
+	}
+}
+
+/// Finds the last bit in a slice that is equal to `value`.
+///
+/// This uses [`.trailing_run()`], which examines the slice a word at a time
+/// when it can.
+///
+/// [`.trailing_run()`]: crate::slice::BitSlice::trailing_run
+fn last_bit<O, T>(bits: &BitSlice<O, T>, value: bool) -> Option<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let last = bits.len().checked_sub(1)?;
+	match bits.trailing_run()? {
+		(bit, _) if bit == value => Some(last),
+		(_, run) if run < bits.len() => Some(last - run),
+		_ => None,
+	}
+}
@@ -2,7 +2,15 @@
 
 use crate::{
 	access::BitAccess,
-	domain::DomainMut,
+	domain::{
+		Domain,
+		DomainMut,
+	},
+	index::BitMask,
+	mem::{
+		BitMemory,
+		BitRegister,
+	},
 	order::BitOrder,
 	slice::{
 		BitSlice,
@@ -11,19 +19,22 @@ use crate::{
 	store::BitStore,
 };
 
-use core::ops::{
-	BitAndAssign,
-	BitOrAssign,
-	BitXorAssign,
-	Index,
-	IndexMut,
-	Not,
-	Range,
-	RangeFrom,
-	RangeFull,
-	RangeInclusive,
-	RangeTo,
-	RangeToInclusive,
+use core::{
+	any::TypeId,
+	ops::{
+		BitAndAssign,
+		BitOrAssign,
+		BitXorAssign,
+		Index,
+		IndexMut,
+		Not,
+		Range,
+		RangeFrom,
+		RangeFull,
+		RangeInclusive,
+		RangeTo,
+		RangeToInclusive,
+	},
 };
 
 impl<O, T, Rhs> BitAndAssign<Rhs> for BitSlice<O, T>
@@ -136,6 +147,230 @@ index!(
 	RangeToInclusive<usize>,
 );
 
+/// In-place bitwise operations against other bit-slices.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Sets each bit of `self` to the `AND` of itself and the bit at the same
+	/// index in `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: Another bit-slice of the same length.
+	///
+	/// # Behavior
+	///
+	/// When `other` has the same type parameters as `self`, and begins at the
+	/// same bit of its first element, the two slices are combined a memory
+	/// element at a time. Otherwise, when `O` and `O2` are `Lsb0` or `Msb0`,
+	/// they are combined a `usize` at a time, and for other orderings, one
+	/// bit at a time. The [`BitAndAssign`] implementation, which accepts any
+	/// iterator of `bool`, always proceeds one bit at a time.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0];
+	/// bits.and_assign(bits![1, 0, 1, 0]);
+	/// assert_eq!(bits, bits![1, 0, 0, 0]);
+	/// ```
+	///
+	/// [`BitAndAssign`]: core::ops::BitAndAssign
+	pub fn and_assign<O2, T2>(&mut self, other: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.assign_words(other, Bitwise::And);
+	}
+
+	/// Sets each bit of `self` to the `OR` of itself and the bit at the same
+	/// index in `other`.
+	///
+	/// This proceeds in the same manner as [`.and_assign()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0];
+	/// bits.or_assign(bits![1, 0, 1, 0]);
+	/// assert_eq!(bits, bits![1, 1, 1, 0]);
+	/// ```
+	///
+	/// [`.and_assign()`]: Self::and_assign
+	pub fn or_assign<O2, T2>(&mut self, other: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.assign_words(other, Bitwise::Or);
+	}
+
+	/// Sets each bit of `self` to the `XOR` of itself and the bit at the same
+	/// index in `other`.
+	///
+	/// This proceeds in the same manner as [`.and_assign()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0];
+	/// bits.xor_assign(bits![1, 0, 1, 0]);
+	/// assert_eq!(bits, bits![0, 1, 1, 0]);
+	/// ```
+	///
+	/// [`.and_assign()`]: Self::and_assign
+	pub fn xor_assign<O2, T2>(&mut self, other: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.assign_words(other, Bitwise::Xor);
+	}
+
+	/// Inverts each bit of `self`.
+	///
+	/// This is the [`Not`] implementation on `&mut BitSlice`, as a method
+	/// that does not consume the reference. It inverts a memory element at a
+	/// time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0];
+	/// bits.not_assign();
+	/// assert_eq!(bits, bits![0, 0, 1, 1]);
+	/// ```
+	///
+	/// [`Not`]: core::ops::Not
+	pub fn not_assign(&mut self) {
+		let _ = !self;
+	}
+
+	/// Combines each bit of `self` with the bit at the same index in `other`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
+	fn assign_words<O2, T2>(&mut self, other: &BitSlice<O2, T2>, op: Bitwise)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Set operations require slices of equal length"
+		);
+		if TypeId::of::<O>() == TypeId::of::<O2>()
+			&& TypeId::of::<T>() == TypeId::of::<T2>()
+			&& self.bitptr().head().value() == other.bitptr().head().value()
+		{
+			let other: &Self = unsafe { &*(other as *const _ as *const _) };
+			return self.assign_elements(other, op);
+		}
+		let width = <usize as BitMemory>::BITS as usize;
+		for (dst, src) in self.chunks_mut(width).zip(other.chunks(width)) {
+			dst.store_word(op.apply(dst.load_word(), src.load_word()));
+		}
+	}
+
+	/// Combines each memory element of `self` with the corresponding element
+	/// of `other`.
+	///
+	/// `other` must have the same length as `self`, and begin at the same bit
+	/// of its first element, so that their domains have the same shape.
+	fn assign_elements(&mut self, other: &Self, op: Bitwise) {
+		match (self.domain_mut(), other.domain()) {
+			(
+				DomainMut::Enclave { head, elem, tail },
+				Domain::Enclave { elem: src, .. },
+			) => {
+				op.apply_edge(elem, O::mask(head, tail), src.load_value());
+			},
+			(
+				DomainMut::Region { head, body, tail },
+				Domain::Region {
+					head: src_head,
+					body: src_body,
+					tail: src_tail,
+				},
+			) => {
+				if let (Some((head, elem)), Some((_, src))) = (head, src_head) {
+					op.apply_edge(elem, O::mask(head, None), src.load_value());
+				}
+				for (elem, src) in body.iter_mut().zip(src_body) {
+					let value = op.apply(elem.load_value(), src.load_value());
+					elem.store_value(value);
+				}
+				if let (Some((elem, tail)), Some((src, _))) = (tail, src_tail) {
+					op.apply_edge(elem, O::mask(None, tail), src.load_value());
+				}
+			},
+			_ => unreachable!("Slices with the same shape have the same domain"),
+		}
+	}
+}
+
+/// The binary bitwise operators that can be applied in place.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Bitwise {
+	And,
+	Or,
+	Xor,
+}
+
+impl Bitwise {
+	/// Applies the operator to two registers.
+	fn apply<R>(self, a: R, b: R) -> R
+	where R: BitRegister {
+		match self {
+			Self::And => a & b,
+			Self::Or => a | b,
+			Self::Xor => a ^ b,
+		}
+	}
+
+	/// Applies the operator to the bits of a shared element that are selected
+	/// by `mask`, with `src` as the right operand, leaving all other bits of
+	/// the element unchanged.
+	fn apply_edge<A>(self, elem: &A, mask: BitMask<A::Item>, src: A::Item)
+	where
+		A: BitAccess,
+		A::Item: BitRegister,
+	{
+		let mask = mask.value();
+		match self {
+			Self::And => elem.clear_bits(BitMask::new(mask & !src)),
+			Self::Or => elem.set_bits(BitMask::new(mask & src)),
+			Self::Xor => elem.invert_bits(BitMask::new(mask & src)),
+		}
+	}
+}
+
 impl<'a, O, T> Not for &'a mut BitSlice<O, T>
 where
 	O: BitOrder,
@@ -0,0 +1,208 @@
+/*! Parallel operations, powered by [`rayon`].
+
+`BitSlice` cannot implement `rayon`’s parallel-iterator traits directly, because
+two subslices that share a memory element cannot be written from different
+threads without synchronization. Instead, the methods in this module split a
+slice at element boundaries, so that each worker thread has exclusive ownership
+of the memory elements it writes, or counts whole elements of memory that no
+other thread modifies.
+
+[`rayon`]: rayon
+!*/
+
+#![cfg(feature = "rayon")]
+
+use crate::{
+	domain::{
+		BitDomain,
+		BitDomainMut,
+	},
+	mem::BitMemory,
+	order::BitOrder,
+	slice::{
+		popcount,
+		BitSlice,
+	},
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	slice,
+};
+
+use rayon::prelude::*;
+
+/// The minimum number of bits that each parallel task fills or counts.
+const TASK_BITS: usize = 4096;
+
+/// Parallel operations.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Produces a parallel iterator over the bits of the slice.
+	///
+	/// This is a parallel version of [`.iter()`]. It yields each bit by value,
+	/// rather than by reference, and can be split at any index.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let data = [0x5555u16; 64];
+	/// let bits = data.view_bits::<Msb0>();
+	///
+	/// assert_eq!(bits.par_iter().filter(|bit| *bit).count(), 512);
+	/// assert!(bits
+	///   .par_iter()
+	///   .enumerate()
+	///   .all(|(idx, bit)| bit == (idx % 2 == 1)));
+	/// ```
+	///
+	/// [`.iter()`]: Self::iter
+	pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = bool> + '_
+	where T: Sync {
+		(0 .. self.len())
+			.into_par_iter()
+			.map(move |idx| unsafe { *self.get_unchecked(idx) })
+	}
+
+	/// Counts the number of bits set to `1` in the slice, counting its memory
+	/// elements in parallel.
+	///
+	/// This is a parallel version of [`.count_ones()`], and returns the same
+	/// value.
+	///
+	/// # Behavior
+	///
+	/// Any partially-occupied edge elements are counted on the calling thread.
+	/// The fully occupied elements are divided into chunks of at least 4096
+	/// bits, each of which is counted by one `rayon` task with the same
+	/// element counter that `.count_ones()` uses, and the partial counts are
+	/// summed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x0Fu8; 4096];
+	/// let bits = &data.view_bits::<Lsb0>()[2 .. 32766];
+	///
+	/// assert_eq!(bits.par_count_ones(), bits.count_ones());
+	/// assert_eq!(bits.par_count_ones(), 16382);
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	pub fn par_count_ones(&self) -> usize
+	where T::Unalias: Sync {
+		let (head, body, tail) = match self.bit_domain() {
+			BitDomain::Enclave { body, .. } => return body.count_ones(),
+			BitDomain::Region { head, body, tail } => (head, body, tail),
+		};
+
+		let width = <T::Mem as BitMemory>::BITS as usize;
+		let per_task = cmp::max(TASK_BITS / width, 1);
+		head.count_ones()
+			+ tail.count_ones()
+			+ body
+				.as_slice()
+				.par_chunks(per_task)
+				.map(popcount::count_ones)
+				.sum::<usize>()
+	}
+
+	/// Sets every bit in the slice to the result of a function of its index,
+	/// computing the bits in parallel.
+	///
+	/// This is a parallel version of [`.for_each()`] for functions that depend
+	/// only on the bit index.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives the index of a bit, and returns the
+	///   value to write into it. It is called concurrently from the threads of
+	///   the `rayon` pool, and so must be `Sync`.
+	///
+	/// # Behavior
+	///
+	/// The slice is split into three parts:
+	///
+	/// - any bits in a partially-occupied first element,
+	/// - the memory elements that are fully occupied by the slice,
+	/// - any bits in a partially-occupied last element.
+	///
+	/// The edge elements may be shared with other slices, and are filled on
+	/// the calling thread. The fully occupied elements are divided into
+	/// chunks of at least 4096 bits, and each chunk is filled by one `rayon`
+	/// task. Because the chunks never share an element, the tasks write to
+	/// their memory without any aliasing protection.
+	///
+	/// The order in which `func` is called on each index is unspecified.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u64; 256];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[3 ..];
+	///
+	/// bits.par_fill_with(|idx| idx % 3 == 0);
+	/// assert!(bits.iter().enumerate().all(|(idx, bit)| *bit == (idx % 3 == 0)));
+	/// ```
+	///
+	/// [`.for_each()`]: Self::for_each
+	pub fn par_fill_with<F>(&mut self, func: F)
+	where
+		F: Fn(usize) -> bool + Sync,
+		T::Unalias: Send,
+	{
+		let (head, body, tail) = match self.bit_domain_mut() {
+			BitDomainMut::Enclave { body, .. } => {
+				return fill_from(body, 0, &func);
+			},
+			BitDomainMut::Region { head, body, tail } => (head, body, tail),
+		};
+
+		let base = head.len();
+		fill_from(head, 0, &func);
+		fill_from(tail, base + body.len(), &func);
+
+		let width = <T::Mem as BitMemory>::BITS as usize;
+		let per_task = cmp::max(TASK_BITS / width, 1);
+		let bitptr = body.bitptr();
+		/* The body region fully spans its elements, and is unaliased, so it
+		can be viewed as an ordinary mutable slice of memory elements.
+		*/
+		let elts = unsafe {
+			slice::from_raw_parts_mut(
+				bitptr.pointer().to_mut(),
+				bitptr.elements(),
+			)
+		};
+		elts.par_chunks_mut(per_task)
+			.enumerate()
+			.for_each(|(idx, chunk)| {
+				let bits = unsafe {
+					BitSlice::<O, T::Unalias>::from_slice_unchecked_mut(chunk)
+				};
+				fill_from(bits, base + idx * per_task * width, &func);
+			});
+	}
+}
+
+/// Sets each bit in `bits` to `func` of its index, offset by `start`.
+fn fill_from<O, T, F>(bits: &mut BitSlice<O, T>, start: usize, func: &F)
+where
+	O: BitOrder,
+	T: BitStore,
+	F: Fn(usize) -> bool,
+{
+	bits.for_each(|idx, _| func(start + idx));
+}
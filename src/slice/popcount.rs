@@ -0,0 +1,119 @@
+/*! Accelerated population counts.
+
+[`BitSlice::count_ones`] spends almost all of its time counting the fully
+spanned elements in the body of a [`Domain::Region`]. On x86 targets with the
+`std` feature enabled, bodies large enough to repay the setup cost are counted
+with AVX2 instructions when the processor supports them. All other targets, and
+`no_std` builds, count each element in turn.
+
+[`BitSlice::count_ones`]: crate::slice::BitSlice::count_ones
+[`Domain::Region`]: crate::domain::Domain::Region
+!*/
+
+use crate::store::BitStore;
+
+#[cfg(all(
+	feature = "std",
+	any(target_arch = "x86", target_arch = "x86_64")
+))]
+use core::{
+	any::TypeId,
+	mem,
+	slice,
+};
+
+/// Counts the set bits in a run of fully spanned memory elements.
+///
+/// # Parameters
+///
+/// - `body`: The body of a [`Domain::Region`].
+///
+/// # Returns
+///
+/// The number of bits set to `1` in `body`.
+///
+/// [`Domain::Region`]: crate::domain::Domain::Region
+pub(crate) fn count_ones<T>(body: &[T]) -> usize
+where T: BitStore {
+	#[cfg(all(
+		feature = "std",
+		any(target_arch = "x86", target_arch = "x86_64")
+	))]
+	{
+		//  Only plain integers may be read as bytes. `Cell` and atomic
+		//  elements keep using their own load instructions.
+		if mem::size_of_val(body) >= avx2::THRESHOLD
+			&& TypeId::of::<T>() == TypeId::of::<T::Mem>()
+			&& is_x86_feature_detected!("avx2")
+		{
+			return unsafe {
+				avx2::count_ones(slice::from_raw_parts(
+					body.as_ptr() as *const u8,
+					mem::size_of_val(body),
+				))
+			};
+		}
+	}
+	scalar(body)
+}
+
+/// Counts the set bits in each element in turn.
+fn scalar<T>(body: &[T]) -> usize
+where T: BitStore {
+	body.iter()
+		.map(BitStore::load_value)
+		.map(|elem| elem.count_ones() as usize)
+		.sum()
+}
+
+#[cfg(all(
+	feature = "std",
+	any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod avx2 {
+	#[cfg(target_arch = "x86")]
+	use core::arch::x86::*;
+	#[cfg(target_arch = "x86_64")]
+	use core::arch::x86_64::*;
+
+	/// The smallest body, in bytes, that is worth counting with vectors.
+	pub(super) const THRESHOLD: usize = 256;
+
+	/// Counts the set bits in a byte buffer, 32 bytes at a time.
+	///
+	/// Each byte is split into two nibbles, which index a table of nibble
+	/// population counts. The per-byte counts of each block are then summed
+	/// into four 64-bit accumulators.
+	///
+	/// # Safety
+	///
+	/// The processor must support AVX2.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn count_ones(bytes: &[u8]) -> usize {
+		let table = _mm256_setr_epi8(
+			0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+			0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+		);
+		let nibble = _mm256_set1_epi8(0x0F);
+		let zero = _mm256_setzero_si256();
+		let mut sums = zero;
+
+		let blocks = bytes.chunks_exact(32);
+		let rest = blocks.remainder();
+		for block in blocks {
+			let data = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+			let low = _mm256_and_si256(data, nibble);
+			let high = _mm256_and_si256(_mm256_srli_epi16(data, 4), nibble);
+			let counts = _mm256_add_epi8(
+				_mm256_shuffle_epi8(table, low),
+				_mm256_shuffle_epi8(table, high),
+			);
+			sums = _mm256_add_epi64(sums, _mm256_sad_epu8(counts, zero));
+		}
+
+		let mut lanes = [0u64; 4];
+		_mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, sums);
+		lanes.iter().sum::<u64>() as usize
+			+ rest.iter().map(|byte| byte.count_ones() as usize).sum::<usize>()
+	}
+}
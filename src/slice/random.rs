@@ -0,0 +1,116 @@
+/*! Random initialization, powered by [`rand_core`].
+
+[`rand_core`]: rand_core
+!*/
+
+#![cfg(feature = "rand_core")]
+
+use crate::{
+	domain::BitDomainMut,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	mem,
+	slice,
+};
+
+use rand_core::RngCore;
+
+/// Random initialization.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Sets every bit in the slice to a random value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rng`: The source of random data.
+	///
+	/// # Behavior
+	///
+	/// The memory elements that are fully occupied by the slice are filled
+	/// directly from [`RngCore::fill_bytes`], a whole element at a time. Any
+	/// partially-occupied edge elements are written bit by bit from one
+	/// [`RngCore::next_u64`] word each, so the bits in those elements that are
+	/// outside the slice keep their values.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rand_core::{RngCore, impls};
+	///
+	/// //  A counter is a poor source of randomness, but a fine test fixture.
+	/// struct Counter(u64);
+	/// impl RngCore for Counter {
+	///   fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+	///   fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+	///   fn fill_bytes(&mut self, dest: &mut [u8]) {
+	///     impls::fill_bytes_via_next(self, dest)
+	///   }
+	///   fn try_fill_bytes(&mut self, dest: &mut [u8])
+	///   -> Result<(), rand_core::Error> {
+	///     self.fill_bytes(dest);
+	///     Ok(())
+	///   }
+	/// }
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 28];
+	/// bits.fill_random(&mut Counter(0));
+	///
+	/// assert_eq!(data[0] & 0x0F, 0);
+	/// assert_eq!(data[3] & 0xF0, 0);
+	/// ```
+	///
+	/// [`RngCore::fill_bytes`]: rand_core::RngCore::fill_bytes
+	/// [`RngCore::next_u64`]: rand_core::RngCore::next_u64
+	pub fn fill_random<R>(&mut self, rng: &mut R)
+	where R: RngCore + ?Sized {
+		let (head, body, tail) = match self.bit_domain_mut() {
+			BitDomainMut::Enclave { body, .. } => {
+				return fill_edge(body, rng);
+			},
+			BitDomainMut::Region { head, body, tail } => (head, body, tail),
+		};
+
+		fill_edge(head, rng);
+		fill_edge(tail, rng);
+
+		let bitptr = body.bitptr();
+		/* The body region fully spans its elements, and is unaliased, so its
+		memory can be viewed as an ordinary byte slice. Every bit pattern is a
+		valid value of every `BitStore` type, so any bytes may be written.
+		*/
+		let bytes = unsafe {
+			slice::from_raw_parts_mut(
+				bitptr.pointer().to_mut() as *mut u8,
+				bitptr.elements() * mem::size_of::<T>(),
+			)
+		};
+		rng.fill_bytes(bytes);
+	}
+}
+
+/// Sets each bit in a partial edge element to a bit drawn from `rng`.
+///
+/// Edge slices never span more than one element, so they are never wider
+/// than 64 bits.
+fn fill_edge<O, T, R>(bits: &mut BitSlice<O, T>, rng: &mut R)
+where
+	O: BitOrder,
+	T: BitStore,
+	R: RngCore + ?Sized,
+{
+	if bits.is_empty() {
+		return;
+	}
+	let word = rng.next_u64();
+	bits.for_each(|idx, _| word >> idx & 1 != 0);
+}
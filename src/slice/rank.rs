@@ -0,0 +1,182 @@
+/*! Constant-time rank queries.
+
+[`BitSlice::rank_ones`] counts every bit before the requested index, so its cost
+grows with the index. When a slice is queried many times without being
+modified, a [`RankIndex`] can precompute running totals of set bits, and answer
+each query by adding one stored total to the population count of a single
+short block.
+
+[`BitSlice::rank_ones`]: crate::slice::BitSlice::rank_ones
+[`RankIndex`]: self::RankIndex
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use alloc::vec::Vec;
+
+/** A precomputed index for answering rank queries over a `BitSlice`.
+
+The indexed slice is divided into blocks of a fixed number of memory elements’
+worth of bits, counting from the start of the slice. The index stores, for each
+block, the number of set bits in the slice before that block begins. A rank
+query looks up the total for the block containing its index, and adds the
+population count of the bits between the start of that block and the index.
+
+# Complexity
+
+Construction takes time linear in the length of the slice, and stores one
+`usize` per block. Each query counts at most one block, so its cost depends on
+the block size, not on the index or the length of the slice.
+
+Larger blocks use less memory and make each query slower; smaller blocks use
+more memory and make each query faster. A block of eight elements costs one
+`usize` for every eight elements of the slice.
+
+# Lifetimes
+
+- `'a`: The lifetime of the indexed slice. The slice cannot be modified while
+  the index borrows it, so the stored totals never go stale.
+
+# Type Parameters
+
+- `O`: The ordering of the indexed slice.
+- `T`: The storage type of the indexed slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::slice::RankIndex;
+
+let data = [0x0Fu8, 0xF0, 0xFF, 0x00, 0x81];
+let bits = data.view_bits::<Lsb0>();
+let index = RankIndex::new(bits, 2);
+
+assert_eq!(index.rank_ones(4), 4);
+assert_eq!(index.rank_ones(20), 12);
+assert_eq!(index.rank_ones(40), bits.rank_ones(40));
+assert_eq!(index.rank_zeros(20), 8);
+```
+**/
+#[derive(Clone, Debug)]
+pub struct RankIndex<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The indexed slice.
+	bits: &'a BitSlice<O, T>,
+	/// The number of bits in each block.
+	block: usize,
+	/// The number of set bits before the start of each block. This always has
+	/// one entry more than the number of whole blocks in `bits`.
+	totals: Vec<usize>,
+}
+
+impl<'a, O, T> RankIndex<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Builds a rank index over a slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice to index.
+	/// - `block_elements`: The number of `T` elements’ worth of bits in each
+	///   block of the index.
+	///
+	/// # Returns
+	///
+	/// An index that answers rank queries over `bits`.
+	///
+	/// # Panics
+	///
+	/// This panics if `block_elements` is zero, or so large that the block
+	/// width in bits overflows `usize`.
+	pub fn new(bits: &'a BitSlice<O, T>, block_elements: usize) -> Self {
+		assert!(block_elements != 0, "Rank blocks must not be empty");
+		let block = block_elements
+			.checked_mul(T::Mem::BITS as usize)
+			.expect("Rank block width overflow");
+		let mut totals = Vec::with_capacity(bits.len() / block + 1);
+		let mut total = 0;
+		totals.push(total);
+		for chunk in bits.chunks_exact(block) {
+			total += chunk.count_ones();
+			totals.push(total);
+		}
+		Self {
+			bits,
+			block,
+			totals,
+		}
+	}
+
+	/// Gets the indexed slice.
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		self.bits
+	}
+
+	/// Gets the number of bits in each block of the index.
+	pub fn block_bits(&self) -> usize {
+		self.block
+	}
+
+	/// Counts the bits set to `1` in the front of the indexed slice.
+	///
+	/// This produces the same result as [`BitSlice::rank_ones`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The number of leading bits to count. This may be equal to
+	///   the length of the indexed slice.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `bits[.. index]` that are set to `1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the length of the indexed slice.
+	///
+	/// [`BitSlice::rank_ones`]: crate::slice::BitSlice::rank_ones
+	pub fn rank_ones(&self, index: usize) -> usize {
+		self.bits.assert_rank_index(index);
+		let block = index / self.block;
+		let start = block * self.block;
+		self.totals[block]
+			+ unsafe { self.bits.get_unchecked(start .. index) }.count_ones()
+	}
+
+	/// Counts the bits set to `0` in the front of the indexed slice.
+	///
+	/// This produces the same result as [`BitSlice::rank_zeros`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The number of leading bits to count. This may be equal to
+	///   the length of the indexed slice.
+	///
+	/// # Returns
+	///
+	/// The number of bits in `bits[.. index]` that are set to `0`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the length of the indexed slice.
+	///
+	/// [`BitSlice::rank_zeros`]: crate::slice::BitSlice::rank_zeros
+	pub fn rank_zeros(&self, index: usize) -> usize {
+		index - self.rank_ones(index)
+	}
+}
@@ -114,6 +114,35 @@ where T: BitStore
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
 	}
+
+	/// Accelerates palindrome detection with batch loads.
+	pub(crate) fn sp_is_palindrome(&self) -> bool {
+		let len = self.len();
+		let half = len / 2;
+		let front = unsafe { self.get_unchecked(.. half) };
+		let back = unsafe { self.get_unchecked(len - half ..) };
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		front
+			.chunks(chunk_size)
+			.zip(back.rchunks(chunk_size))
+			.all(|(f, b)| {
+				let shamt = chunk_size - f.len();
+				f.load_le::<usize>()
+					== b.load_le::<usize>().reverse_bits() >> shamt
+			})
+	}
+
+	/// Loads up to one `usize` of bits, with bit `n` of the slice in bit `n`
+	/// of the returned word.
+	pub(crate) fn sp_load_word(&self) -> usize {
+		self.load_le::<usize>()
+	}
+
+	/// Stores up to one `usize` of bits, with bit `n` of the word written into
+	/// bit `n` of the slice.
+	pub(crate) fn sp_store_word(&mut self, word: usize) {
+		self.store_le::<usize>(word);
+	}
 }
 
 /** Order-specialized function implementations.
@@ -190,4 +219,35 @@ where T: BitStore
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_be::<usize>() == b.load_be::<usize>())
 	}
+
+	/// Accelerates palindrome detection with batch loads.
+	pub(crate) fn sp_is_palindrome(&self) -> bool {
+		let len = self.len();
+		let half = len / 2;
+		let front = unsafe { self.get_unchecked(.. half) };
+		let back = unsafe { self.get_unchecked(len - half ..) };
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		front
+			.chunks(chunk_size)
+			.zip(back.rchunks(chunk_size))
+			.all(|(f, b)| {
+				let shamt = chunk_size - f.len();
+				f.load_be::<usize>()
+					== b.load_be::<usize>().reverse_bits() >> shamt
+			})
+	}
+
+	/// Loads up to one `usize` of bits, with bit `n` of the slice in bit `n`
+	/// of the returned word.
+	pub(crate) fn sp_load_word(&self) -> usize {
+		let shamt = <usize as BitMemory>::BITS as usize - self.len();
+		self.load_be::<usize>().reverse_bits() >> shamt
+	}
+
+	/// Stores up to one `usize` of bits, with bit `n` of the word written into
+	/// bit `n` of the slice.
+	pub(crate) fn sp_store_word(&mut self, word: usize) {
+		let shamt = <usize as BitMemory>::BITS as usize - self.len();
+		self.store_be::<usize>(word.reverse_bits() >> shamt);
+	}
 }
@@ -136,6 +136,70 @@ fn get_set() {
 	assert!(shared_2[0]);
 }
 
+#[test]
+fn fetch_set_clear() {
+	let byte = core::cell::Cell::new(0b0100u8);
+	let bits = byte.view_bits::<Msb0>();
+	let bits_2 = bits;
+
+	assert!(!bits.fetch_set(0));
+	assert!(bits_2.fetch_set(0));
+	assert!(bits[5]);
+	assert!(bits_2.fetch_clear(5));
+	assert!(!bits.fetch_clear(5));
+	assert_eq!(byte.get(), 0x80);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn fetch_set_threads() {
+	radium::if_atomic! {
+		if atomic(32) {
+			use core::sync::atomic::{
+				AtomicU32,
+				Ordering,
+			};
+			use std::{
+				sync::atomic::AtomicUsize,
+				thread,
+			};
+
+			static DATA: [AtomicU32; 4] = [
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+			];
+			static WINS: AtomicUsize = AtomicUsize::new(0);
+
+			let threads = (0 .. 4)
+				.map(|_| {
+					thread::spawn(|| {
+						let bits = DATA.view_bits::<Lsb0>();
+						let wins = (0 .. bits.len())
+							.filter(|&idx| !bits.fetch_set(idx))
+							.count();
+						WINS.fetch_add(wins, Ordering::Relaxed);
+					})
+				})
+				.collect::<Vec<_>>();
+			for thread in threads {
+				thread.join().unwrap();
+			}
+
+			assert_eq!(WINS.load(Ordering::Relaxed), 128);
+			assert!(DATA.view_bits::<Lsb0>().all());
+		}
+	}
+}
+
+#[test]
+#[should_panic = "Index out of range: 4 >= 4"]
+fn fetch_set_out_of_bounds() {
+	let elem = core::cell::Cell::new(0u8);
+	elem.view_bits::<Lsb0>()[.. 4].fetch_set(4);
+}
+
 #[test]
 #[should_panic = "Index 1 out of bounds: 1"]
 fn index_out_of_bounds() {
@@ -421,6 +485,46 @@ fn shift() {
 	assert_eq!(bits, bits![0, 0, 1, 1, 0, 0]);
 }
 
+#[test]
+fn shift_domains() {
+	fn check<O>(by: usize, left: bool)
+	where O: BitOrder {
+		let orig = [0x1234_5678u32, 0x9ABC_DEF0, 0x0F1E_2D3C];
+		let mut data = orig;
+		let bits = &mut data.view_bits_mut::<O>()[5 .. 83];
+		let src = &orig.view_bits::<O>()[5 .. 83];
+		let len = src.len();
+		if left {
+			bits.shift_left(by);
+		}
+		else {
+			bits.shift_right(by);
+		}
+		for idx in 0 .. len {
+			let expected = if left {
+				idx + by < len && src[idx + by]
+			}
+			else {
+				idx >= by && src[idx - by]
+			};
+			assert_eq!(bits[idx], expected, "{} by {}", idx, by);
+		}
+		//  Bits outside the slice are untouched.
+		let (after, before) = (data.view_bits::<O>(), orig.view_bits::<O>());
+		assert_eq!(after[.. 5], before[.. 5]);
+		assert_eq!(after[83 ..], before[83 ..]);
+	}
+
+	for &by in &[1, 7, 31, 32, 33, 64, 77, 78, 200] {
+		check::<Lsb0>(by, true);
+		check::<Lsb0>(by, false);
+		check::<Msb0>(by, true);
+		check::<Msb0>(by, false);
+		check::<Swap>(by, true);
+		check::<Swap>(by, false);
+	}
+}
+
 #[test]
 fn invert() {
 	let mut data = [0u8; 4];
@@ -447,6 +551,50 @@ fn rotate() {
 	assert_eq!(bits, bits![0, 1, 0, 0, 1, 0]);
 }
 
+#[test]
+fn rotate_wraps() {
+	use core::ops::Range;
+
+	fn check<O>(range: Range<usize>, by: usize)
+	where O: BitOrder {
+		let orig = [0x1234_5678u32, 0x9ABC_DEF0, 0x0F1E_2D3C, 0x4B5A_6978];
+		let src = &orig.view_bits::<O>()[range.clone()];
+		let len = src.len();
+
+		let mut data = orig;
+		data.view_bits_mut::<O>()[range.clone()].rotate_left(by);
+		let bits = &data.view_bits::<O>()[range.clone()];
+		for idx in 0 .. len {
+			assert_eq!(bits[idx], src[(idx + by) % len], "{} by {}", idx, by);
+		}
+
+		data.view_bits_mut::<O>()[range.clone()].rotate_right(by);
+		assert_eq!(data, orig);
+
+		data.view_bits_mut::<O>()[range.clone()].rotate_right(by);
+		let bits = &data.view_bits::<O>()[range];
+		for idx in 0 .. len {
+			let from = (idx + len - by % len) % len;
+			assert_eq!(bits[idx], src[from], "{} by {}", idx, by);
+		}
+	}
+
+	for &by in &[1, 31, 32, 64, 96, 127, 128, 129, 200, 1000] {
+		//  Whole elements, which rotate by element when `by` allows.
+		check::<Lsb0>(0 .. 128, by);
+		check::<Msb0>(0 .. 128, by);
+		check::<Swap>(0 .. 128, by);
+		//  Partial head and tail elements.
+		check::<Lsb0>(5 .. 123, by);
+		check::<Msb0>(5 .. 123, by);
+		check::<Swap>(32 .. 123, by);
+	}
+
+	let empty = BitSlice::<Lsb0, u8>::empty_mut();
+	empty.rotate_left(3);
+	empty.rotate_right(3);
+}
+
 #[test]
 fn unspecialized() {
 	use crate::{
@@ -586,6 +734,33 @@ fn windows() {
 	assert!(windows.nth_back(1).is_none());
 }
 
+#[test]
+fn windows_matching() {
+	let bits = bits![Msb0, u8; 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1];
+	let pattern = bits![1, 1, 0];
+
+	let mut windows = bits.windows(3);
+	assert_eq!(windows.len(), 11);
+	windows.next();
+	windows.next_back();
+	assert_eq!(windows.len(), 9);
+	assert_eq!(windows.rev().count(), 9);
+
+	let hits = bits
+		.windows(3)
+		.enumerate()
+		.filter(|(_, w)| *w == pattern)
+		.map(|(idx, _)| idx);
+	assert!(hits.eq([1, 5, 8].iter().copied()));
+	assert!(bits.windows(14).next().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Window width cannot be 0")]
+fn windows_zero() {
+	bits![0; 4].windows(0);
+}
+
 #[test]
 fn chunks() {
 	let bits = bits![Lsb0, u16; 0; 16];
@@ -668,6 +843,30 @@ fn chunks_exact_mut() {
 	assert_eq!(chunks.into_remainder().bitptr(), rest);
 }
 
+#[test]
+fn chunks_exact_fields() {
+	let data = [0xABu8, 0xCD, 0xEF, 0x12, 0x34];
+	let bits = data.view_bits::<Msb0>();
+
+	let mut chunks = bits.chunks_exact(12);
+	assert_eq!(chunks.len(), 3);
+	assert_eq!(chunks.remainder(), &bits[36 ..]);
+	let samples = chunks.clone().map(BitField::load_be::<u16>);
+	assert!(samples.eq([0xABCu16, 0xDEF, 0x123].iter().copied()));
+	assert_eq!(chunks.nth(2).unwrap().load_be::<u16>(), 0x123);
+	assert!(chunks.next().is_none());
+
+	let mut rchunks = bits.rchunks_exact(12);
+	assert_eq!(rchunks.len(), 3);
+	assert_eq!(rchunks.remainder(), &bits[.. 4]);
+	assert_eq!(rchunks.next_back().unwrap().load_be::<u16>(), 0xBCD);
+	assert_eq!(rchunks.nth(1).unwrap().load_be::<u16>(), 0xEF1);
+	assert_eq!(rchunks.len(), 0);
+
+	let bits = bits![mut Lsb0, u8; 0; 24];
+	assert!(bits.chunks_exact_mut(8).nth(2).is_some());
+}
+
 #[test]
 fn rchunks() {
 	let bits = bits![Lsb0, u16; 0; 16];
@@ -773,6 +972,1414 @@ fn iter_ones_zeros() {
 	assert!(zeros.nth_back(0).is_none());
 }
 
+#[test]
+fn byte_chunks() {
+	let data = [0u16; 2];
+	let bits = &data.view_bits::<Msb0>()[3 .. 30];
+
+	let mut chunks = bits.byte_chunks();
+	assert_eq!(chunks.len(), 4);
+	assert_eq!(chunks.next().unwrap().bitptr(), bits[.. 8].bitptr());
+	assert_eq!(chunks.next().unwrap().bitptr(), bits[8 .. 16].bitptr());
+	assert_eq!(chunks.next_back().unwrap().bitptr(), bits[24 ..].bitptr());
+	assert_eq!(bits.byte_chunks().last().unwrap().len(), 3);
+
+	assert!(BitSlice::<LocalBits, usize>::empty()
+		.byte_chunks()
+		.next()
+		.is_none());
+}
+
+#[test]
+fn rank_select() {
+	let data = [0x0Fu8, 0xFF, 0x00, 0xF0];
+	let bits = &data.view_bits::<Msb0>()[2 .. 30];
+
+	assert_eq!(bits.rank_ones(0), 0);
+	assert_eq!(bits.rank_ones(6), 4);
+	assert_eq!(bits.rank_ones(28), 16);
+	assert_eq!(bits.rank_zeros(6), 2);
+	assert_eq!(bits.rank_zeros(28), 12);
+
+	assert_eq!(bits.select_ones(0), Some(2));
+	assert_eq!(bits.select_ones(4), Some(6));
+	assert_eq!(bits.select_ones(15), Some(25));
+	assert!(bits.select_ones(16).is_none());
+
+	assert_eq!(bits.select_zeros(0), Some(0));
+	assert_eq!(bits.select_zeros(2), Some(14));
+	assert_eq!(bits.select_zeros(9), Some(21));
+	//  The last cleared bit is in the final, partial element.
+	assert_eq!(bits.select_zeros(11), Some(27));
+	assert!(bits.select_zeros(12).is_none());
+
+	for rank in 0 .. 16 {
+		let idx = bits.select_ones(rank).unwrap();
+		assert_eq!(bits.rank_ones(idx), rank);
+		assert!(bits[idx]);
+	}
+	for rank in 0 .. 12 {
+		let idx = bits.select_zeros(rank).unwrap();
+		assert_eq!(bits.rank_zeros(idx), rank);
+		assert!(!bits[idx]);
+	}
+
+	assert!(BitSlice::<Lsb0, u8>::empty().select_zeros(0).is_none());
+}
+
+#[test]
+#[should_panic]
+fn rank_out_of_range() {
+	bits![0, 1].rank_zeros(3);
+}
+
+#[test]
+fn gf2_mul() {
+	//  Reference multiplication in the AES field, GF(2^8) modulo 0x11B.
+	fn reference(mut a: u8, mut b: u8) -> u8 {
+		let mut out = 0;
+		while b != 0 {
+			if b & 1 != 0 {
+				out ^= a;
+			}
+			let carry = a & 0x80 != 0;
+			a <<= 1;
+			if carry {
+				a ^= 0x1B;
+			}
+			b >>= 1;
+		}
+		out
+	}
+
+	let poly = 0x11Bu16;
+	let modulus = &poly.view_bits::<Lsb0>()[.. 9];
+	for a in 0 ..= 255u8 {
+		for b in 0 ..= 255u8 {
+			let mut c = 0u8;
+			BitSlice::gf2_mul(
+				a.view_bits::<Lsb0>(),
+				b.view_bits::<Lsb0>(),
+				modulus,
+				c.view_bits_mut::<Lsb0>(),
+			);
+			assert_eq!(c, reference(a, b), "{:02X} * {:02X}", a, b);
+		}
+	}
+
+	//  GF(2), modulo x + 1.
+	let one = bits![Lsb0, u8; 1];
+	let out = bits![mut Lsb0, u8; 0];
+	BitSlice::gf2_mul(one, one, bits![Lsb0, u8; 1, 1], out);
+	assert!(out[0]);
+}
+
+#[test]
+fn partition() {
+	let mut data = [0x0100u16, 0x8421, 0x0000];
+	let bits = &mut data.view_bits_mut::<Msb0>()[4 .. 44];
+	assert_eq!(bits.partition(), 5);
+	assert!(bits[.. 5].all());
+	assert!(bits[5 ..].not_any());
+	assert_eq!(data, [0x0F80, 0x0000, 0x0000]);
+
+	let bits = bits![mut 1, 1, 1];
+	assert_eq!(bits.partition(), 3);
+	assert!(bits.all());
+
+	assert_eq!(BitSlice::<Lsb0, u8>::empty_mut().partition(), 0);
+}
+
+#[test]
+fn match_indices() {
+	//  Overlapping matches.
+	let bits = bits![1, 1, 1, 1, 0, 1, 1];
+	let mut hits = bits.match_indices(bits![1, 1]);
+	assert_eq!(hits.next(), Some(0));
+	assert_eq!(hits.next(), Some(1));
+	assert_eq!(hits.next(), Some(2));
+	assert_eq!(hits.next(), Some(5));
+	assert!(hits.next().is_none());
+	assert!(hits.next().is_none());
+
+	//  Adjacent matches, across element boundaries and type parameters.
+	let data = [0b0110_0110u8, 0b0110_0000];
+	let bits = &data.view_bits::<Msb0>()[1 ..];
+	let mut hits = bits.match_indices(bits![Lsb0, u16; 1, 1, 0, 0]);
+	assert_eq!(hits.next(), Some(0));
+	assert_eq!(hits.next(), Some(4));
+	assert_eq!(hits.next(), Some(8));
+	assert!(hits.next().is_none());
+
+	//  The empty pattern matches at every index, including the end.
+	let bits = bits![0, 1];
+	let mut hits = bits.match_indices(BitSlice::<Lsb0, u8>::empty());
+	assert_eq!(hits.size_hint(), (0, Some(3)));
+	assert_eq!(hits.next(), Some(0));
+	assert_eq!(hits.next(), Some(1));
+	assert_eq!(hits.next(), Some(2));
+	assert!(hits.next().is_none());
+	assert_eq!(hits.size_hint(), (0, Some(0)));
+
+	assert!(bits.match_indices(bits![0, 1, 0]).next().is_none());
+}
+
+#[test]
+fn nth_one_run() {
+	let data = [0xF00Fu16, 0x8001];
+	let bits = &data.view_bits::<Lsb0>()[2 ..];
+
+	assert_eq!(bits.nth_one_run(0), Some(0 .. 2));
+	assert_eq!(bits.nth_one_run(1), Some(10 .. 15));
+	assert_eq!(bits.nth_one_run(2), Some(29 .. 30));
+	assert!(bits.nth_one_run(3).is_none());
+
+	assert_eq!(bits![1; 5].nth_one_run(0), Some(0 .. 5));
+	assert!(bits![0; 5].nth_one_run(0).is_none());
+	assert!(BitSlice::<Lsb0, u8>::empty().nth_one_run(0).is_none());
+}
+
+#[test]
+fn is_palindrome() {
+	fn check<O, T>(bits: &mut BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = bits.len();
+		for idx in 0 .. len {
+			let mirror = core::cmp::min(idx, len - 1 - idx);
+			bits.set(idx, mirror * 7 % 5 < 2);
+		}
+		assert!(bits.is_palindrome());
+		if len > 1 {
+			let idx = len / 3;
+			let bit = bits[idx];
+			bits.set(idx, !bit);
+			assert!(!bits.is_palindrome());
+			bits.set(idx, bit);
+		}
+	}
+
+	let mut data = [0u8; 32];
+	let mut wide = [0u32; 8];
+	for &(start, end) in &[(0, 0), (0, 1), (3, 10), (3, 11), (5, 200), (5, 201)]
+	{
+		check(&mut data.view_bits_mut::<Lsb0>()[start .. end]);
+		check(&mut data.view_bits_mut::<Msb0>()[start .. end]);
+		check(&mut wide.view_bits_mut::<Lsb0>()[start .. end]);
+		check(&mut wide.view_bits_mut::<Msb0>()[start .. end]);
+	}
+
+	assert!(bits![1, 0, 0, 1].is_palindrome());
+	assert!(!bits![1, 0, 1, 1].is_palindrome());
+}
+
+#[test]
+fn check_range() {
+	use core::ops::Bound;
+
+	let bits = bits![0; 8];
+	assert_eq!(bits.check_range(..), Some(0 .. 8));
+	assert_eq!(bits.check_range(3 ..), Some(3 .. 8));
+	assert_eq!(bits.check_range(8 ..), Some(8 .. 8));
+	assert_eq!(bits.check_range(.. 5), Some(0 .. 5));
+	assert_eq!(bits.check_range(..= 7), Some(0 .. 8));
+	assert_eq!(bits.check_range(2 .. 6), Some(2 .. 6));
+	assert_eq!(bits.check_range(2 ..= 6), Some(2 .. 7));
+	assert_eq!(bits.check_range(4 .. 4), Some(4 .. 4));
+	assert_eq!(
+		bits.check_range((Bound::Excluded(1), Bound::Included(3))),
+		Some(2 .. 4),
+	);
+
+	assert!(bits.check_range(9 ..).is_none());
+	assert!(bits.check_range(.. 9).is_none());
+	assert!(bits.check_range(..= 8).is_none());
+	assert!(bits.check_range(5 .. 4).is_none());
+	assert!(bits.check_range(..= usize::MAX).is_none());
+	assert!(
+		bits.check_range((Bound::Excluded(usize::MAX), Bound::Unbounded))
+			.is_none()
+	);
+
+	assert_eq!(BitSlice::<Lsb0, u8>::empty().check_range(..), Some(0 .. 0));
+}
+
+#[test]
+fn set_relations() {
+	let empty = bits![0; 100];
+	let mut data = [0u16; 8];
+	let evens = &mut data.view_bits_mut::<Msb0>()[3 .. 103];
+	evens.for_each(|idx, _| idx % 2 == 0);
+	let mut data = [0u32; 4];
+	let sixes = &mut data.view_bits_mut::<Lsb0>()[1 .. 101];
+	sixes.for_each(|idx, _| idx % 6 == 0);
+	let mut data = [0u8; 13];
+	let odds = &mut data.view_bits_mut::<Lsb0>()[.. 100];
+	odds.for_each(|idx, _| idx % 2 == 1);
+
+	//  Equal sets.
+	assert!(evens.is_subset(evens));
+	assert!(evens.is_superset(evens));
+	assert!(!evens.is_disjoint(evens));
+
+	//  Proper subsets.
+	assert!(sixes.is_subset(evens));
+	assert!(!evens.is_subset(sixes));
+	assert!(evens.is_superset(sixes));
+	assert!(!sixes.is_superset(evens));
+	assert!(empty.is_subset(sixes));
+	assert!(sixes.is_superset(empty));
+
+	//  Disjoint sets.
+	assert!(evens.is_disjoint(odds));
+	assert!(sixes.is_disjoint(odds));
+	assert!(empty.is_disjoint(empty));
+	assert!(!evens.is_subset(odds));
+	assert!(!odds.is_superset(sixes));
+
+	//  A shared bit in the final partial word is still detected.
+	odds.set(98, true);
+	assert!(!evens.is_disjoint(odds));
+
+	//  Orderings without a `BitField` implementation take the slow path.
+	let mut data = [0u8; 13];
+	let swapped = &mut data.view_bits_mut::<Swap>()[.. 100];
+	swapped.for_each(|idx, _| idx % 6 == 0);
+	assert!(swapped.is_subset(sixes) && swapped.is_superset(sixes));
+	assert!(swapped.is_subset(evens));
+	assert!(swapped.is_disjoint(odds));
+}
+
+#[test]
+#[should_panic]
+fn set_relations_lengths() {
+	bits![0; 4].is_subset(bits![0; 5]);
+}
+
+#[test]
+fn nearest_one() {
+	let mut data = [0u32; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 120];
+	assert!((0 .. bits.len()).all(|idx| bits.nearest_one(idx).is_none()));
+
+	for &idx in &[10, 14, 80] {
+		bits.set(idx, true);
+	}
+	for idx in 0 .. bits.len() {
+		let expected = if idx <= 12 {
+			10
+		}
+		else if idx <= 47 {
+			14
+		}
+		else {
+			80
+		};
+		assert_eq!(bits.nearest_one(idx), Some(expected));
+	}
+
+	//  Equidistant set bits resolve to the lower index.
+	assert_eq!(bits.nearest_one(12), Some(10));
+	assert_eq!(bits.nearest_one(47), Some(14));
+	assert_eq!(bits.nearest_one(48), Some(80));
+}
+
+#[test]
+#[should_panic]
+fn nearest_one_out_of_range() {
+	let _ = bits![1; 4].nearest_one(4);
+}
+
+#[test]
+fn edge_runs() {
+	fn reference<O, T>(bits: &BitSlice<O, T>) -> Option<(bool, usize)>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let first = *bits.first()?;
+		Some((first, bits.iter().take_while(|&&bit| bit == first).count()))
+	}
+
+	let mut data = [0u16; 12];
+	let len = data.view_bits::<Lsb0>().len();
+	for &(start, run) in &[(0, 1), (3, 13), (5, 70), (9, 150), (0, len)] {
+		for &value in &[false, true] {
+			data = [if value { 0 } else { !0 }; 12];
+			data.view_bits_mut::<Msb0>()[start ..][.. run].set_all(value);
+			for bits in &[
+				&data.view_bits::<Msb0>()[start ..],
+				&data.view_bits::<Msb0>()[start .. start + run],
+			] {
+				assert_eq!(bits.leading_run(), Some((value, run)));
+			}
+			let bits = &data.view_bits::<Msb0>()[.. start + run];
+			assert_eq!(bits.trailing_run().unwrap(), (value, run));
+
+			let bits = &data.view_bits::<Lsb0>()[start ..];
+			assert_eq!(bits.leading_run(), reference(bits));
+			let bits = &data.view_bits::<Swap>()[start ..];
+			assert_eq!(bits.leading_run(), reference(bits));
+		}
+	}
+
+	//  Trailing runs reported against a reversed reference.
+	let data = [0x0Fu8, 0xF0, 0xFF, 0x01];
+	#[cfg(feature = "alloc")]
+	for bits in &[data.view_bits::<Lsb0>(), &data.view_bits::<Lsb0>()[.. 25]] {
+		let rev = bits.iter().rev().copied().collect::<crate::vec::BitVec>();
+		assert_eq!(bits.trailing_run(), reference(rev.as_bitslice()));
+	}
+	assert_eq!(data.view_bits::<Lsb0>().trailing_run(), Some((false, 7)));
+	assert_eq!(data.view_bits::<Swap>().trailing_run(), Some((false, 6)));
+
+	assert!(BitSlice::<Msb0, u8>::empty().leading_run().is_none());
+	assert!(BitSlice::<Msb0, u8>::empty().trailing_run().is_none());
+}
+
+#[test]
+fn adjacency_rows() {
+	let mut data = [0u16; 5];
+	let row = &mut data.view_bits_mut::<Msb0>()[7 .. 77];
+	for &vertex in &[0, 9, 10, 41, 69] {
+		row.set(vertex, true);
+	}
+	assert!(row.neighbors().eq([0, 9, 10, 41, 69].iter().copied()));
+	assert_eq!(row.degree(), 5);
+
+	let row = bits![0; 12];
+	assert_eq!(row.neighbors().next(), None);
+	assert_eq!(row.degree(), 0);
+}
+
+#[test]
+fn ones_fraction() {
+	assert_eq!(bits![0; 12].ones_fraction(), 0.0);
+	assert_eq!(bits![1; 12].ones_fraction(), 1.0);
+	assert_eq!(bits![Msb0, u16; 1, 0, 0, 0].ones_fraction(), 0.25);
+	assert!(BitSlice::<Lsb0, usize>::empty().ones_fraction().is_nan());
+
+	let data = [0x0Fu8, 0xFF, 0x00];
+	assert_eq!(data.view_bits::<Lsb0>()[4 .. 20].ones_fraction(), 0.5);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn shannon_entropy_bit() {
+	assert_eq!(bits![0; 100].shannon_entropy_bit(), 0.0);
+	assert_eq!(bits![1; 100].shannon_entropy_bit(), 0.0);
+	assert_eq!(BitSlice::<Lsb0, u8>::empty().shannon_entropy_bit(), 0.0);
+	assert_eq!(bits![0, 1].shannon_entropy_bit(), 1.0);
+
+	let data = [0x5555_5555u32; 4];
+	assert_eq!(data.view_bits::<Msb0>().shannon_entropy_bit(), 1.0);
+
+	//  One bit in eight: 0.125 * 3 + 0.875 * log2(8 / 7)
+	let h = bits![0, 0, 0, 0, 1, 0, 0, 0].shannon_entropy_bit();
+	assert!((h - 0.543_564_443).abs() < 1e-9);
+	assert!(bits![0, 0, 1].shannon_entropy_bit() < 1.0);
+}
+
+#[test]
+fn count_transitions() {
+	fn reference<O, T>(bits: &BitSlice<O, T>) -> usize
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		bits.windows(2).filter(|pair| pair[0] != pair[1]).count()
+	}
+
+	let mut data = [0u32; 8];
+	for (idx, elem) in data.iter_mut().enumerate() {
+		*elem = (idx as u32).wrapping_mul(0x9E37_79B9) ^ 0xF0F0_0FF0;
+	}
+	for &(start, end) in &[(0, 256), (3, 200), (31, 33), (7, 71)] {
+		let bits = &data.view_bits::<Lsb0>()[start .. end];
+		assert_eq!(bits.count_transitions(), reference(bits));
+		let bits = &data.view_bits::<Msb0>()[start .. end];
+		assert_eq!(bits.count_transitions(), reference(bits));
+		let bits = &data.view_bits::<Swap>()[start .. end];
+		assert_eq!(bits.count_transitions(), reference(bits));
+	}
+
+	let alternating = [0x55u8; 4];
+	assert_eq!(alternating.view_bits::<Msb0>().count_transitions(), 31);
+	assert_eq!(alternating.view_bits::<Lsb0>()[5 ..].count_transitions(), 26);
+	assert_eq!(bits![0, 1].count_transitions(), 1);
+	assert_eq!(bits![1].count_transitions(), 0);
+	assert_eq!(BitSlice::<Lsb0, u8>::empty().count_transitions(), 0);
+}
+
+#[test]
+fn split_around() {
+	let data = [0x5Au8, 0xC3];
+	let bits = &data.view_bits::<Msb0>()[1 ..];
+
+	for idx in 0 .. bits.len() {
+		let (front, pivot, back) = bits.split_around(idx);
+		assert_eq!(front, &bits[.. idx]);
+		assert_eq!(pivot, bits[idx]);
+		assert_eq!(back, &bits[idx + 1 ..]);
+	}
+
+	let mut data = [0u16; 2];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 .. 29];
+	let (front, mut pivot, back) = bits.split_around_mut(15);
+	assert_eq!((front.len(), back.len()), (15, 10));
+	front.set_all(true);
+	back.set_all(true);
+	assert!(!*pivot);
+	pivot.set(true);
+	drop(pivot);
+	assert!(bits.all());
+	assert_eq!(data, [!0 << 3, !0 >> 3]);
+}
+
+#[test]
+#[should_panic]
+fn split_around_out_of_range() {
+	let _ = bits![0; 4].split_around(4);
+}
+
+#[test]
+fn reverse_each_chunk() {
+	fn check<O, T>(bits: &mut BitSlice<O, T>, chunk: usize)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		//  Reference: each output bit comes from the mirrored position of its
+		//  own group in the original.
+		let mut orig = bitarr![Lsb0, u32; 0; 96];
+		orig[.. bits.len()].clone_from_bitslice(bits);
+		let len = bits.len();
+		bits.reverse_each_chunk(chunk);
+		for idx in 0 .. len {
+			let start = idx - idx % chunk;
+			let end = core::cmp::min(start + chunk, len);
+			assert_eq!(bits[idx], orig[end - 1 - (idx - start)]);
+		}
+	}
+
+	let mut data = [0x1234_5678u32, 0x9ABC_DEF0, 0x0F1E_2D3C];
+	check(&mut data.view_bits_mut::<Lsb0>()[.. 90], 4);
+	check(&mut data.view_bits_mut::<Msb0>()[3 ..], 4);
+	check(&mut data.view_bits_mut::<Lsb0>()[.. 70], 32);
+	check(&mut data.view_bits_mut::<Msb0>()[1 .. 71], 32);
+	check(&mut data.view_bits_mut::<LocalBits>()[..], 7);
+
+	let mut data = [0x12u8, 0x34];
+	data.view_bits_mut::<Msb0>().reverse_each_chunk(4);
+	assert_eq!(data, [0x84, 0xC2]);
+	data.view_bits_mut::<Lsb0>().reverse_each_chunk(8);
+	assert_eq!(data, [0x21, 0x43]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn popcount_chunks() {
+	let data = [0x0Fu8, 0xFF, 0x81, 0x7E];
+	let bits = data.view_bits::<Lsb0>();
+
+	//  Element-aligned, with and without a partial final group.
+	assert_eq!(bits.popcount_chunks(8), [4, 8, 2, 6]);
+	assert_eq!(bits[.. 29].popcount_chunks(8), [4, 8, 2, 4]);
+
+	//  Unaligned start and group widths.
+	assert_eq!(bits[2 ..].popcount_chunks(8), [4, 7, 2, 5]);
+	assert_eq!(bits.popcount_chunks(5), [4, 2, 5, 2, 1, 5, 1]);
+	assert_eq!(bits.popcount_chunks(32), [20]);
+
+	assert!(BitSlice::<Lsb0, u8>::empty().popcount_chunks(3).is_empty());
+}
+
+/// An ordering that has no `BitField` implementation, used to exercise the
+/// fallback paths of methods that specialize on `Lsb0` and `Msb0`.
+struct Swap;
+
+unsafe impl BitOrder for Swap {
+	fn at<R>(index: BitIdx<R>) -> crate::index::BitPos<R>
+	where R: crate::mem::BitRegister {
+		crate::index::BitPos::new(index.value() ^ 1).unwrap()
+	}
+}
+
+#[test]
+fn iter_ones_zeros_sparse() {
+	fn check<O>(bits: &BitSlice<O, u16>)
+	where O: BitOrder {
+		let ones = || {
+			bits.iter()
+				.enumerate()
+				.filter(|(_, bit)| **bit)
+				.map(|(idx, _)| idx)
+		};
+		let zeros = || {
+			bits.iter()
+				.enumerate()
+				.filter(|(_, bit)| !**bit)
+				.map(|(idx, _)| idx)
+		};
+		assert!(bits.iter_ones().eq(ones()));
+		assert!(bits.iter_ones().rev().eq(ones().rev()));
+		assert!(bits.iter_zeros().eq(zeros()));
+		assert!(bits.iter_zeros().rev().eq(zeros().rev()));
+	}
+
+	let mut data = [0u16; 24];
+	data[2] = 0x0100;
+	data[13] = 0x8001;
+	data[23] = 0x0004;
+	check(&data.view_bits::<Lsb0>()[5 ..]);
+	check(&data.view_bits::<Msb0>()[5 .. 370]);
+	check(&data.view_bits::<Swap>()[5 .. 370]);
+
+	for elem in data.iter_mut() {
+		*elem = !*elem;
+	}
+	check(&data.view_bits::<Lsb0>()[3 .. 380]);
+	check(&data.view_bits::<Msb0>()[3 ..]);
+}
+
+#[test]
+fn leading_trailing_counts() {
+	let data = [0u16, 0x0300, 0, 0xFFFF, 0xFFFF, 0x7FFF];
+
+	let bits = &data.view_bits::<Lsb0>()[3 ..];
+	assert_eq!(bits.leading_zeros(), 21);
+	assert_eq!(bits.leading_ones(), 0);
+	assert_eq!(bits.trailing_zeros(), 1);
+	assert_eq!(bits[.. 90].trailing_ones(), 45);
+	assert_eq!(bits[21 ..].leading_ones(), 2);
+
+	let bits = &data.view_bits::<Msb0>()[3 ..];
+	assert_eq!(bits.leading_zeros(), 19);
+	assert_eq!(bits[19 ..].leading_ones(), 2);
+	assert_eq!(bits.trailing_ones(), 47);
+	assert_eq!(bits.trailing_zeros(), 0);
+
+	let bits = &data.view_bits::<Swap>()[3 ..];
+	assert_eq!(bits.leading_zeros(), 21);
+	assert_eq!(bits.trailing_ones(), 1);
+
+	let empty = BitSlice::<Msb0, u8>::empty();
+	assert_eq!(empty.leading_zeros(), 0);
+	assert_eq!(empty.trailing_ones(), 0);
+	assert_eq!(bits![0; 100].leading_zeros(), 100);
+	assert_eq!(bits![1; 100].trailing_ones(), 100);
+}
+
+#[test]
+fn find_subslice() {
+	let mut data = [0u32; 8];
+	for (idx, elem) in data.iter_mut().enumerate() {
+		*elem = (idx as u32 + 1).wrapping_mul(0x9E37_79B9);
+	}
+	let hay = &data.view_bits::<Lsb0>()[3 ..];
+
+	for &(start, len) in &[(0, 1), (17, 5), (40, 64), (61, 65), (100, 130)] {
+		let needle = &hay[start .. start + len];
+		let naive = hay.windows(len).position(|w| w == needle);
+		assert_eq!(hay.find(needle), naive);
+		assert!(naive.unwrap() <= start);
+		assert!(hay.contains(needle));
+
+		//  The needle may have different type parameters.
+		let mut other = [0u8; 32];
+		other.view_bits_mut::<Msb0>()[.. len].clone_from_bitslice(needle);
+		assert_eq!(hay.find(&other.view_bits::<Msb0>()[.. len]), naive);
+		assert_eq!(
+			hay.find(&other.view_bits::<Msb0>()[.. len]),
+			hay.iter()
+				.enumerate()
+				.map(|(idx, _)| idx)
+				.find(|&idx| hay[idx ..].starts_with(needle)),
+		);
+	}
+
+	let zeros = bits![0; 70];
+	assert_eq!(hay.find(zeros), None);
+	assert!(!hay.contains(zeros));
+	assert_eq!(hay.find(bits![]), Some(0));
+	assert!(hay.contains(bits![]));
+	assert_eq!(bits![0, 1].find(bits![0, 1, 0]), None);
+	//  0, 0, 1, 0, 1
+	let byte = 0x28u8;
+	let swapped = &byte.view_bits::<Swap>()[.. 5];
+	assert_eq!(swapped.find(bits![1, 0, 1]), Some(2));
+}
+
+#[test]
+fn iter_runs() {
+	fn check<O>(bits: &BitSlice<O, u16>)
+	where O: BitOrder {
+		//  No slice under test is longer than 96 bits, so neither are its runs.
+		let mut fwd = [(false, 0usize); 96];
+		let mut count = 0;
+		let mut idx = 0;
+		let mut prev = None;
+		for (value, run) in bits.iter_runs() {
+			assert!(run > 0);
+			assert_ne!(prev, Some(value));
+			assert!(bits[idx .. idx + run].iter().all(|&bit| bit == value));
+			fwd[count] = (value, run);
+			count += 1;
+			idx += run;
+			prev = Some(value);
+		}
+		assert_eq!(idx, bits.len());
+		assert!(bits.iter_runs().rev().eq(fwd[.. count].iter().rev().copied()));
+		assert_eq!(bits.iter_runs().last(), bits.iter_runs().next_back());
+	}
+
+	//  Runs that span element boundaries.
+	let data = [0xFFF0u16, 0xFFFF, 0x000F, 0x0000, 0x8001, 0x5555];
+	check(&data.view_bits::<Lsb0>()[2 ..]);
+	check(&data.view_bits::<Msb0>()[2 .. 90]);
+	check(&data.view_bits::<Swap>()[2 .. 90]);
+
+	let bits = &data.view_bits::<Lsb0>()[2 .. 70];
+	let mut runs = bits.iter_runs();
+	assert_eq!(runs.next(), Some((false, 2)));
+	assert_eq!(runs.next(), Some((true, 32)));
+	assert_eq!(runs.next(), Some((false, 28)));
+	assert_eq!(runs.next_back(), Some((false, 5)));
+	assert_eq!(runs.next_back(), Some((true, 1)));
+	assert!(runs.next().is_none());
+
+	assert!(BitSlice::<Lsb0, u8>::empty().iter_runs().next().is_none());
+}
+
+#[test]
+fn cmp_words() {
+	use core::cmp::Ordering;
+
+	let a = [0x5Au8; 24];
+	let b = [0x5A5Au16; 12];
+	let mut c = [0u8; 24];
+	let mut d = [0u8; 24];
+	c.view_bits_mut::<Swap>()
+		.clone_from_bitslice(a.view_bits::<Msb0>());
+	d.view_bits_mut::<Lsb0>()
+		.clone_from_bitslice(a.view_bits::<Msb0>());
+
+	//  Equal bit sequences compare equal, regardless of type parameters.
+	let a_bits = a.view_bits::<Msb0>();
+	assert_eq!(a_bits.cmp(a_bits), Ordering::Equal);
+	assert_eq!(a_bits.partial_cmp(b.view_bits::<Msb0>()), Some(Ordering::Equal));
+	assert_eq!(
+		a_bits[3 ..].partial_cmp(&c.view_bits::<Swap>()[3 ..]),
+		Some(Ordering::Equal)
+	);
+
+	//  A prefix is less than the slice that extends it.
+	assert!(a_bits[5 .. 150] < a_bits[5 .. 151]);
+	assert!(a_bits[.. 100] > a_bits[.. 70]);
+
+	//  Differences past the first word are found at the first differing index.
+	for &idx in &[0, 7, 63, 64, 100, 190] {
+		let mut x = a;
+		let mut y = d;
+		x.view_bits_mut::<Msb0>().set(idx, true);
+		y.view_bits_mut::<Lsb0>().set(idx, false);
+		y.view_bits_mut::<Lsb0>().set(idx + 1, true);
+		let x = &x.view_bits::<Msb0>()[1 ..];
+		let y = &y.view_bits::<Lsb0>()[1 ..];
+		assert_eq!(x.partial_cmp(y), Some(x.iter().cmp(y.iter())));
+		assert_eq!(y.partial_cmp(x), Some(y.iter().cmp(x.iter())));
+
+		let y = &c.view_bits::<Swap>()[1 ..];
+		assert_eq!(x.partial_cmp(y), Some(x.iter().cmp(y.iter())));
+	}
+}
+
+#[test]
+fn hash_across_types() {
+	use core::hash::{
+		Hash,
+		Hasher,
+	};
+
+	//  An FNV-1a hasher, which depends on every byte written to it.
+	struct Fnv(u64);
+	impl Hasher for Fnv {
+		fn finish(&self) -> u64 {
+			self.0
+		}
+
+		fn write(&mut self, bytes: &[u8]) {
+			for byte in bytes {
+				self.0 ^= *byte as u64;
+				self.0 = self.0.wrapping_mul(0x100_0000_01B3);
+			}
+		}
+	}
+
+	fn hash<O, T>(bits: &BitSlice<O, T>) -> u64
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut hasher = Fnv(0xCBF2_9CE4_8422_2325);
+		bits.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	let a = [0x3Cu8, 0xA5, 0x0F, 0x96, 0x81, 0x7E, 0x42, 0x99, 0x12, 0x34];
+	let mut b = [0u16; 5];
+	let mut c = [0u32; 3];
+	let mut d = [0u8; 10];
+	let src = &a.view_bits::<Msb0>()[3 ..];
+	b.view_bits_mut::<Lsb0>()[5 .. 82].clone_from_bitslice(src);
+	c.view_bits_mut::<Msb0>()[1 .. 78].clone_from_bitslice(src);
+	d.view_bits_mut::<Swap>()[.. 77].clone_from_bitslice(src);
+
+	let b = &b.view_bits::<Lsb0>()[5 .. 82];
+	let c = &c.view_bits::<Msb0>()[1 .. 78];
+	let d = &d.view_bits::<Swap>()[.. 77];
+	assert_eq!(src, b);
+	assert_eq!(src, c);
+	assert_eq!(src, d);
+	assert_eq!(hash(src), hash(b));
+	assert_eq!(hash(src), hash(c));
+	assert_eq!(hash(src), hash(d));
+
+	//  The length is part of the hash, so trailing zeros are not ignored.
+	assert_ne!(hash(&src[.. 64]), hash(&src[.. 65]));
+	assert_ne!(hash(&src[.. 0]), hash(&src[.. 1]));
+	assert_ne!(hash(src), hash(&src[1 ..]));
+}
+
+#[test]
+fn copy_within_elements() {
+	use core::ops::Range;
+
+	fn check<O>(offset: usize, src: Range<usize>, dest: usize)
+	where O: BitOrder {
+		let mut data = [0u16; 6];
+		for (idx, elem) in data.iter_mut().enumerate() {
+			*elem = 0x9A5C ^ (idx as u16).wrapping_mul(0x1111);
+		}
+		let before = data;
+
+		let mut expected = [false; 96];
+		let bits = &mut data.view_bits_mut::<O>()[offset ..];
+		for (slot, bit) in expected.iter_mut().zip(bits.iter()) {
+			*slot = *bit;
+		}
+		let original = expected;
+		for idx in 0 .. src.len() {
+			expected[dest + idx] = original[src.start + idx];
+		}
+
+		bits.copy_within(src, dest);
+		let expected = expected[.. bits.len()].iter().copied();
+		assert!(bits.iter().copied().eq(expected));
+		assert_eq!(
+			data.view_bits::<O>()[.. offset],
+			before.view_bits::<O>()[.. offset]
+		);
+	}
+
+	fn check_all<O>()
+	where O: BitOrder {
+		//  Whole elements, disjoint and overlapping in either direction.
+		check::<O>(0, 0 .. 32, 48);
+		check::<O>(0, 16 .. 80, 0);
+		check::<O>(0, 0 .. 64, 16);
+		check::<O>(16, 0 .. 32, 16);
+		check::<O>(5, 11 .. 43, 27);
+		//  Partial elements.
+		check::<O>(0, 3 .. 35, 19);
+		check::<O>(0, 16 .. 40, 32);
+		check::<O>(5, 0 .. 32, 16);
+		check::<O>(0, 16 .. 16, 0);
+	}
+
+	check_all::<Lsb0>();
+	check_all::<Msb0>();
+	check_all::<Swap>();
+}
+
+#[test]
+fn swap_bits() {
+	let mut data = [0x0Fu8, 0xF0];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.swap(3, 8);
+	assert_eq!(data, [0x1F, 0x70]);
+
+	//  Equal bits are left alone.
+	let bits = data.view_bits_mut::<Lsb0>();
+	bits.swap(0, 0);
+	bits.swap(1, 3);
+	bits.swap(15, 8);
+	assert_eq!(data, [0x1F, 0x70]);
+
+	let bits = data.view_bits_mut::<Lsb0>();
+	bits.swap(1, 5);
+	bits.swap(14, 8);
+	assert_eq!(data, [0x3D, 0x31]);
+
+	let mut data = [0x0Fu8, 0xF0];
+	let bits = data.view_bits_mut::<Swap>();
+	unsafe {
+		bits.swap_unchecked(3, 7);
+		bits.swap_unchecked(6, 12);
+	}
+	assert_eq!(data, [0xCB, 0xD0]);
+}
+
+#[test]
+#[should_panic]
+fn swap_out_of_bounds() {
+	let mut data = 0u16;
+	data.view_bits_mut::<Lsb0>()[.. 12].swap(3, 12);
+}
+
+#[test]
+fn bitwise_assign() {
+	fn check<O1, O2>(dst: usize, src: usize, len: usize)
+	where
+		O1: BitOrder,
+		O2: BitOrder,
+	{
+		let rhs = [0x3C5A_96E1u32, 0x0FF0_A55A, 0x1234_5678, 0x8001_7FFE];
+		let rhs = &rhs.view_bits::<O2>()[src .. src + len];
+		let init = [
+			0xA5u8, 0xFF, 0x00, 0x96, 0x3C, 0x81, 0x7E, 0x55, 0xC3, 0x18,
+		];
+
+		let mut data = init;
+		let mut oracle = init;
+		data.view_bits_mut::<O1>()[dst .. dst + len].and_assign(rhs);
+		oracle.view_bits_mut::<O1>()[dst .. dst + len] &= rhs.iter().copied();
+		assert_eq!(data, oracle);
+
+		data.view_bits_mut::<O1>()[dst .. dst + len].or_assign(rhs);
+		oracle.view_bits_mut::<O1>()[dst .. dst + len] |= rhs.iter().copied();
+		assert_eq!(data, oracle);
+
+		data.view_bits_mut::<O1>()[dst .. dst + len].xor_assign(rhs);
+		oracle.view_bits_mut::<O1>()[dst .. dst + len] ^= rhs.iter().copied();
+		assert_eq!(data, oracle);
+
+		data.view_bits_mut::<O1>()[dst .. dst + len].not_assign();
+		let _ = !&mut oracle.view_bits_mut::<O1>()[dst .. dst + len];
+		assert_eq!(data, oracle);
+	}
+
+	for &(dst, src, len) in &[
+		(0, 0, 80),
+		(3, 3, 70),
+		(3, 11, 70),
+		(5, 5, 2),
+		(0, 7, 64),
+		(8, 0, 72),
+	] {
+		check::<Lsb0, Lsb0>(dst, src, len);
+		check::<Msb0, Msb0>(dst, src, len);
+		check::<Lsb0, Msb0>(dst, src, len);
+		check::<Swap, Lsb0>(dst, src, len);
+		check::<Swap, Swap>(dst, src, len);
+	}
+
+	//  Same type parameters and alignment take the element-wise path.
+	let mut a = [0xF0F0u16, 0xFF00, 0x1234];
+	let b = [0xAAAAu16, 0x0F0F, 0xFFFF];
+	let bits = &mut a.view_bits_mut::<Swap>()[3 .. 44];
+	bits.xor_assign(&b.view_bits::<Swap>()[3 .. 44]);
+	assert_eq!(a, [0xF0F0 ^ (0xAAAA & !0b1011), 0xF00F, 0x1234 ^ 0x0FFF]);
+}
+
+#[test]
+#[should_panic]
+fn bitwise_assign_lengths() {
+	let mut data = 0u16;
+	data.view_bits_mut::<Lsb0>()[.. 9].or_assign(bits![0; 8]);
+}
+
+#[test]
+fn count_in_ranges() {
+	fn check<O, T>(bits: &BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = bits.len();
+		for start in 0 ..= len {
+			for end in start ..= len {
+				let sub = &bits[start .. end];
+				assert_eq!(bits.count_ones_in(start .. end), sub.count_ones());
+				assert_eq!(bits.count_zeros_in(start .. end), sub.count_zeros());
+			}
+		}
+		assert_eq!(bits.count_ones_in(..), bits.count_ones());
+		assert_eq!(bits.count_zeros_in(..), bits.count_zeros());
+	}
+
+	let data = [0x96A5_3C0Fu32, 0x0123_4567, 0xFEDC_BA98];
+	check(&data.view_bits::<Lsb0>()[5 .. 90]);
+	check(&data.view_bits::<Msb0>()[.. 64]);
+	check(&data.view_bits::<Swap>()[33 ..]);
+	let data = [0x5Au8, 0xC3, 0x0F, 0xF0, 0x99];
+	check(&data.view_bits::<Msb0>()[3 .. 37]);
+	check(&data.view_bits::<Lsb0>()[2 .. 5]);
+	check(BitSlice::<Lsb0, u8>::empty());
+}
+
+#[test]
+#[should_panic]
+fn count_in_out_of_bounds() {
+	bits![0, 1, 1].count_ones_in(1 .. 4);
+}
+
+#[test]
+fn count_large() {
+	//  Fill enough elements to take any accelerated path, with a remainder.
+	let mut data = [0u32; 601];
+	let mut state = 0x9E37_79B9u32;
+	for elem in data.iter_mut() {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		*elem = state;
+	}
+	let scalar = data.iter().map(|e| e.count_ones() as usize).sum::<usize>();
+
+	let bits = data.view_bits::<Lsb0>();
+	assert_eq!(bits.count_ones(), scalar);
+	assert_eq!(bits.count_zeros(), bits.len() - scalar);
+
+	let bits = &bits[3 .. bits.len() - 5];
+	assert_eq!(bits.count_ones(), bits.iter().filter(|b| **b).count());
+
+	let cells = core::cell::Cell::from_mut(&mut data[..]).as_slice_of_cells();
+	let bits = cells.view_bits::<Msb0>();
+	assert_eq!(bits.count_ones(), scalar);
+}
+
+#[test]
+fn first_last_bits() {
+	fn check<O, T>(bits: &BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		assert_eq!(bits.first_one(), bits.iter().position(|b| *b));
+		assert_eq!(bits.last_one(), bits.iter().rposition(|b| *b));
+		assert_eq!(bits.first_zero(), bits.iter().position(|b| !*b));
+		assert_eq!(bits.last_zero(), bits.iter().rposition(|b| !*b));
+	}
+
+	let mut data = [0usize; 4];
+	check(data.view_bits::<Lsb0>());
+	check(&data.view_bits::<Msb0>()[3 .. 250]);
+	assert!(data.view_bits::<Lsb0>().first_one().is_none());
+	assert_eq!(data.view_bits::<Lsb0>().first_zero(), Some(0));
+
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.set(100, true);
+	bits.set(150, true);
+	check(bits);
+	check(&bits[101 ..]);
+	check(&bits[.. 100]);
+	assert_eq!(bits.first_one(), Some(100));
+	assert_eq!(bits.last_one(), Some(150));
+	assert_eq!(bits[101 ..].first_one(), Some(49));
+
+	bits.set_all(true);
+	check(&bits[5 ..]);
+	assert!(bits.first_zero().is_none());
+	assert!(bits.last_zero().is_none());
+
+	check(0x96A5u16.view_bits::<Swap>());
+	check(BitSlice::<Lsb0, u8>::empty());
+}
+
+#[test]
+fn raw_slices() {
+	let mut data = [0x0123u16, 0x4567, 0x89AB, 0xCDEF];
+	let base = data.as_ptr();
+
+	let bits = &data.view_bits::<Lsb0>()[5 .. 50];
+	let raw = bits.as_raw_slice();
+	assert_eq!(raw.as_ptr(), base);
+	assert_eq!(raw, &[0x0123, 0x4567, 0x89AB, 0xCDEF]);
+	assert_eq!(bits[11 .. 27].as_raw_slice(), &[0x4567]);
+
+	let bits = &mut data.view_bits_mut::<Lsb0>()[16 .. 48];
+	unsafe {
+		bits.as_raw_mut_slice()[1] = 0;
+	}
+	assert!(bits[16 ..].not_any());
+	assert_eq!(data, [0x0123, 0x4567, 0, 0xCDEF]);
+
+	let bytes = [0xA5u8; 32];
+	let bits = &bytes.view_bits::<Msb0>()[1 .. 250];
+	let (head, body, tail) = unsafe { bits.align_to::<u32>() };
+	assert_eq!(head.len() + body.len() + tail.len(), bits.len());
+	assert_eq!(body.len(), body.as_raw_slice().len() * 32);
+	assert!(body.as_raw_slice().iter().all(|&word| word == 0xA5A5_A5A5));
+}
+
+#[test]
+fn fill() {
+	let mut data = [0u16; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 59];
+	bits.fill(true);
+	assert!(bits.all());
+	assert_eq!(data, [0x07FF, !0, !0, 0xFFE0]);
+
+	let bits = &mut data.view_bits_mut::<Msb0>()[6 .. 9];
+	bits.fill(false);
+	assert_eq!(data[0], 0x047F);
+
+	let mut data = [!0u8; 3];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 .. 21];
+	bits.fill_with(|idx| idx % 2 == 1);
+	assert!(bits.iter().enumerate().all(|(idx, bit)| *bit == (idx % 2 == 1)));
+	assert_eq!(data[0] & 0x07, 0x07);
+	assert_eq!(data[2] & 0xE0, 0xE0);
+
+	let mut calls = 0;
+	bits![mut 0; 10].fill_with(|idx| {
+		assert_eq!(idx, calls);
+		calls += 1;
+		false
+	});
+	assert_eq!(calls, 10);
+}
+
+#[test]
+fn transform_elements() {
+	//  Identity.
+	let mut data = [0x1234u16, 0x5678, 0x9ABC];
+	data.view_bits_mut::<Msb0>()[3 .. 45].transform_elements(|elem| elem);
+	assert_eq!(data, [0x1234, 0x5678, 0x9ABC]);
+
+	//  Inversion matches `!`, including in the partial edge elements.
+	let mut data = [0x1234u16, 0x5678, 0x9ABC];
+	let mut expected = data;
+	data.view_bits_mut::<Msb0>()[3 .. 45].transform_elements(|elem| !elem);
+	let _ = !&mut expected.view_bits_mut::<Msb0>()[3 .. 45];
+	assert_eq!(data, expected);
+	assert_eq!(data[1], !0x5678);
+
+	//  Byte swaps of whole elements.
+	let mut data = [0x1122_3344u32, 0x5566_7788];
+	data.view_bits_mut::<Lsb0>().transform_elements(u32::swap_bytes);
+	assert_eq!(data, [0x4433_2211, 0x8877_6655]);
+
+	//  A partial element keeps its bits outside the slice.
+	let mut data = [0x1122_3344u32, 0x5566_7788];
+	data.view_bits_mut::<Lsb0>()[8 .. 56].transform_elements(u32::swap_bytes);
+	assert_eq!(data, [0x4433_2244, 0x5577_6655]);
+
+	//  An enclave passes its one element to the function once.
+	let mut data = 0b1100_0011u8;
+	let mut calls = 0;
+	data.view_bits_mut::<Lsb0>()[2 .. 6].transform_elements(|elem| {
+		calls += 1;
+		elem.reverse_bits()
+	});
+	assert_eq!(calls, 1);
+	assert_eq!(data, 0b1100_0011);
+	data.view_bits_mut::<Lsb0>()[1 .. 7].transform_elements(|elem| !elem);
+	assert_eq!(data, 0b1011_1101);
+}
+
+#[test]
+fn write_bools_at() {
+	fn check<O, T>(bits: &mut BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let stream = |idx: usize| idx % 3 == 0 || idx % 11 == 5;
+		let len = bits.len();
+		bits.set_all(false);
+
+		//  A short stream stops early and leaves the rest untouched.
+		assert_eq!(bits.write_bools_at(7, (0 .. 90).map(stream)), 90);
+		for idx in 0 .. len {
+			let expected = (7 .. 97).contains(&idx) && stream(idx - 7);
+			assert_eq!(bits[idx], expected);
+		}
+
+		//  A long stream is truncated at the end of the slice.
+		bits.set_all(true);
+		let written = bits.write_bools_at(40, (0 ..).map(stream));
+		assert_eq!(written, len - 40);
+		assert!(bits[.. 40].all());
+		for idx in 40 .. len {
+			assert_eq!(bits[idx], stream(idx - 40));
+		}
+
+		assert_eq!(bits.write_bools_at(len, (0 ..).map(stream)), 0);
+	}
+
+	let mut data = [0u8; 24];
+	check(&mut data.view_bits_mut::<Lsb0>()[3 .. 181]);
+	check(&mut data.view_bits_mut::<Msb0>()[3 .. 181]);
+	let mut data = [0u32; 6];
+	check(&mut data.view_bits_mut::<Lsb0>()[5 .. 190]);
+	check(&mut data.view_bits_mut::<Msb0>()[5 .. 190]);
+	check(&mut data.view_bits_mut::<Swap>()[.. 100]);
+}
+
+#[test]
+#[should_panic]
+fn write_bools_at_offset() {
+	bits![mut 0; 4].write_bools_at(5, core::iter::empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn rank_index() {
+	use crate::slice::RankIndex;
+
+	let mut data = [0u16; 20];
+	for (idx, elem) in data.iter_mut().enumerate() {
+		*elem = (idx as u16).wrapping_mul(0x9E37) ^ 0x5A5A;
+	}
+	let bits = &data.view_bits::<Msb0>()[5 .. 301];
+
+	for &block in &[1, 2, 3, 8, 32] {
+		let index = RankIndex::new(bits, block);
+		assert_eq!(index.block_bits(), block * 16);
+		for idx in 0 ..= bits.len() {
+			assert_eq!(index.rank_ones(idx), bits.rank_ones(idx));
+			assert_eq!(index.rank_zeros(idx), bits.rank_zeros(idx));
+		}
+	}
+
+	let index = RankIndex::new(BitSlice::<Lsb0, u8>::empty(), 4);
+	assert_eq!(index.rank_ones(0), 0);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn rank_index_out_of_range() {
+	let data = [0u8; 2];
+	let index = crate::slice::RankIndex::new(data.view_bits::<Lsb0>(), 1);
+	let _ = index.rank_ones(17);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn set_operations() {
+	fn check<O, T>(a: &BitSlice<O, T>, b: &BitSlice<Msb0, u16>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let union = a.union(b);
+		let intersection = a.intersection(b);
+		let difference = a.difference(b);
+		let symmetric = a.symmetric_difference(b);
+		for (idx, (x, y)) in a.iter().zip(b.iter()).enumerate() {
+			assert_eq!(union[idx], *x | *y);
+			assert_eq!(intersection[idx], *x & *y);
+			assert_eq!(difference[idx], *x & !*y);
+			assert_eq!(symmetric[idx], *x ^ *y);
+		}
+		for out in &[union, intersection, difference, symmetric] {
+			assert_eq!(out.len(), a.len());
+			//  Storage bits past the end of the vector are cleared.
+			let live = out.len();
+			let all = out.as_raw_slice().view_bits::<O>();
+			assert!(all[live ..].not_any());
+		}
+	}
+
+	let mut data = [0u16; 8];
+	let b = &mut data.view_bits_mut::<Msb0>()[5 .. 105];
+	b.for_each(|idx, _| idx % 3 == 0);
+
+	let mut data = [!0u32; 4];
+	let a = &mut data.view_bits_mut::<Lsb0>()[3 .. 103];
+	a.for_each(|idx, _| idx % 2 == 0);
+	check(a, b);
+
+	let mut data = [!0u8; 14];
+	let a = &mut data.view_bits_mut::<Swap>()[7 .. 107];
+	a.for_each(|idx, _| idx % 5 < 2);
+	check(a, b);
+
+	let empty = BitSlice::<Lsb0, u8>::empty();
+	assert!(empty.union(BitSlice::<Msb0, u16>::empty()).is_empty());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn set_operations_lengths() {
+	bits![0; 4].union(bits![0; 5]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn diff() {
+	fn reference<O1, T1, O2, T2>(
+		a: &BitSlice<O1, T1>,
+		b: &BitSlice<O2, T2>,
+	) -> Vec<(usize, usize)>
+	where
+		O1: BitOrder,
+		T1: BitStore,
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut runs: Vec<(usize, usize)> = Vec::new();
+		for idx in (0 .. a.len()).filter(|&idx| a[idx] != b[idx]) {
+			match runs.last_mut() {
+				Some(run) if run.1 == idx => run.1 += 1,
+				_ => runs.push((idx, idx + 1)),
+			}
+		}
+		runs
+	}
+
+	let mut one = [0u8; 40];
+	let mut two = [0u16; 20];
+	let a = &mut one.view_bits_mut::<Lsb0>()[3 .. 300];
+	let b = &mut two.view_bits_mut::<Msb0>()[11 .. 308];
+	assert!(a.diff(b).is_empty());
+
+	//  Scattered single-bit differences, at chunk edges and slice edges.
+	for &idx in &[0, 31, 32, 63, 64, 65, 200, 296] {
+		a.set(idx, true);
+	}
+	assert_eq!(a.diff(b), reference(a, b));
+	assert_eq!(a.diff(b)[.. 3], [(0, 1), (31, 33), (63, 66)]);
+
+	//  Contiguous runs that cross chunk boundaries.
+	b[50 .. 140].set_all(true);
+	b[250 ..].set_all(true);
+	assert_eq!(a.diff(b), reference(a, b));
+	assert_eq!(*a.diff(b).last().unwrap(), (250, 296));
+	assert_eq!(b.diff(a), reference(b, a));
+
+	//  Orderings without `BitField` fall back to bitwise comparison.
+	let mut swap = BitVec::<Swap, u32>::repeat(false, 297);
+	swap[40 .. 90].set_all(true);
+	let swap = swap.as_bitslice();
+	assert_eq!(a.diff(swap), reference(a, swap));
+	assert_eq!(swap.diff(b), reference(swap, b));
+
+	assert!(BitSlice::<Lsb0, u8>::empty()
+		.diff(BitSlice::<Msb0, u16>::empty())
+		.is_empty());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn diff_lengths() {
+	let _ = bits![0; 4].diff(bits![0; 5]);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_fill_with() {
+	let func = |idx: usize| idx.count_ones() % 2 == 1 || idx % 7 == 0;
+
+	let mut data = [0u16; 1024];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 16_000];
+	bits.par_fill_with(func);
+	assert!(bits.iter().enumerate().all(|(idx, bit)| *bit == func(idx)));
+
+	//  Edge elements keep the bits outside the slice.
+	let mut data = [!0u8; 4];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[2 .. 30];
+	bits.par_fill_with(|_| false);
+	assert_eq!(data, [0x03, 0, 0, 0xC0]);
+
+	let mut data = !0u8;
+	data.view_bits_mut::<Lsb0>()[2 .. 6].par_fill_with(|idx| idx == 1);
+	assert_eq!(data, 0b1100_1011);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_count_ones() {
+	use rayon::prelude::*;
+
+	let mut state = 0x2545_F491u32;
+	let mut data = [0u32; 700];
+	for elem in data.iter_mut() {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		*elem = state;
+	}
+	let bits = data.view_bits::<Lsb0>();
+
+	for &(start, end) in &[(0, 22400), (3, 22400), (0, 22395), (7, 20001)] {
+		let bits = &bits[start .. end];
+		assert_eq!(bits.par_count_ones(), bits.count_ones());
+		assert_eq!(
+			bits.par_iter().filter(|bit| *bit).count(),
+			bits.count_ones(),
+		);
+	}
+	assert!(bits.par_iter().enumerate().all(|(idx, bit)| bit == bits[idx]));
+
+	let bits = &bits[5 .. 20];
+	assert_eq!(bits.par_count_ones(), bits.count_ones());
+	assert_eq!(bits![].par_count_ones(), 0);
+}
+
+#[test]
+#[cfg(feature = "rand_core")]
+fn fill_random() {
+	use rand_core::{
+		Error,
+		RngCore,
+	};
+
+	//  Produces only set bits, so that every written bit is visible.
+	struct Ones;
+	impl RngCore for Ones {
+		fn next_u32(&mut self) -> u32 {
+			!0
+		}
+
+		fn next_u64(&mut self) -> u64 {
+			!0
+		}
+
+		fn fill_bytes(&mut self, dest: &mut [u8]) {
+			for byte in dest {
+				*byte = !0;
+			}
+		}
+
+		fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+			self.fill_bytes(dest);
+			Ok(())
+		}
+	}
+
+	let mut data = [0u8; 4];
+	data.view_bits_mut::<Lsb0>()[2 .. 30].fill_random(&mut Ones);
+	assert_eq!(data, [0xFC, 0xFF, 0xFF, 0x3F]);
+
+	let mut data = [0u16; 3];
+	data.view_bits_mut::<Msb0>()[5 ..].fill_random(&mut Ones);
+	assert_eq!(data, [0x07FF, !0, !0]);
+
+	let mut data = 0u8;
+	data.view_bits_mut::<Lsb0>()[2 .. 6].fill_random(&mut Ones);
+	assert_eq!(data, 0b0011_1100);
+
+	BitSlice::<Lsb0, u8>::empty_mut().fill_random(&mut Ones);
+}
+
 #[cfg(feature = "alloc")]
 mod format {
 	use crate::prelude::*;
@@ -1010,4 +2617,33 @@ mod format {
 ]"
 		);
 	}
+
+	#[test]
+	fn display_groups() {
+		let data = [0xA5u8, 0x3C, 0x80];
+		let bits = &data.view_bits::<Msb0>()[.. 18];
+
+		assert_eq!(
+			format!("{}", bits.display_groups(4, '_')),
+			"1010_0101_0011_1100_10"
+		);
+		assert_eq!(
+			format!("{:#}", bits[.. 8].display_groups(8, ' ')),
+			"0b10100101"
+		);
+		assert_eq!(format!("{:x}", bits.display_groups(8, '_')), "a5_3c_8");
+		assert_eq!(format!("{:#X}", bits.display_groups(16, ' ')), "0xA53C 8");
+		assert_eq!(format!("{:x}", bits[1 .. 7].display_groups(4, '_')), "4_8");
+		assert_eq!(format!("{:x}", bits[.. 5].display_groups(3, '.')), "a.0");
+
+		let bits = data.view_bits::<Lsb0>();
+		assert_eq!(format!("{:X}", bits.display_groups(8, ':')), "A5:3C:01");
+		assert_eq!(format!("{}", bits[.. 0].display_groups(1, ',')), "");
+	}
+
+	#[test]
+	#[should_panic]
+	fn display_groups_empty() {
+		let _ = 0u8.view_bits::<Msb0>().display_groups(0, ' ');
+	}
 }
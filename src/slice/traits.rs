@@ -169,7 +169,16 @@ where
 The comparison sorts by testing at each index if one slice has a high bit where
 the other has a low. At the first index where the slices differ, the slice with
 the high bit is greater. If the slices are equal until at least one terminates,
-then they are compared by length.
+then they are compared by length, so a slice that is a prefix of a longer slice
+is less than it.
+
+This is a lexicographic ordering over the bit *indices* of the slices. It does
+not depend on the ordering or storage type parameters, so slices of different
+types that hold the same sequence of bits compare as equal, and the ordering is
+not the ordering of the underlying memory elements as integers.
+
+When the ordering parameters are `Lsb0` or `Msb0`, the slices are loaded and
+compared a `usize` at a time; other orderings are compared bit by bit.
 **/
 impl<O1, O2, T1, T2> PartialOrd<BitSlice<O2, T2>> for BitSlice<O1, T1>
 where
@@ -179,11 +188,21 @@ where
 	T2: BitStore,
 {
 	fn partial_cmp(&self, rhs: &BitSlice<O2, T2>) -> Option<cmp::Ordering> {
-		for (l, r) in self.iter().zip(rhs.iter()) {
-			match (l, r) {
-				(true, false) => return Some(cmp::Ordering::Greater),
-				(false, true) => return Some(cmp::Ordering::Less),
-				_ => continue,
+		let len = cmp::min(self.len(), rhs.len());
+		let width = <usize as BitMemory>::BITS as usize;
+		let this = unsafe { self.get_unchecked(.. len) };
+		let that = unsafe { rhs.get_unchecked(.. len) };
+		for (l, r) in this.chunks(width).zip(that.chunks(width)) {
+			let l = l.load_word();
+			let diff = l ^ r.load_word();
+			if diff != 0 {
+				//  The lowest set bit of `diff` is the first differing index.
+				return Some(if l >> diff.trailing_zeros() & 1 == 1 {
+					cmp::Ordering::Greater
+				}
+				else {
+					cmp::Ordering::Less
+				});
 			}
 		}
 		self.len().partial_cmp(&rhs.len())
@@ -493,8 +512,14 @@ fmt!(Octal, b'0', b'o', 3);
 fmt!(LowerHex, b'a', b'x', 4);
 fmt!(UpperHex, b'A', b'x', 4);
 
-/// Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
-#[cfg(not(tarpaulin_include))]
+/** Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
+
+The length is written first, followed by the bits packed into `usize` words,
+with bit `n` of the slice in bit `n % usize::BITS` of word `n / usize::BITS`,
+and the unused high bits of the last word cleared. This depends only on the
+sequence of bits, not on the ordering or storage type parameters, so slices that
+compare as equal also hash equally.
+**/
 impl<O, T> Hash for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -502,8 +527,9 @@ where
 {
 	fn hash<H>(&self, hasher: &mut H)
 	where H: Hasher {
-		for bit in self {
-			hasher.write_u8(*bit as u8);
+		hasher.write_usize(self.len());
+		for word in self.chunks(<usize as BitMemory>::BITS as usize) {
+			hasher.write_usize(word.load_word());
 		}
 	}
 }
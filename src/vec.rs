@@ -260,7 +260,9 @@ where
 	/// Constructs a `BitVec` from a value repeated many times.
 	///
 	/// This function is equivalent to the `bitvec![O, T; bit; len]` [macro]
-	/// call, and is in fact the implementation of that macro syntax.
+	/// call, and is in fact the implementation of that macro syntax. To tile a
+	/// pattern of several bits, use [`BitSlice::repeat`], which is also
+	/// available as a method on `BitVec` values.
 	///
 	/// # Parameters
 	///
@@ -280,6 +282,7 @@ where
 	/// assert_eq!(bv, bits![1; 20]);
 	/// ```
 	///
+	/// [`BitSlice::repeat`]: crate::slice::BitSlice::repeat
 	/// [macro]: macro@crate::bitvec
 	pub fn repeat(bit: bool, len: usize) -> Self {
 		let mut out = Self::with_capacity(len);
@@ -290,6 +293,226 @@ where
 		out
 	}
 
+	/// Constructs a `BitVec` from a sparse list of set-bit indices.
+	///
+	/// This is the inverse of [`.to_sparse_indices()`].
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of live bits in the constructed `BitVec`.
+	/// - `indices`: The indices of the bits to set. They may appear in any
+	///   order, and repeated indices are permitted.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` with `len` live bits, where each bit in `indices` is set and
+	/// all other bits are cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if any index in `indices` is not less than `len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Lsb0, u8>::from_sparse_indices(10, &[1, 4, 9]);
+	/// assert_eq!(bv, bits![0, 1, 0, 0, 1, 0, 0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`.to_sparse_indices()`]: Self::to_sparse_indices
+	pub fn from_sparse_indices(len: usize, indices: &[usize]) -> Self {
+		let mut out = Self::repeat(false, len);
+		for &idx in indices {
+			out.set(idx, true);
+		}
+		out
+	}
+
+	/// Constructs a `BitVec` from one bit of each of a sequence of samples.
+	///
+	/// This is a bit-plane decomposition: the bit at position `k` of each
+	/// sample, where `0` is the least significant bit, becomes one bit of the
+	/// output, in the same order as the samples.
+	///
+	/// # Parameters
+	///
+	/// - `samples`: The integers from which to take bits.
+	/// - `k`: The numeric significance of the bit to take from each sample.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` whose `n`th bit is bit `k` of `samples[n]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `k` is not less than `16`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let samples = [0x0001u16, 0x0003, 0x0002, 0x8000];
+	/// let plane = BitVec::<Lsb0, usize>::extract_plane(&samples, 1);
+	/// assert_eq!(plane, bits![0, 1, 1, 0]);
+	/// ```
+	pub fn extract_plane(samples: &[u16], k: u32) -> Self {
+		assert!(k < 16, "Cannot take bit {} of a 16-bit sample", k);
+		let mut out = Self::with_capacity(samples.len());
+		out.extend(samples.iter().map(|&sample| (sample >> k) & 1 != 0));
+		out
+	}
+
+	/// Applies HDLC bit stuffing to a bit-slice.
+	///
+	/// HDLC frames are delimited by the flag sequence `0111_1110`. To keep the
+	/// flag out of the frame body, the sender inserts a `0` after every run of
+	/// five consecutive `1` bits, so that the body never contains six `1`s in
+	/// a row.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The unstuffed frame body.
+	///
+	/// # Returns
+	///
+	/// A copy of `data` with a `0` inserted after every fifth consecutive `1`.
+	/// This includes a run of five `1`s at the very end of `data`, which is
+	/// followed by a `0` just as a run in the interior is.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = bits![0, 1, 1, 1, 1, 1, 1, 0];
+	/// let stuffed = BitVec::<Lsb0, u8>::bit_stuff(data);
+	/// assert_eq!(stuffed, bits![0, 1, 1, 1, 1, 1, 0, 1, 0]);
+	/// ```
+	pub fn bit_stuff<O2, T2>(data: &BitSlice<O2, T2>) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut out = Self::with_capacity(data.len() + data.len() / 5);
+		let mut rest = data;
+		while let Some((value, run)) = rest.leading_run() {
+			if value {
+				for _ in 0 .. run / 5 {
+					out.grow_filled(5, true);
+					out.push(false);
+				}
+				out.grow_filled(run % 5, true);
+			}
+			else {
+				out.grow_filled(run, false);
+			}
+			rest = unsafe { rest.get_unchecked(run ..) };
+		}
+		out
+	}
+
+	/// Removes HDLC bit stuffing from a bit-slice.
+	///
+	/// This is the inverse of [`::bit_stuff()`].
+	///
+	/// # Parameters
+	///
+	/// - `data`: A stuffed frame body.
+	///
+	/// # Returns
+	///
+	/// A copy of `data` with the `0` that follows each run of five `1`s
+	/// removed. If `data` ends immediately after a run of five `1`s, there is
+	/// no stuffed bit to remove.
+	///
+	/// This returns `None` if `data` contains six or more consecutive `1`s,
+	/// which [`::bit_stuff()`] never produces.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let stuffed = bits![0, 1, 1, 1, 1, 1, 0, 1, 0];
+	/// let data = BitVec::<Lsb0, u8>::bit_unstuff(stuffed).unwrap();
+	/// assert_eq!(data, bits![0, 1, 1, 1, 1, 1, 1, 0]);
+	///
+	/// assert!(BitVec::<Lsb0, u8>::bit_unstuff(bits![1; 6]).is_none());
+	/// ```
+	///
+	/// [`::bit_stuff()`]: Self::bit_stuff
+	pub fn bit_unstuff<O2, T2>(data: &BitSlice<O2, T2>) -> Option<Self>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut out = Self::with_capacity(data.len());
+		let mut rest = data;
+		while let Some((value, run)) = rest.leading_run() {
+			if value && run > 5 {
+				return None;
+			}
+			out.grow_filled(run, value);
+			rest = unsafe { rest.get_unchecked(run ..) };
+			//  The run is maximal, so the next bit is the stuffed `0`.
+			if value && run == 5 && !rest.is_empty() {
+				rest = unsafe { rest.get_unchecked(1 ..) };
+			}
+		}
+		Some(out)
+	}
+
+	/// Decodes a sequence of alternating run lengths into a bit-vector.
+	///
+	/// This is the inverse of [`BitSlice::rle_encode`].
+	///
+	/// # Parameters
+	///
+	/// - `runs`: The lengths of consecutive runs of identical bits. Entries may
+	///   be zero.
+	/// - `start_bit`: The value of the bits in the first run. Each following
+	///   run has the opposite value of the run before it. Use `false` to
+	///   decode the output of [`BitSlice::rle_encode`].
+	///
+	/// # Returns
+	///
+	/// A bit-vector whose length is the sum of `runs`. Each run is written
+	/// with [`.grow_filled()`], which fills whole elements at a time.
+	///
+	/// # Panics
+	///
+	/// This panics if the sum of `runs` exceeds the maximum length of a
+	/// [`BitSlice`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Msb0, u8>::rle_decode(&[2, 3, 1, 1], false);
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 1, 0, 1]);
+	///
+	/// let bv = BitVec::<Msb0, u8>::rle_decode(&[2, 1], true);
+	/// assert_eq!(bv, bits![1, 1, 0]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`BitSlice::rle_encode`]: crate::slice::BitSlice::rle_encode
+	/// [`.grow_filled()`]: Self::grow_filled
+	pub fn rle_decode(runs: &[u32], start_bit: bool) -> Self {
+		let total = runs.iter().map(|&run| run as usize).sum();
+		let mut out = Self::with_capacity(total);
+		let mut value = start_bit;
+		for &run in runs {
+			out.grow_filled(run as usize, value);
+			value = !value;
+		}
+		out
+	}
+
 	/// Copies the contents of a [`BitSlice`] into a new allocation.
 	///
 	/// This is an exact copy: the newly-created vector is initialized with a
@@ -355,6 +578,14 @@ where
 	///
 	/// A `BitVec` over the `vec` buffer.
 	///
+	/// # Behavior
+	///
+	/// The allocation of `vec` is adopted as-is. Every element of `vec` is
+	/// fully live, so the bit-vector begins at bit `0` of the first element,
+	/// and its length is `vec.len() * T::Mem::BITS`. Its
+	/// [`.alloc_capacity()`] is `vec.capacity()`, measured in elements, and so
+	/// its [`.capacity()`] is `vec.capacity() * T::Mem::BITS`.
+	///
 	/// # Panics
 	///
 	/// This panics if `vec` is too long to convert into a `BitVec`. See
@@ -368,10 +599,19 @@ where
 	/// let vec = vec![0u8; 4];
 	/// let bv = BitVec::<LocalBits, _>::from_vec(vec);
 	/// assert_eq!(bv, bits![0; 32]);
+	///
+	/// let mut vec = Vec::<u16>::with_capacity(10);
+	/// vec.extend_from_slice(&[0x0F0F, 0xFFFF]);
+	/// let bv = BitVec::<Lsb0, _>::from_vec(vec);
+	/// assert_eq!(bv.len(), 32);
+	/// assert_eq!(bv.count_ones(), 24);
+	/// assert_eq!(bv.alloc_capacity(), 10);
 	/// ```
 	///
 	/// [`BitSlice::MAX_ELTS`]: crate::slice::BitSlice::MAX_ELTS
 	/// [`Vec<T>`]: alloc::vec::Vec
+	/// [`.alloc_capacity()`]: Self::alloc_capacity
+	/// [`.capacity()`]: Self::capacity
 	pub fn from_vec(vec: Vec<T>) -> Self {
 		Self::try_from_vec(vec)
 			.expect("Vector was too long to be converted into a `BitVec`")
@@ -452,8 +692,15 @@ where
 	///
 	/// # Behavior
 	///
-	/// `self` is extended by the length of `other`, and then the contents of
-	/// `other` are copied into the newly-allocated end of `self`.
+	/// `self` is extended by the length of `other`, reserving all of the new
+	/// capacity at once, and then the contents of `other` are copied into the
+	/// newly-allocated end of `self` with [`.clone_from_bitslice()`]. When the
+	/// type parameters match, this uses [`.copy_from_bitslice()`], which copies
+	/// whole elements directly if the end of `self` and the start of `other`
+	/// have the same index within their elements, and otherwise moves the bits
+	/// in batched `usize` transfers when `O` is `Lsb0` or `Msb0`.
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
@@ -465,6 +712,8 @@ where
 	/// ```
 	///
 	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`.clone_from_bitslice()`]: crate::slice::BitSlice::clone_from_bitslice
+	/// [`.copy_from_bitslice()`]: crate::slice::BitSlice::copy_from_bitslice
 	//  Implementation note: per #85, users want this method to stay generic.
 	pub fn extend_from_bitslice<O2, T2>(&mut self, other: &BitSlice<O2, T2>)
 	where
@@ -477,6 +726,191 @@ where
 		unsafe { self.get_unchecked_mut(len ..) }.clone_from_bitslice(other);
 	}
 
+	/// Appends `additional` bits, all set to `value`, to the end of the
+	/// vector.
+	///
+	/// This is the relative counterpart to [`.resize()`], which takes an
+	/// absolute length. It is useful for padding a buffer out to a frame
+	/// boundary.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of bits to append.
+	/// - `value`: The value of each appended bit.
+	///
+	/// # Behavior
+	///
+	/// The vector reserves space for the new bits, then writes them with
+	/// [`.set_all()`]. Any partially-filled element at the current end of the
+	/// vector is written with a mask that leaves its live bits untouched, and
+	/// the remaining fully-new elements are filled with `0` or `!0` directly.
+	///
+	/// # Panics
+	///
+	/// This panics if the new length exceeds the maximum length of a
+	/// [`BitSlice`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 0, 1];
+	/// bv.grow_filled(9, true);
+	/// assert_eq!(bv.len(), 12);
+	/// assert_eq!(bv.as_slice(), &[0b1011_1111, 0b1111_0000]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`.resize()`]: Self::resize
+	/// [`.set_all()`]: crate::slice::BitSlice::set_all
+	pub fn grow_filled(&mut self, additional: usize, value: bool) {
+		let len = self.len();
+		let new_len = len
+			.checked_add(additional)
+			.expect("Vector length overflow");
+		self.reserve(additional);
+		unsafe {
+			self.set_len(new_len);
+			self.get_unchecked_mut(len ..).set_all(value);
+		}
+	}
+
+	/// Copies the vector into a new vector with different type parameters.
+	///
+	/// The new vector holds the same sequence of bits as `self`, in the same
+	/// order, but packs them into `T2` memory elements according to the `O2`
+	/// ordering. Use this to change the memory layout of a bit-vector without
+	/// changing its contents.
+	///
+	/// # Type Parameters
+	///
+	/// - `O2`: The ordering of the new vector.
+	/// - `T2`: The storage type of the new vector.
+	///
+	/// # Returns
+	///
+	/// A new bit-vector whose contents are equal to `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![Msb0, u8; 1, 1, 0, 0, 1, 0, 1, 0, 1];
+	/// let tc = bv.transcode::<Lsb0, u16>();
+	///
+	/// assert_eq!(tc, bv);
+	/// assert_eq!(tc.as_slice(), &[0b1_0101_0011]);
+	/// ```
+	pub fn transcode<O2, T2>(&self) -> BitVec<O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut out = BitVec::with_capacity(self.len());
+		out.extend_from_bitslice(self.as_bitslice());
+		out
+	}
+
+	/// Lists the indices of all set bits in the vector.
+	///
+	/// This converts the dense bit-vector into a sparse representation, which
+	/// is more compact when only a small fraction of the bits are set. The
+	/// indices are found with [`.iter_ones()`], which skips over cleared
+	/// memory elements without inspecting their bits.
+	///
+	/// # Returns
+	///
+	/// The index of each set bit, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 1, 0, 0, 1, 0, 0, 0, 0, 1];
+	/// assert_eq!(bv.to_sparse_indices(), vec![1, 4, 9]);
+	/// ```
+	///
+	/// [`.iter_ones()`]: crate::slice::BitSlice::iter_ones
+	pub fn to_sparse_indices(&self) -> Vec<usize> {
+		self.iter_ones().collect()
+	}
+
+	/// Rotates the vector in-place such that the first `by` bits move to the
+	/// end while the last `self.len() - by` bits move to the front.
+	///
+	/// This has the same effect as [`BitSlice::rotate_left`], which it shadows,
+	/// but takes advantage of the vector’s ownership of its buffer to avoid
+	/// moving every bit on each call. It is intended for circular buffers that
+	/// rotate by small amounts many times.
+	///
+	/// # Behavior
+	///
+	/// When `by` is less than the width of `T`, the first `by` bits are copied
+	/// to the end of the vector, and the start of the vector is then advanced
+	/// past them within its first element. Only when the start would cross
+	/// into the next element is the whole vector moved back to the front of
+	/// the buffer, as with [`.force_align()`]. Rotations by `T`’s width or more
+	/// fall back to [`BitSlice::rotate_left`].
+	///
+	/// As with [`BitSlice::rotate_left`], `by` is reduced modulo `self.len()`.
+	///
+	/// # Complexity
+	///
+	/// A rotation by `by` bits, where `by` is less than the width of `T`, costs
+	/// `O(by)` time, plus an `O(self.len())` realignment once every
+	/// `T::Mem::BITS / by` calls, for an amortized cost of `O(by +
+	/// self.len() * by / T::Mem::BITS)`. The vector may need up to one element
+	/// of spare capacity, which it reserves as needed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 0, 0, 1, 1, 0];
+	/// bv.rotate_left(1);
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 0, 1]);
+	/// bv.rotate_left(2);
+	/// assert_eq!(bv, bits![1, 1, 0, 1, 0, 0]);
+	/// ```
+	///
+	/// [`BitSlice::rotate_left`]: crate::slice::BitSlice::rotate_left
+	/// [`.force_align()`]: Self::force_align
+	pub fn rotate_left(&mut self, mut by: usize) {
+		let len = self.len();
+		if len == 0 {
+			return;
+		}
+		by %= len;
+		if by == 0 {
+			return;
+		}
+		let width = T::Mem::BITS as usize;
+		if by >= width {
+			self.as_mut_bitslice().rotate_left(by);
+			return;
+		}
+		if self.bitptr().head().value() as usize + by >= width {
+			self.force_align();
+		}
+		self.reserve(by);
+		unsafe {
+			//  Extend the live region over the spare capacity, and copy the
+			//  front bits into it.
+			self.set_len_unchecked(len + by);
+			self.copy_within_unchecked(.. by, len);
+			//  Then move the start of the live region past the old front bits.
+			let mut bp = self.bitptr();
+			bp.set_head(BitIdx::new_unchecked(bp.head().value() + by as u8));
+			bp.set_len(len);
+			self.pointer = bp.to_nonnull();
+		}
+	}
+
 	/// Produces the allocation capacity of the underlying vector, measured in
 	/// `T` units.
 	///
@@ -514,7 +948,9 @@ where
 
 	/// Converts the vector into a [`BitBox<O, T>`].
 	///
-	/// Note that this will drop any excess capacity.
+	/// The vector is first shrunk to fit its live elements, so the box does
+	/// not retain any excess capacity. The bit-slice in the box has the same
+	/// length and starting bit position within its first element as `self`.
 	///
 	/// # Original
 	///
@@ -531,7 +967,8 @@ where
 	/// ```
 	///
 	/// [`BitBox<O, T>`]: crate::boxed::BitBox
-	pub fn into_boxed_bitslice(self) -> BitBox<O, T> {
+	pub fn into_boxed_bitslice(mut self) -> BitBox<O, T> {
+		self.shrink_to_fit();
 		let mut bitptr = self.bitptr();
 		let boxed = self.into_boxed_slice().pipe(ManuallyDrop::new);
 		unsafe {
@@ -542,7 +979,49 @@ where
 
 	/// Removes the bit-precision view, returning the underlying [`Vec`].
 	///
+	/// This is the inverse of [`::from_vec()`], and does not copy the buffer.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A vector over the allocation of `self`. Its length is
+	/// [`.elements()`], the number of elements that hold any live bit of
+	/// `self`, and its capacity is [`.alloc_capacity()`].
+	///
+	/// # Behavior
+	///
+	/// When the bit-vector begins at bit `0` of its first element and its
+	/// length is a multiple of `T::Mem::BITS`, every element of the returned
+	/// vector is fully live, and `BitVec::from_vec(bv.into_vec())` is equal to
+	/// `bv`.
+	///
+	/// Otherwise, the partially-live elements at either edge are kept whole.
+	/// No live bit is lost, but the dead bits in those elements keep whatever
+	/// value they had in the buffer. Clear them, or truncate the vector to an
+	/// element boundary, before the conversion if they must be in a known
+	/// state.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![Lsb0, u8; 1; 16];
+	/// assert_eq!(bv.into_vec(), [0xFF, 0xFF]);
+	///
+	/// let mut bv = bitvec![Lsb0, u8; 1; 16];
+	/// bv.truncate(12);
+	/// assert_eq!(bv.elements(), 2);
+	/// assert_eq!(bv.into_vec().len(), 2);
+	/// ```
+	///
 	/// [`Vec`]: alloc::vec::Vec
+	/// [`.alloc_capacity()`]: Self::alloc_capacity
+	/// [`.elements()`]: Self::elements
+	/// [`::from_vec()`]: Self::from_vec
 	pub fn into_vec(self) -> Vec<T> {
 		let mut this = ManuallyDrop::new(self);
 		let buf = this.as_mut_slice();
@@ -661,6 +1140,52 @@ where
 		}
 	}
 
+	/// Appends `0` bits to the vector until its length is a whole multiple of
+	/// the width of another memory element type.
+	///
+	/// This prepares a vector to have its contents reinterpreted as a sequence
+	/// of wider elements, such as when moving a buffer of `u8` elements onto
+	/// `u32` elements to use wider memory accesses.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The memory element type to whose width the length is padded.
+	///
+	/// # Returns
+	///
+	/// The number of bits appended. This is in the range `0 .. U::Mem::BITS`:
+	/// no bits are appended if the length is already a multiple of the width,
+	/// and at most `U::Mem::BITS - 1` bits are appended otherwise.
+	///
+	/// # Behavior
+	///
+	/// Only the length of the vector is considered. If the live region of the
+	/// vector does not begin at the leading edge of its buffer, call
+	/// [`.force_align()`] first so that the padded region covers whole `U`
+	/// elements of memory.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Lsb0, u8; 1; 20];
+	/// assert_eq!(bv.pad_to_store_boundary::<u32>(), 12);
+	/// assert_eq!(bv.len(), 32);
+	/// assert!(bv[20 ..].not_any());
+	///
+	/// assert_eq!(bv.pad_to_store_boundary::<u16>(), 0);
+	/// ```
+	///
+	/// [`.force_align()`]: Self::force_align
+	pub fn pad_to_store_boundary<U>(&mut self) -> usize
+	where U: BitStore {
+		let width = <U::Mem as BitMemory>::BITS as usize;
+		let extra = (width - self.len() % width) % width;
+		self.grow_filled(extra, false);
+		extra
+	}
+
 	/// Writes a new length value into the pointer without any checks.
 	pub(crate) unsafe fn set_len_unchecked(&mut self, new_len: usize) {
 		let mut bp = self.bitptr();
@@ -805,14 +1330,21 @@ where
 }
 
 mod api;
+mod cow;
 mod iter;
+mod matrix;
 mod ops;
 mod traits;
 
-pub use self::iter::{
-	Drain,
-	IntoIter,
-	Splice,
+pub use self::{
+	cow::BitCow,
+	iter::{
+		Drain,
+		IntoIter,
+		Splice,
+	},
+	matrix::bool_matmul,
+	traits::ParseBitVecError,
 };
 
 #[cfg(test)]
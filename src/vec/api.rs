@@ -1,6 +1,7 @@
 //! Port of the `Vec<T>` inherent API.
 
 use crate::{
+	devel as dvl,
 	mem::BitMemory,
 	order::BitOrder,
 	ptr::BitPtr,
@@ -790,6 +791,12 @@ where
 	///
 	/// Panics if `index > len`.
 	///
+	/// # Complexity
+	///
+	/// Takes linear (in `self.len() - index`) time. The bits after `index` are
+	/// moved with [`BitSlice::rotate_right`], which copies them a word at a
+	/// time when `O` is `Lsb0` or `Msb0`.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -801,6 +808,8 @@ where
 	/// bv.insert(2, true);
 	/// assert_eq!(bv, bits![0, 0, 1, 0, 0, 1, 0]);
 	/// ```
+	///
+	/// [`BitSlice::rotate_right`]: crate::slice::BitSlice::rotate_right
 	pub fn insert(&mut self, index: usize, value: bool) {
 		let len = self.len();
 		assert!(index <= len, "Index {} out of bounds: {}", index, len);
@@ -819,6 +828,12 @@ where
 	///
 	/// Panics if `index` is out of bounds.
 	///
+	/// # Complexity
+	///
+	/// Takes linear (in `self.len() - index`) time. The bits after `index` are
+	/// moved with [`BitSlice::rotate_left`], which copies them a word at a time
+	/// when `O` is `Lsb0` or `Msb0`.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -828,6 +843,8 @@ where
 	/// assert!(bv.remove(1));
 	/// assert_eq!(bv, bits![0, 0]);
 	/// ```
+	///
+	/// [`BitSlice::rotate_left`]: crate::slice::BitSlice::rotate_left
 	pub fn remove(&mut self, index: usize) -> bool {
 		self.assert_in_bounds(index);
 		let last = self.len() - 1;
@@ -854,6 +871,12 @@ where
 	/// decision, the predicate receives the index of each bit, as well as its
 	/// value.
 	///
+	/// # Complexity
+	///
+	/// Takes linear (in [`self.len()`]) time, and no extra space. Each
+	/// retained bit is written back to the next open index at the front of the
+	/// vector, and the vector is truncated to the retained bits at the end.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -863,13 +886,24 @@ where
 	/// bv.retain(|i, b| (i % 2 == 0) ^ b);
 	/// assert_eq!(bv, bits![0, 1, 0, 1]);
 	/// ```
+	///
+	/// [`self.len()`]: Self::len
 	pub fn retain<F>(&mut self, mut func: F)
 	where F: FnMut(usize, &bool) -> bool {
-		for n in (0 .. self.len()).rev() {
-			if !func(n, unsafe { self.get_unchecked(n) }) {
-				self.remove(n);
+		let len = self.len();
+		let mut kept = 0;
+		for idx in 0 .. len {
+			let bit = unsafe { *self.get_unchecked(idx) };
+			if func(idx, &bit) {
+				if kept != idx {
+					unsafe {
+						self.set_unchecked(kept, bit);
+					}
+				}
+				kept += 1;
 			}
 		}
+		self.truncate(kept);
 	}
 
 	/// Appends a bit to the back of a collection.
@@ -944,6 +978,22 @@ where
 	///
 	/// [`Vec::append`](alloc::vec::Vec::append)
 	///
+	/// # Behavior
+	///
+	/// The bits of `other` are copied onto the end of `self` with
+	/// [`.extend_from_bitslice()`], and `other` is then cleared. As with
+	/// [`Vec::append`], `other` keeps its allocation.
+	///
+	/// # Performance
+	///
+	/// When `other` has the same type parameters as `self`, and the end of
+	/// `self` falls at the same index within an element as the start of
+	/// `other` (such as when `self` holds a whole number of elements and
+	/// `other` begins at the start of its buffer), the storage of `other` is
+	/// copied wholesale into `self`. Otherwise, `Lsb0` and `Msb0` vectors
+	/// shift the bits of `other` into place an element at a time, and all
+	/// other vectors copy one bit at a time.
+	///
 	/// # Panics
 	///
 	/// Panics if the number of bits overflows the maximum vector capacity.
@@ -961,16 +1011,15 @@ where
 	/// assert_eq!(bv1.count_ones(), 10);
 	/// assert!(bv2.is_empty());
 	/// ```
+	///
+	/// [`.extend_from_bitslice()`]: Self::extend_from_bitslice
+	/// [`Vec::append`]: alloc::vec::Vec::append
 	pub fn append<O2, T2>(&mut self, other: &mut BitVec<O2, T2>)
 	where
 		O2: BitOrder,
 		T2: BitStore,
 	{
-		let this_len = self.len();
-		let new_len = this_len + other.len();
-		self.resize(new_len, false);
-		unsafe { self.get_unchecked_mut(this_len .. new_len) }
-			.clone_from_bitslice(other.as_bitslice());
+		self.extend_from_bitslice(other.as_bitslice());
 		other.clear();
 	}
 
@@ -979,8 +1028,10 @@ where
 	///
 	/// When the iterator **is** dropped, all bits in the range are removed from
 	/// the vector, even if the iterator was not fully consumed. If the iterator
-	/// **is not** dropped (with [`mem::forget`] for example), it is unspecified
-	/// how many bits are removed.
+	/// **is not** dropped (with [`mem::forget`] for example), the vector is
+	/// left holding only the bits before the range: it is truncated to the
+	/// start of the range as soon as the iterator is created, and only the
+	/// destructor moves the bits after the range back into place.
 	///
 	/// # Original
 	///
@@ -1211,6 +1262,10 @@ where
 	/// This method requires a single `bool` value. If you need more
 	/// flexibility, use [`.resize_with()`].
 	///
+	/// The new bits are written with [`.set_all()`], which fills every memory
+	/// element wholly inside the new region with one store, rather than
+	/// setting each bit.
+	///
 	/// # Original
 	///
 	/// [`Vec::resize`](alloc::vec::Vec::resize)
@@ -1230,6 +1285,7 @@ where
 	/// ```
 	///
 	/// [`.resize_with()`]: Self::resize_with
+	/// [`.set_all()`]: crate::slice::BitSlice::set_all
 	pub fn resize(&mut self, new_len: usize, value: bool) {
 		let len = self.len();
 		if new_len > len {
@@ -1254,6 +1310,44 @@ where
 		self.extend(other.iter().copied());
 	}
 
+	/// Copies bits from `src` range to the end of the vector.
+	///
+	/// # Original
+	///
+	/// [`Vec::extend_from_within`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.extend_from_within)
+	///
+	/// # Panics
+	///
+	/// This panics if the starting point is greater than the end point, or if
+	/// the end point is greater than the length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 1];
+	/// bv.extend_from_within(1 .. 4);
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 1, 1, 1, 0]);
+	///
+	/// bv.extend_from_within(5 ..);
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0]);
+	/// ```
+	pub fn extend_from_within<R>(&mut self, src: R)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let src = dvl::normalize_range(src, len);
+		dvl::assert_range(src.clone(), len);
+		self.resize(len + src.len(), false);
+		/* The source region ends at or before the old length, and the
+		destination region begins there, but the two may share the element at
+		that boundary. `copy_within` handles the shared element correctly.
+		*/
+		unsafe {
+			self.copy_within_unchecked(src, len);
+		}
+	}
+
 	/// Creates a splicing iterator that replaces the specified range in the
 	/// vector with the given `replace_with` iterator and yields the removed
 	/// items. `replace_with` does not need to be the same length as `range`.
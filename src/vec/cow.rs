@@ -0,0 +1,189 @@
+/*! Borrowed-or-owned bit sequences.
+
+This is the bit-sequence counterpart of [`Cow`]. A function that usually returns
+a view of its input, but must sometimes return a modified copy, can return a
+[`BitCow`] and only allocate in the second case.
+
+[`BitCow`]: self::BitCow
+[`Cow`]: alloc::borrow::Cow
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ops::Deref,
+};
+
+/** A clone-on-write bit sequence.
+
+This either borrows a [`BitSlice`] or owns a [`BitVec`]. It dereferences to
+`BitSlice` in both cases, and only copies borrowed bits into a new allocation
+when mutable access is requested through [`.to_mut()`].
+
+# Original
+
+[`Cow<'a, [bool]>`](alloc::borrow::Cow)
+
+# API Differences
+
+`Cow` is generic over any `ToOwned` type. `BitCow` is specialized to bit
+sequences, so it does not need the `Borrow` and `ToOwned` trait plumbing.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::vec::BitCow;
+
+fn set_first<'a>(bits: &'a BitSlice) -> BitCow<'a, Lsb0, usize> {
+  if bits[0] {
+    BitCow::from(bits)
+  }
+  else {
+    let mut out = BitCow::from(bits);
+    out.to_mut().set(0, true);
+    out
+  }
+}
+
+let already = bits![1, 0, 1];
+assert!(set_first(already).is_borrowed());
+
+let changed = set_first(bits![0, 0, 1]);
+assert!(changed.is_owned());
+assert_eq!(*changed, bits![1, 0, 1]);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`.to_mut()`]: Self::to_mut
+**/
+pub enum BitCow<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// A borrowed bit-slice.
+	Borrowed(&'a BitSlice<O, T>),
+	/// An owned bit-vector.
+	Owned(BitVec<O, T>),
+}
+
+impl<'a, O, T> BitCow<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Tests whether the sequence is borrowed.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self, Self::Borrowed(_))
+	}
+
+	/// Tests whether the sequence is owned.
+	pub fn is_owned(&self) -> bool {
+		!self.is_borrowed()
+	}
+
+	/// Gets mutable access to an owned bit-vector.
+	///
+	/// If the sequence is borrowed, its bits are first copied into a new
+	/// bit-vector, and the sequence becomes owned.
+	///
+	/// # Original
+	///
+	/// [`Cow::to_mut`](alloc::borrow::Cow::to_mut)
+	pub fn to_mut(&mut self) -> &mut BitVec<O, T> {
+		if let Self::Borrowed(bits) = *self {
+			*self = Self::Owned(BitVec::from_bitslice(bits));
+		}
+		match self {
+			Self::Owned(bv) => bv,
+			Self::Borrowed(_) => unreachable!("The sequence was made owned"),
+		}
+	}
+
+	/// Extracts an owned bit-vector, copying the bits if they are borrowed.
+	///
+	/// # Original
+	///
+	/// [`Cow::into_owned`](alloc::borrow::Cow::into_owned)
+	pub fn into_owned(self) -> BitVec<O, T> {
+		match self {
+			Self::Borrowed(bits) => BitVec::from_bitslice(bits),
+			Self::Owned(bv) => bv,
+		}
+	}
+}
+
+impl<O, T> Clone for BitCow<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn clone(&self) -> Self {
+		match self {
+			Self::Borrowed(bits) => Self::Borrowed(*bits),
+			Self::Owned(bv) => Self::Owned(bv.clone()),
+		}
+	}
+}
+
+impl<O, T> Debug for BitCow<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Borrowed(bits) => {
+				fmt.debug_tuple("Borrowed").field(bits).finish()
+			},
+			Self::Owned(bv) => fmt.debug_tuple("Owned").field(bv).finish(),
+		}
+	}
+}
+
+impl<O, T> Deref for BitCow<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Target = BitSlice<O, T>;
+
+	fn deref(&self) -> &Self::Target {
+		match self {
+			Self::Borrowed(bits) => *bits,
+			Self::Owned(bv) => bv.as_bitslice(),
+		}
+	}
+}
+
+impl<'a, O, T> From<&'a BitSlice<O, T>> for BitCow<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: &'a BitSlice<O, T>) -> Self {
+		Self::Borrowed(bits)
+	}
+}
+
+impl<O, T> From<BitVec<O, T>> for BitCow<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bv: BitVec<O, T>) -> Self {
+		Self::Owned(bv)
+	}
+}
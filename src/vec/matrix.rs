@@ -0,0 +1,120 @@
+/*! Boolean matrices stored as rows of bits.
+
+A matrix of `n` rows and `m` columns is held as `n` bit-vectors of `m` bits
+each, with bit `j` of row `i` holding cell `(i, j)`. An adjacency matrix of a
+graph has the same layout: bit `j` of row `i` is set when the graph has an edge
+from vertex `i` to vertex `j`.
+!*/
+
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+/** Multiplies two boolean matrices.
+
+The product is taken over the boolean semiring, where `AND` multiplies and `OR`
+adds: cell `(i, j)` of the product is set when there is some `k` for which both
+`a[i][k]` and `b[k][j]` are set.
+
+For adjacency matrices, the product of `a` and `b` holds an edge from `i` to `j`
+when a step along an edge of `a` followed by a step along an edge of `b` leads
+from `i` to `j`. Squaring an adjacency matrix, and combining the result with the
+original, finds every vertex reachable in at most two steps; repeating this
+computes the transitive closure.
+
+# Parameters
+
+- `a`: The left matrix, of `n` rows of `k` bits each.
+- `b`: The right matrix, of `k` rows of `m` bits each.
+
+# Returns
+
+The product matrix, of `n` rows of `m` bits each.
+
+# Behavior
+
+`b` is transposed, so that each of its columns is a bit-vector, and then each
+cell of the product is computed as `!a[i].is_disjoint(&column[j])`. This tests
+whole words of both operands at once.
+
+# Panics
+
+This panics if the rows of `a` have different lengths, if the rows of `b` have
+different lengths, or if the length of each row of `a` is not the number of rows
+in `b`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+//  0 -> 1 -> 2 -> 3
+let graph = vec![
+  bitvec![0, 1, 0, 0],
+  bitvec![0, 0, 1, 0],
+  bitvec![0, 0, 0, 1],
+  bitvec![0, 0, 0, 0],
+];
+
+let two_steps = bitvec::vec::bool_matmul(&graph, &graph);
+assert_eq!(two_steps[0], bits![0, 0, 1, 0]);
+assert_eq!(two_steps[1], bits![0, 0, 0, 1]);
+assert!(two_steps[2].not_any());
+```
+**/
+pub fn bool_matmul<O, T>(
+	a: &[BitVec<O, T>],
+	b: &[BitVec<O, T>],
+) -> Vec<BitVec<O, T>>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let inner = b.len();
+	for (idx, row) in a.iter().enumerate() {
+		assert_eq!(
+			row.len(),
+			inner,
+			"Row {} of the left matrix has {} columns, but the right matrix has \
+			 {} rows",
+			idx,
+			row.len(),
+			inner
+		);
+	}
+	let cols = b.first().map_or(0, |row| row.len());
+	for (idx, row) in b.iter().enumerate() {
+		assert_eq!(
+			row.len(),
+			cols,
+			"Row {} of the right matrix has {} columns, but row 0 has {}",
+			idx,
+			row.len(),
+			cols
+		);
+	}
+
+	let mut columns = Vec::with_capacity(cols);
+	columns.resize_with(cols, || BitVec::<O, T>::repeat(false, inner));
+	for (r, row) in b.iter().enumerate() {
+		for c in row.iter_ones() {
+			columns[c].set(r, true);
+		}
+	}
+
+	a.iter()
+		.map(|row| {
+			let mut out = BitVec::repeat(false, cols);
+			for (c, column) in columns.iter().enumerate() {
+				if !row.is_disjoint(column.as_bitslice()) {
+					out.set(c, true);
+				}
+			}
+			out
+		})
+		.collect()
+}
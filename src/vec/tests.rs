@@ -38,6 +38,86 @@ fn from_vec() {
 	assert_eq!(bv.as_slice(), &[0, 1, 2, 3]);
 }
 
+#[test]
+fn vec_round_trip() {
+	let mut vec = Vec::<u64>::with_capacity(8);
+	vec.extend_from_slice(&[!0, 0, 0x0123_4567_89AB_CDEF]);
+	let (ptr, capa) = (vec.as_ptr(), vec.capacity());
+
+	let bv = BitVec::<Lsb0, u64>::from_vec(vec);
+	assert_eq!(bv.len(), 192);
+	assert_eq!(bv.alloc_capacity(), capa);
+	assert_eq!(bv.capacity(), capa * 64);
+	assert_eq!(bv.as_slice().as_ptr(), ptr);
+	assert_eq!(bv.count_ones(), 64 + 32);
+
+	let vec = bv.into_vec();
+	assert_eq!(vec.as_ptr(), ptr);
+	assert_eq!(vec.capacity(), capa);
+	assert_eq!(vec, [!0, 0, 0x0123_4567_89AB_CDEF]);
+
+	//  Partially-live elements are kept whole.
+	let mut bv = BitVec::<Msb0, u8>::from_vec(vec![0xA5, 0x5A, 0xFF]);
+	bv.truncate(12);
+	assert_eq!(bv.elements(), 2);
+	let vec = bv.into_vec();
+	assert_eq!(vec.len(), 2);
+	assert_eq!(vec[0], 0xA5);
+	assert_eq!(vec[1] & 0xF0, 0x50);
+}
+
+#[test]
+fn append() {
+	let pattern = [0xA5u8, 0x3C, 0x0F];
+
+	//  Element-aligned: the storage of `other` lands intact.
+	let mut bv = bitvec![Lsb0, u8; 1; 16];
+	let mut other = BitVec::<Lsb0, u8>::from_vec(pattern.to_vec());
+	let cap = other.capacity();
+	bv.append(&mut other);
+	assert_eq!(bv.len(), 40);
+	assert_eq!(bv.as_slice(), &[0xFF, 0xFF, 0xA5, 0x3C, 0x0F]);
+	assert!(other.is_empty());
+	assert_eq!(other.capacity(), cap);
+
+	//  Unaligned: the bits of `other` are shifted by the length of `self`.
+	let mut bv = bitvec![Lsb0, u8; 1; 3];
+	let mut other = BitVec::<Lsb0, u8>::from_vec(pattern.to_vec());
+	bv.append(&mut other);
+	assert_eq!(bv.len(), 27);
+	assert!(bv[.. 3].all());
+	assert_eq!(&bv[3 ..], pattern.view_bits::<Lsb0>());
+	assert!(other.is_empty());
+
+	//  Differing type parameters.
+	let mut bv = bitvec![Msb0, u16; 0; 5];
+	let mut other = BitVec::<Lsb0, u8>::from_vec(pattern.to_vec());
+	bv.append(&mut other);
+	assert_eq!(&bv[5 ..], pattern.view_bits::<Lsb0>());
+	assert!(other.is_empty());
+}
+
+#[test]
+fn repeat_patterns() {
+	let data = [0b1011_0010u8, 0x5A];
+	for &len in &[1, 3, 5, 8, 11, 16] {
+		let pattern = &data.view_bits::<Msb0>()[.. len];
+		for &n in &[0, 1, 2, 3, 7, 8, 13] {
+			let tiled = pattern.repeat(n);
+			assert_eq!(tiled.len(), len * n);
+			for chunk in tiled.chunks_exact(len) {
+				assert_eq!(chunk, pattern);
+			}
+		}
+	}
+
+	let pattern = bits![Lsb0, u16; 1, 0, 0, 1, 1];
+	let tiled = bitvec![Lsb0, u16; 1, 0, 0, 1, 1].repeat(20);
+	assert_eq!(tiled.len(), 100);
+	assert!(tiled.chunks(5).all(|chunk| chunk == pattern));
+	assert!(bits![].repeat(10).is_empty());
+}
+
 #[test]
 fn push() {
 	let mut bvm08 = BitVec::<Msb0, u8>::new();
@@ -301,6 +381,501 @@ fn misc() {
 	assert_eq!(bv, bits![0, 0, 1, 1, 0, 1]);
 }
 
+#[test]
+fn grow_filled() {
+	//  The dead bits of a partially-filled tail element must be overwritten.
+	let mut bv = BitVec::<Lsb0, u8>::from_vec(vec![!0, !0]);
+	bv.truncate(11);
+	bv.grow_filled(7, false);
+	assert_eq!(bv.len(), 18);
+	assert_eq!(bv[.. 11].count_ones(), 11);
+	assert!(bv[11 ..].not_any());
+	assert_eq!(bv.as_slice()[1], 0b0000_0111);
+
+	bv.grow_filled(20, true);
+	assert_eq!(bv.len(), 38);
+	assert!(bv[18 ..].all());
+	assert_eq!(bv.as_slice()[2], 0b1111_1100);
+	assert_eq!(bv.as_slice()[3], !0);
+
+	bv.grow_filled(0, false);
+	assert_eq!(bv.len(), 38);
+}
+
+#[test]
+fn transcode() {
+	macro_rules! check {
+		($src:expr; $($o:ty, $t:ty);+ $(;)?) => { $(
+			let out = $src.transcode::<$o, $t>();
+			assert_eq!(out.len(), $src.len());
+			assert_eq!(out.as_bitslice(), $src.as_bitslice());
+		)+ };
+	}
+
+	macro_rules! from {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			//  Start from an unaligned region so that every destination must
+			//  repack the bits.
+			let data = [0x5A3Cu16, 0x0FF0, 0x1234, 0xC3A5];
+			let src = data.view_bits::<Msb0>()[3 .. 57]
+				.iter()
+				.copied()
+				.collect::<BitVec<$o, $t>>();
+			check!(src;
+				Lsb0, u8; Lsb0, u16; Lsb0, u32;
+				Msb0, u8; Msb0, u16; Msb0, u32;
+			);
+		)+ };
+	}
+
+	from!(
+		Lsb0, u8; Lsb0, u16; Lsb0, u32;
+		Msb0, u8; Msb0, u16; Msb0, u32;
+	);
+
+	let empty = BitVec::<Lsb0, u8>::new().transcode::<Msb0, u32>();
+	assert!(empty.is_empty());
+}
+
+#[test]
+fn rotate_left() {
+	let data = [0x3C5Au16, 0x9E01, 0x7F22];
+	let src = &data.view_bits::<Lsb0>()[3 .. 45];
+
+	let mut bv = src.iter().copied().collect::<BitVec<Msb0, u8>>();
+	let mut reference = src.to_bitvec();
+	//  Many single-bit rotations walk the start across element edges.
+	for _ in 0 .. 100 {
+		bv.rotate_left(1);
+		reference.as_mut_bitslice().rotate_left(1);
+		assert_eq!(bv, reference);
+	}
+
+	for &by in &[0, 3, 7, 8, 13, 42, 5, 1] {
+		bv.rotate_left(by);
+		reference.as_mut_bitslice().rotate_left(by);
+		assert_eq!(bv, reference);
+	}
+
+	//  Growing the vector after rotating still works.
+	bv.push(true);
+	reference.push(true);
+	assert_eq!(bv, reference);
+}
+
+#[test]
+fn rotate_left_too_far() {
+	let mut bv = bitvec![0, 1, 0];
+	bv.rotate_left(4);
+	assert_eq!(bv, bits![1, 0, 0]);
+	bv.rotate_left(3);
+	assert_eq!(bv, bits![1, 0, 0]);
+}
+
+#[test]
+fn extend_from_within() {
+	let data = [0xA5u8, 0x3C];
+	let mut bv = data.view_bits::<Msb0>()[3 .. 13].to_bitvec();
+	let mut reference = bv.iter().copied().collect::<Vec<bool>>();
+
+	//  Sources that end at the old length share an element with the
+	//  destination.
+	let ranges = [(0, 10), (7, 10), (2, 5), (13, 20), (0, 1), (4, 4)];
+	for &(start, end) in &ranges {
+		bv.extend_from_within(start .. end);
+		let copy = reference[start .. end].to_vec();
+		reference.extend(copy);
+		assert!(bv.iter().copied().eq(reference.iter().copied()));
+	}
+
+	bv.extend_from_within(..);
+	assert_eq!(bv[.. bv.len() / 2], bv[bv.len() / 2 ..]);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_within_out_of_bounds() {
+	bitvec![0, 1].extend_from_within(1 .. 3);
+}
+
+#[test]
+fn sparse_indices() {
+	let indices = [0, 3, 64, 65, 190, 299];
+	let bv = BitVec::<Msb0, u16>::from_sparse_indices(300, &indices);
+	assert_eq!(bv.len(), 300);
+	assert_eq!(bv.count_ones(), indices.len());
+	assert_eq!(bv.to_sparse_indices(), indices);
+
+	//  Order and repetition do not matter.
+	let bv2 = BitVec::<Msb0, u16>::from_sparse_indices(300, &[
+		299, 0, 65, 3, 65, 64, 190,
+	]);
+	assert_eq!(bv, bv2);
+
+	let empty = BitVec::<Lsb0, u8>::from_sparse_indices(20, &[]);
+	assert!(empty.to_sparse_indices().is_empty());
+	assert_eq!(empty, bits![0; 20]);
+}
+
+#[test]
+#[should_panic]
+fn sparse_indices_out_of_range() {
+	BitVec::<Lsb0, u8>::from_sparse_indices(8, &[2, 8]);
+}
+
+#[test]
+fn extract_plane() {
+	let mut samples = [0u16; 300];
+	for (n, sample) in samples.iter_mut().enumerate() {
+		*sample = (n as u32).wrapping_mul(0x9E37).wrapping_shr(3) as u16;
+	}
+	for k in 0 .. 16 {
+		let plane = BitVec::<Msb0, u8>::extract_plane(&samples, k);
+		assert_eq!(plane.len(), samples.len());
+		for (bit, sample) in plane.iter().zip(samples.iter()) {
+			assert_eq!(*bit, sample & (1 << k) != 0);
+		}
+	}
+
+	assert!(BitVec::<Lsb0, usize>::extract_plane(&[], 3).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn extract_plane_out_of_range() {
+	BitVec::<Lsb0, usize>::extract_plane(&[1, 2, 3], 16);
+}
+
+#[test]
+fn bit_stuffing() {
+	//  The flag pattern, sent as frame data.
+	let flag = bits![Msb0, u8; 0, 1, 1, 1, 1, 1, 1, 0];
+	let stuffed = BitVec::<Msb0, u8>::bit_stuff(flag);
+	assert_eq!(stuffed, bits![0, 1, 1, 1, 1, 1, 0, 1, 0]);
+	let unstuffed = BitVec::<Msb0, u8>::bit_unstuff(stuffed.as_bitslice());
+	assert_eq!(unstuffed.unwrap(), flag);
+
+	//  Runs of exactly five, at the end and across element boundaries.
+	let data = bits![Lsb0, u8; 0, 0, 0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1];
+	let stuffed = BitVec::<Lsb0, u16>::bit_stuff(data);
+	assert_eq!(
+		stuffed,
+		bits![0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0]
+	);
+	let unstuffed = BitVec::<Lsb0, u16>::bit_unstuff(stuffed.as_bitslice());
+	assert_eq!(unstuffed.unwrap(), data);
+
+	//  Long runs of ones are broken every five bits.
+	let ones = BitVec::<Lsb0, usize>::bit_stuff(bits![1; 12]);
+	assert_eq!(ones, bits![1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1]);
+	assert!(!ones.windows(6).any(|win| win.all()));
+
+	//  A stuffed stream that is cut off after five ones is still accepted.
+	let cut = BitVec::<Lsb0, u8>::bit_unstuff(bits![0, 1, 1, 1, 1, 1]);
+	assert_eq!(cut.unwrap(), bits![0, 1, 1, 1, 1, 1]);
+	let six = bits![0, 1, 1, 1, 1, 1, 1];
+	assert!(BitVec::<Lsb0, u8>::bit_unstuff(six).is_none());
+
+	//  Round trips of irregular data.
+	let mut data = BitVec::<Msb0, u32>::new();
+	for idx in 0 .. 2_000usize {
+		data.push(idx.wrapping_mul(0x9E37_79B9).rotate_left(7) % 7 != 0);
+	}
+	let stuffed = BitVec::<Lsb0, u8>::bit_stuff(data.as_bitslice());
+	assert!(!stuffed.windows(6).any(|win| win.all()));
+	let unstuffed = BitVec::<Msb0, u32>::bit_unstuff(stuffed.as_bitslice());
+	assert_eq!(unstuffed.unwrap(), data);
+
+	assert!(BitVec::<Lsb0, u8>::bit_stuff(BitSlice::<Lsb0, u8>::empty())
+		.is_empty());
+}
+
+#[test]
+fn run_length_coding() {
+	let mut state = 0x2545_F491u32;
+	let mut random = || {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		state
+	};
+
+	for &len in &[1usize, 7, 64, 65, 300, 1_000] {
+		for _ in 0 .. 8 {
+			//  Bias toward longer runs, so that some span whole elements.
+			let density = random() % 4;
+			let mut bv = BitVec::<Msb0, u16>::with_capacity(len);
+			let mut bit = random() & 1 == 1;
+			for _ in 0 .. len {
+				if random() % 8 <= density {
+					bit = !bit;
+				}
+				bv.push(bit);
+			}
+			let runs = bv.rle_encode();
+			let total = runs.iter().map(|&run| run as usize).sum::<usize>();
+			assert_eq!(total, len);
+			assert!(runs.iter().skip(1).all(|&run| run != 0));
+			assert_eq!(runs[0] == 0, bv[0]);
+			assert_eq!(BitVec::<Lsb0, u64>::rle_decode(&runs, false), bv);
+			if let Some(tail) = bv.get(3 ..) {
+				let runs = tail.rle_encode();
+				assert_eq!(BitVec::<Lsb0, u8>::rle_decode(&runs, false), tail);
+			}
+		}
+	}
+
+	assert!(BitSlice::<Lsb0, u8>::empty().rle_encode().is_empty());
+	assert!(BitVec::<Lsb0, u8>::rle_decode(&[], false).is_empty());
+	assert_eq!(
+		BitVec::<Lsb0, u8>::rle_decode(&[2, 0, 1, 3], true),
+		bits![1, 1, 1, 0, 0, 0],
+	);
+}
+
+#[test]
+fn bit_cow() {
+	use crate::vec::BitCow;
+
+	let data = [0x3Cu8, 0xA5];
+	let bits = &data.view_bits::<Msb0>()[2 ..];
+
+	let mut cow = BitCow::from(bits);
+	assert!(cow.is_borrowed());
+	assert_eq!(cow.len(), 14);
+	assert_eq!(*cow, bits);
+	assert_eq!(cow.clone().into_owned(), bits);
+
+	cow.to_mut().push(true);
+	assert!(cow.is_owned());
+	assert_eq!(cow.len(), 15);
+	assert_eq!(cow[.. 14], bits);
+	assert!(cow[14]);
+	//  The borrowed source is untouched.
+	assert_eq!(data, [0x3C, 0xA5]);
+
+	let owned = BitCow::from(bitvec![Msb0, u8; 1, 0, 1]);
+	assert!(owned.is_owned());
+	assert_eq!(owned.into_owned(), bits![1, 0, 1]);
+}
+
+#[test]
+fn pad_to_store_boundary() {
+	let mut bv = BitVec::<Msb0, u8>::new();
+	assert_eq!(bv.pad_to_store_boundary::<u64>(), 0);
+	assert!(bv.is_empty());
+
+	bv.extend_from_bitslice(bits![1; 9]);
+	assert_eq!(bv.pad_to_store_boundary::<u16>(), 7);
+	assert_eq!(bv.len(), 16);
+	assert_eq!(bv.as_raw_slice(), &[0xFF, 0x80]);
+
+	bv.push(true);
+	assert_eq!(bv.pad_to_store_boundary::<u32>(), 15);
+	assert_eq!(bv.len(), 32);
+	assert_eq!(bv.pad_to_store_boundary::<u32>(), 0);
+	assert_eq!(bv.pad_to_store_boundary::<u8>(), 0);
+	assert_eq!(bv.count_ones(), 10);
+
+	let mut bv = bitvec![Lsb0, u32; 0; 33];
+	assert_eq!(bv.pad_to_store_boundary::<u8>(), 7);
+	assert_eq!(bv.len(), 40);
+}
+
+#[test]
+fn boolean_matrix_product() {
+	//  0 -> 1 -> 2 -> 0, 3 -> 4, and 5 is isolated.
+	let n = 6;
+	let edges = [(0, 1), (1, 2), (2, 0), (3, 4)];
+	let mut graph = vec![BitVec::<Msb0, u8>::repeat(false, n); n];
+	for &(from, to) in &edges {
+		graph[from].set(to, true);
+	}
+
+	//  Reachability in one or more steps, by squaring until it settles.
+	let mut reach = graph.clone();
+	loop {
+		let step = crate::vec::bool_matmul(&reach, &reach);
+		let next = reach
+			.iter()
+			.zip(step.iter())
+			.map(|(a, b)| a.union(b.as_bitslice()))
+			.collect::<Vec<_>>();
+		if next == reach {
+			break;
+		}
+		reach = next;
+	}
+	for vertex in 0 .. 3 {
+		assert_eq!(reach[vertex], bits![1, 1, 1, 0, 0, 0]);
+	}
+	assert_eq!(reach[3], bits![0, 0, 0, 0, 1, 0]);
+	assert!(reach[4].not_any());
+	assert!(reach[5].not_any());
+
+	//  Rectangular operands: (2 x 3) * (3 x 4).
+	let a = vec![bitvec![Lsb0, u16; 1, 0, 1], bitvec![Lsb0, u16; 0, 1, 0]];
+	let b = vec![
+		bitvec![Lsb0, u16; 1, 0, 0, 0],
+		bitvec![Lsb0, u16; 0, 1, 1, 0],
+		bitvec![Lsb0, u16; 0, 0, 0, 1],
+	];
+	let product = crate::vec::bool_matmul(&a, &b);
+	assert_eq!(product.len(), 2);
+	assert_eq!(product[0], bits![1, 0, 0, 1]);
+	assert_eq!(product[1], bits![0, 1, 1, 0]);
+
+	assert!(crate::vec::bool_matmul::<Lsb0, u8>(&[], &[]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn boolean_matrix_dimensions() {
+	let a = vec![bitvec![0, 1], bitvec![1, 0]];
+	let b = vec![bitvec![0, 1], bitvec![1, 0], bitvec![1, 1]];
+	crate::vec::bool_matmul(&a, &b);
+}
+
+#[test]
+fn drain_partial() {
+	let data = [0x5Au8, 0xC3, 0x96];
+	let src = &data.view_bits::<Msb0>()[2 ..];
+
+	//  Both ends may be consumed, and the rest is removed on drop.
+	let mut bv = src.to_bitvec();
+	let mut drain = bv.drain(3 .. 17);
+	assert_eq!(drain.next(), Some(src[3]));
+	assert_eq!(drain.next_back(), Some(src[16]));
+	assert_eq!(drain.len(), 12);
+	drop(drain);
+	assert_eq!(bv.len(), src.len() - 14);
+	assert_eq!(bv[.. 3], src[.. 3]);
+	assert_eq!(bv[3 ..], src[17 ..]);
+
+	//  An unconsumed drain still removes its range.
+	let mut bv = src.to_bitvec();
+	bv.drain(.. 10);
+	assert_eq!(bv, src[10 ..]);
+
+	//  A leaked drain leaves only the bits before the range.
+	let mut bv = src.to_bitvec();
+	core::mem::forget(bv.drain(5 .. 8));
+	assert_eq!(bv, src[.. 5]);
+	bv.push(true);
+	assert_eq!(bv.len(), 6);
+}
+
+#[test]
+fn retain_compacts() {
+	let mut bv = BitVec::<Msb0, u16>::repeat(false, 77);
+	for &idx in &[0, 15, 16, 40, 63, 76] {
+		bv.set(idx, true);
+	}
+	let mut seen = 0;
+	bv.retain(|idx, bit| {
+		assert_eq!(idx, seen);
+		seen += 1;
+		*bit
+	});
+	assert_eq!(seen, 77);
+	assert_eq!(bv, bits![1; 6]);
+
+	let mut bv = bitvec![Msb0, u16; 1, 0, 1, 1, 0];
+	bv.retain(|_, bit| !*bit);
+	assert_eq!(bv, bits![0, 0]);
+	bv.retain(|_, _| false);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn insert_remove_tail() {
+	let data = [0x3C5Au16, 0x9E01, 0x7F22];
+	let src = &data.view_bits::<Msb0>()[.. 41];
+
+	//  Index 37 is in the final, partial, element.
+	for &idx in &[0, 13, 16, 37, 41] {
+		let mut bv = src.to_bitvec();
+		bv.insert(idx, true);
+		assert_eq!(bv.len(), 42);
+		assert_eq!(bv[.. idx], src[.. idx]);
+		assert!(bv[idx]);
+		assert_eq!(bv[idx + 1 ..], src[idx ..]);
+
+		assert!(bv.remove(idx));
+		assert_eq!(bv, src);
+	}
+
+	let mut bv = src.to_bitvec();
+	assert_eq!(bv.remove(40), src[40]);
+	assert_eq!(bv.remove(33), src[33]);
+	assert_eq!(bv[33 ..], src[34 .. 40]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+	bitvec![0; 5].insert(6, true);
+}
+
+#[test]
+#[should_panic]
+fn remove_out_of_bounds() {
+	bitvec![0; 5].remove(5);
+}
+
+#[test]
+fn resize_calls() {
+	let mut bv = bitvec![Msb0, u8; 1; 5];
+	let mut calls = 0;
+	bv.resize_with(45, || {
+		calls += 1;
+		calls % 3 == 0
+	});
+	assert_eq!(calls, 40);
+	assert_eq!(bv.len(), 45);
+	assert!(bv[.. 5].all());
+	assert!(bv[5 ..].iter_ones().eq((0 .. 40).filter(|idx| idx % 3 == 2)));
+
+	calls = 0;
+	bv.resize_with(10, || {
+		calls += 1;
+		true
+	});
+	assert_eq!(calls, 0);
+	assert_eq!(bv.len(), 10);
+
+	bv.resize(70, true);
+	assert_eq!(bv.len(), 70);
+	assert!(bv[10 ..].all());
+	assert_eq!(bv.as_slice()[2 .. 8], [!0u8; 6]);
+	bv.resize(3, false);
+	assert_eq!(bv, bits![1; 3]);
+}
+
+#[test]
+fn parse_bit_strings() {
+	let bv: BitVec<Msb0, u16> = "1011_0010 0000\t1111\n1".parse().unwrap();
+	assert_eq!(bv.len(), 17);
+	assert_eq!(bv[.. 16].load_be::<u16>(), 0xB20F);
+	assert!(bv[16]);
+
+	let bv: BitVec<Lsb0, u8> = "0000_0001".parse().unwrap();
+	assert_eq!(bv.as_slice(), &[0x80]);
+
+	assert!("".parse::<BitVec>().unwrap().is_empty());
+	assert!("_ _".parse::<BitVec>().unwrap().is_empty());
+
+	let err = "10é2".parse::<BitVec>().unwrap_err();
+	assert_eq!((err.index(), err.character()), (2, 'é'));
+	let err = "10_2".parse::<BitVec>().unwrap_err();
+	assert_eq!((err.index(), err.character()), (3, '2'));
+	assert_eq!(
+		format!("{}", err),
+		"Invalid character '2' at index 3 of a bit string; expected `0`, `1`, \
+		 `_`, or whitespace",
+	);
+}
+
 #[test]
 fn cloning() {
 	let mut a = bitvec![0];
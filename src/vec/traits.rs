@@ -31,6 +31,7 @@ use core::{
 		Hash,
 		Hasher,
 	},
+	str::FromStr,
 };
 
 use tap::tap::Tap;
@@ -254,6 +255,97 @@ where
 	}
 }
 
+/** Parses a string of `0` and `1` characters into a bit-vector.
+
+The leftmost character in the string becomes bit `0` of the vector, the next
+character becomes bit `1`, and so on. This is the *index* order of the vector;
+the [`BitOrder`] type parameter then decides where in memory each index is
+stored. For instance, `"1011_0010"` parsed into a `BitVec<Msb0, u8>` produces
+the byte `0xB2`, but parsed into a `BitVec<Lsb0, u8>` produces the byte `0x4D`.
+
+Underscores and ASCII whitespace are ignored, so they may be used to group the
+digits. Any other character is rejected with a [`ParseBitVecError`].
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bv: BitVec<Msb0, u8> = "1011_0010".parse().unwrap();
+assert_eq!(bv, bits![1, 0, 1, 1, 0, 0, 1, 0]);
+assert_eq!(bv.as_slice(), &[0xB2]);
+
+let bv: BitVec<Lsb0, u8> = "1011 0010".parse().unwrap();
+assert_eq!(bv.as_slice(), &[0x4D]);
+
+assert!("10120".parse::<BitVec>().is_err());
+```
+
+[`BitOrder`]: crate::order::BitOrder
+[`ParseBitVecError`]: crate::vec::ParseBitVecError
+**/
+impl<O, T> FromStr for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Err = ParseBitVecError;
+
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::with_capacity(src.len());
+		for (index, ch) in src.char_indices() {
+			match ch {
+				'0' => out.push(false),
+				'1' => out.push(true),
+				'_' => {},
+				c if c.is_ascii_whitespace() => {},
+				c => return Err(ParseBitVecError { index, ch: c }),
+			}
+		}
+		Ok(out)
+	}
+}
+
+/** The error type returned when a string cannot be parsed into a [`BitVec`].
+
+[`BitVec`]: crate::vec::BitVec
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseBitVecError {
+	/// The byte index in the source string of the rejected character.
+	index: usize,
+	/// The rejected character.
+	ch: char,
+}
+
+impl ParseBitVecError {
+	/// Gets the byte index in the source string of the rejected character.
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Gets the rejected character.
+	pub fn character(&self) -> char {
+		self.ch
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitVecError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"Invalid character {:?} at index {} of a bit string; expected `0`, \
+			 `1`, `_`, or whitespace",
+			self.ch, self.index
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitVecError {
+}
+
 impl<O, T> Default for BitVec<O, T>
 where
 	O: BitOrder,